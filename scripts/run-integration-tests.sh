@@ -270,6 +270,38 @@ setup_n8n() {
     log_info "Jira Software Cloud API credential created with ID: $JIRA_CREDENTIAL_ID"
     export JIRA_CREDENTIAL_ID
     
+    # Jira Software Server/Data Center credentials (basic auth and PAT) for the
+    # jiraVersion "server" and "serverPat" trigger test workflows.
+    log_step "Creating Jira Software Server API credentials (pointing to n8n-unihook)..."
+    
+    local jira_server_credential_response=$(curl -s -b "$cookie_jar" -X POST http://localhost:6789/rest/credentials \
+        -H "Content-Type: application/json" \
+        -d '{"name":"Test jiraSoftwareServerApi","type":"jiraSoftwareServerApi","data":{"email":"test@example.com","password":"test-password","domain":"http://n8n-unihook:3000"}}')
+    
+    JIRA_SERVER_CREDENTIAL_ID=$(echo "$jira_server_credential_response" | grep -o '"id":"[^"]*"' | head -1 | cut -d'"' -f4)
+    
+    if [ -z "$JIRA_SERVER_CREDENTIAL_ID" ]; then
+        log_error "Failed to create Jira Server credential: $jira_server_credential_response"
+        rm -f "$cookie_jar"
+        return 1
+    fi
+    
+    local jira_server_pat_credential_response=$(curl -s -b "$cookie_jar" -X POST http://localhost:6789/rest/credentials \
+        -H "Content-Type: application/json" \
+        -d '{"name":"Test jiraSoftwareServerPatApi","type":"jiraSoftwareServerPatApi","data":{"personalAccessToken":"test-pat","domain":"http://n8n-unihook:3000"}}')
+    
+    JIRA_SERVER_PAT_CREDENTIAL_ID=$(echo "$jira_server_pat_credential_response" | grep -o '"id":"[^"]*"' | head -1 | cut -d'"' -f4)
+    
+    if [ -z "$JIRA_SERVER_PAT_CREDENTIAL_ID" ]; then
+        log_error "Failed to create Jira Server PAT credential: $jira_server_pat_credential_response"
+        rm -f "$cookie_jar"
+        return 1
+    fi
+    
+    log_info "Jira Server credentials created with IDs: $JIRA_SERVER_CREDENTIAL_ID (basic), $JIRA_SERVER_PAT_CREDENTIAL_ID (PAT)"
+    export JIRA_SERVER_CREDENTIAL_ID
+    export JIRA_SERVER_PAT_CREDENTIAL_ID
+    
     # Create a "dud" GitHub API credential for GitHub trigger test workflows.
     # The server points at the n8n-unihook container so that n8n's
     # webhook registration calls are intercepted by Unihook's built-in
@@ -382,12 +414,14 @@ echo ""
 export TEST_N8N_API_KEY="$N8N_API_KEY"
 export SLACK_CREDENTIAL_ID="$SLACK_CREDENTIAL_ID"
 export JIRA_CREDENTIAL_ID="$JIRA_CREDENTIAL_ID"
+export JIRA_SERVER_CREDENTIAL_ID="$JIRA_SERVER_CREDENTIAL_ID"
+export JIRA_SERVER_PAT_CREDENTIAL_ID="$JIRA_SERVER_PAT_CREDENTIAL_ID"
 export GITHUB_CREDENTIAL_ID="$GITHUB_CREDENTIAL_ID"
 
 if [ -n "$TEST_FILTER" ]; then
-    TEST_N8N_API_KEY="$N8N_API_KEY" SLACK_CREDENTIAL_ID="$SLACK_CREDENTIAL_ID" JIRA_CREDENTIAL_ID="$JIRA_CREDENTIAL_ID" GITHUB_CREDENTIAL_ID="$GITHUB_CREDENTIAL_ID" cargo test --test integration "$TEST_FILTER" -- --test-threads=1 --nocapture
+    TEST_N8N_API_KEY="$N8N_API_KEY" SLACK_CREDENTIAL_ID="$SLACK_CREDENTIAL_ID" JIRA_CREDENTIAL_ID="$JIRA_CREDENTIAL_ID" JIRA_SERVER_CREDENTIAL_ID="$JIRA_SERVER_CREDENTIAL_ID" JIRA_SERVER_PAT_CREDENTIAL_ID="$JIRA_SERVER_PAT_CREDENTIAL_ID" GITHUB_CREDENTIAL_ID="$GITHUB_CREDENTIAL_ID" cargo test --test integration "$TEST_FILTER" -- --test-threads=1 --nocapture
 else
-    TEST_N8N_API_KEY="$N8N_API_KEY" SLACK_CREDENTIAL_ID="$SLACK_CREDENTIAL_ID" JIRA_CREDENTIAL_ID="$JIRA_CREDENTIAL_ID" GITHUB_CREDENTIAL_ID="$GITHUB_CREDENTIAL_ID" cargo test --test integration -- --test-threads=1 --nocapture
+    TEST_N8N_API_KEY="$N8N_API_KEY" SLACK_CREDENTIAL_ID="$SLACK_CREDENTIAL_ID" JIRA_CREDENTIAL_ID="$JIRA_CREDENTIAL_ID" JIRA_SERVER_CREDENTIAL_ID="$JIRA_SERVER_CREDENTIAL_ID" JIRA_SERVER_PAT_CREDENTIAL_ID="$JIRA_SERVER_PAT_CREDENTIAL_ID" GITHUB_CREDENTIAL_ID="$GITHUB_CREDENTIAL_ID" cargo test --test integration -- --test-threads=1 --nocapture
 fi
 
 TEST_RESULT=$?
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Deserializer};
 
+use crate::crypto::{SecretCipher, SecretCipherError};
+use crate::zoom::{ZoomEventTier, ZoomRedactionMode, ZoomRoutingMode, event_pattern_matches};
+
 /// Configuration for the Slack Unihook router.
 /// All values are loaded from environment variables.
 #[derive(Debug, Deserialize, Clone)]
@@ -15,6 +18,12 @@ pub struct Config {
     #[serde(default = "default_listen_addr")]
     pub listen_addr: String,
 
+    /// Optional address for a separate internal listener serving the
+    /// provider API mocks. When set, the mocks are no longer served on
+    /// `listen_addr`, which can then be exposed to the internet.
+    #[serde(default)]
+    pub internal_listen_addr: Option<String>,
+
     /// How often to refresh the routing table from n8n (in seconds)
     #[serde(default = "default_refresh_interval")]
     pub refresh_interval_secs: u64,
@@ -29,12 +38,64 @@ pub struct Config {
     #[serde(default = "default_endpoint_webhook_test")]
     pub n8n_endpoint_webhook_test: String,
 
-    /// Optional shared secret for verifying inbound GitHub webhook signatures.
-    /// When set, the `X-Hub-Signature-256` header on incoming requests to
-    /// `/github/events` is verified using HMAC-SHA256 before routing.
-    /// When unset, inbound verification is skipped (existing behavior).
+    /// Optional comma-separated shared secrets for verifying inbound GitHub
+    /// webhook signatures. When set, the `X-Hub-Signature-256` header on
+    /// incoming requests to `/github/events` must verify against one of them
+    /// (or a matching scoped secret); listing the old and new secret lets a
+    /// secret be rotated webhook by webhook. When unset, inbound verification
+    /// is skipped (existing behavior).
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub github_webhook_secret: Vec<String>,
+
+    /// Optional comma-separated `scope=secret` pairs, where the scope is an
+    /// owner (`acme`) or repository (`acme/api`). Events for that owner or
+    /// repository also verify against the scoped secret.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub github_webhook_scoped_secrets: Vec<String>,
+
+    /// Optional comma-separated shared secrets for verifying inbound Jira
+    /// webhook signatures on `/jira/events`. When set, the `X-Hub-Signature`
+    /// header on those requests must verify against one of them. When unset
+    /// (along with `JIRA_SITE_SECRETS`), inbound verification is skipped.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub jira_webhook_secret: Vec<String>,
+
+    /// Optional comma-separated `site=secret` pairs for Jira sites with their
+    /// own webhook secret. Events for such a site (by the `/jira/{site}/events`
+    /// path or detection via `JIRA_SITE_URLS`) are verified only against its
+    /// secrets; `JIRA_WEBHOOK_SECRET` applies only to `/jira/events`.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub jira_site_secrets: Vec<String>,
+
+    /// Optional comma-separated `name=base_url` pairs used to detect which
+    /// Jira site sent an event to the un-prefixed `/jira/events` path, from the
+    /// payload's `self` URLs (e.g. `acme=https://acme.atlassian.net`).
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub jira_site_urls: Vec<String>,
+
+    /// Optional comma-separated access tokens the GitHub API mock accepts
+    /// (the dud token in n8n's GitHub credential). When set, mock requests
+    /// without one of them get GitHub's `401` responses. When unset, the
+    /// mock accepts any caller.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub github_mock_token: Vec<String>,
+
+    /// Optional hostname of the GitHub Enterprise Server instance whose hooks
+    /// are registered through the mock's `/api/v3` API. Deliveries are routed
+    /// to those hooks only when their `X-GitHub-Enterprise-Host` matches it.
+    #[serde(default)]
+    pub github_enterprise_host: Option<String>,
+
+    /// Optional user (email or username) the Jira API mock expects in
+    /// basic-auth credentials. Only checked when `jira_mock_token` is set.
     #[serde(default)]
-    pub github_webhook_secret: Option<String>,
+    pub jira_mock_user: Option<String>,
+
+    /// Optional API token, password or personal access token the Jira API
+    /// mock accepts (basic auth or `Bearer`). When unset, the mock accepts
+    /// any caller.
+    #[serde(default)]
+    pub jira_mock_token: Option<String>,
 
     /// Path to the SQLite database file used for storing webhook secrets and
     /// trigger metadata. Defaults to `"unihook.db"` in the current working
@@ -43,15 +104,40 @@ pub struct Config {
     #[serde(default = "default_database_path")]
     pub database_path: String,
 
-    /// Secret token from the Zoom app Event Subscriptions settings.
-    /// Used for URL validation challenges and inbound webhook signature verification.
-    pub zoom_webhook_secret: String,
+    /// Comma-separated Secret Tokens from the Zoom app Event Subscriptions
+    /// settings. A request verifying against any of them is accepted, and URL
+    /// validation challenges are answered with the secret that signed them,
+    /// so the token can be regenerated without an outage.
+    #[serde(deserialize_with = "deserialize_comma_separated")]
+    pub zoom_webhook_secret: Vec<String>,
+
+    /// How far `x-zm-request-timestamp` may be from the current time (in
+    /// seconds) before a Zoom request is rejected as a replay. Signatures
+    /// seen within the window are rejected too. `0` disables both checks.
+    #[serde(default = "default_zoom_replay_window")]
+    pub zoom_replay_window_secs: u64,
 
     /// Comma-separated Zoom event types Unihook is allowed to forward.
-    /// Events not listed are acknowledged to Zoom but not routed to n8n.
+    /// Entries may use `*` globs (`meeting.*`), and entries prefixed with `!`
+    /// deny matching events even if another entry allows them. Events not
+    /// allowed are acknowledged to Zoom but not routed to n8n.
     #[serde(deserialize_with = "deserialize_comma_separated")]
     pub zoom_allowed_events: Vec<String>,
 
+    /// Optional comma-separated `account_id=secret` pairs for Zoom accounts
+    /// with their own Secret Token. Requests for such an account (by the
+    /// `/zoom/{account_id}/events` path or the payload's `account_id`) are
+    /// verified only against its secrets; other requests use
+    /// `ZOOM_WEBHOOK_SECRET`.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub zoom_account_secrets: Vec<String>,
+
+    /// Optional comma-separated `pattern=tier` pairs assigning Zoom event
+    /// types a sensitivity tier (`host` or `privileged`); the first matching
+    /// pattern wins. Events matching no pattern use `host` routing.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub zoom_event_tiers: Vec<String>,
+
     /// Comma-separated emails of n8n users whose personal-project Zoom workflows
     /// receive all allowlisted events (bypasses host-based routing).
     #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
@@ -61,6 +147,68 @@ pub struct Config {
     /// allowlisted events (bypasses host-based routing).
     #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
     pub zoom_privileged_workflow_ids: Vec<String>,
+
+    /// Optional comma-separated `pattern=mode` pairs enabling extra Zoom
+    /// routing modes per event type: `participant`, `alternative_host` or
+    /// `host_id`. Every matching entry applies, in addition to host routing.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub zoom_routing_modes: Vec<String>,
+
+    /// Optional comma-separated `user_id=email` pairs resolving Zoom
+    /// `host_id`s for the `host_id` routing mode. Takes precedence over
+    /// mappings learned from events.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub zoom_user_emails: Vec<String>,
+
+    /// Optional comma-separated `pattern=/json/pointer` pairs naming payload
+    /// fields redacted before a Zoom event is forwarded to non-privileged
+    /// triggers. Every matching entry applies; a `*` pointer segment matches
+    /// all array elements or object members.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub zoom_redact_fields: Vec<String>,
+
+    /// How `ZOOM_REDACT_FIELDS` are redacted: `mask` (replace the value with
+    /// `"[REDACTED]"`, default) or `remove`.
+    #[serde(default = "default_zoom_redaction_mode")]
+    pub zoom_redaction_mode: String,
+
+    /// Whether Jira triggers whose JQL filter Unihook can't evaluate still
+    /// receive issue events (`true`, default) or are skipped (`false`).
+    #[serde(default = "default_jira_jql_fail_open")]
+    pub jira_jql_fail_open: bool,
+
+    /// Whether GitHub and Jira triggers are also reconciled against the n8n
+    /// API (`true`, default). Trigger rows are created from provider-mock
+    /// registrations either way; polling adds workflow names and `unihook.*`
+    /// annotations and removes triggers n8n no longer reports.
+    #[serde(default = "default_n8n_trigger_reconcile")]
+    pub n8n_trigger_reconcile: bool,
+
+    /// How often to garbage-collect webhook secrets that no trigger
+    /// references (in seconds). `0` disables garbage collection.
+    #[serde(default = "default_webhook_secret_gc_interval")]
+    pub webhook_secret_gc_interval_secs: u64,
+
+    /// How long an orphaned webhook secret is kept in quarantine before it is
+    /// deleted (in seconds). `0` deletes orphaned secrets on the first pass.
+    #[serde(default = "default_webhook_secret_gc_grace")]
+    pub webhook_secret_gc_grace_secs: u64,
+
+    /// Optional hex-encoded 256-bit key (64 hex characters) for encrypting
+    /// webhook secrets stored in the database. When unset (and no key file is
+    /// configured), secrets are stored in plaintext.
+    #[serde(default)]
+    pub webhook_secret_key: Option<String>,
+
+    /// Optional path to a file containing the hex-encoded key, used when
+    /// `WEBHOOK_SECRET_KEY` is unset (e.g. a Docker secret).
+    #[serde(default)]
+    pub webhook_secret_key_file: Option<String>,
+
+    /// Optional comma-separated hex-encoded previous keys, still accepted for
+    /// decrypting secrets until `rotate-secret-key` re-encrypts them.
+    #[serde(default, deserialize_with = "deserialize_comma_separated_optional")]
+    pub webhook_secret_previous_keys: Vec<String>,
 }
 
 fn deserialize_comma_separated<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
@@ -111,6 +259,30 @@ fn default_database_path() -> String {
     "unihook.db".to_string()
 }
 
+fn default_zoom_replay_window() -> u64 {
+    300
+}
+
+fn default_zoom_redaction_mode() -> String {
+    "mask".to_string()
+}
+
+fn default_jira_jql_fail_open() -> bool {
+    true
+}
+
+fn default_n8n_trigger_reconcile() -> bool {
+    true
+}
+
+fn default_webhook_secret_gc_interval() -> u64 {
+    3600
+}
+
+fn default_webhook_secret_gc_grace() -> u64 {
+    86400
+}
+
 impl Config {
     /// Load configuration from environment variables.
     /// Environment variables should be prefixed with nothing (e.g., N8N_API_URL).
@@ -118,9 +290,146 @@ impl Config {
         envy::from_env::<Config>()
     }
 
-    /// Returns true if the given Zoom event type is on the platform allowlist.
+    /// Returns true if the given Zoom event type is on the platform allowlist:
+    /// an allow pattern matches it and no `!` deny pattern does.
     pub fn is_zoom_event_allowed(&self, event: &str) -> bool {
-        self.zoom_allowed_events.iter().any(|e| e == event)
+        let (deny, allow): (Vec<&str>, Vec<&str>) = self
+            .zoom_allowed_events
+            .iter()
+            .map(String::as_str)
+            .partition(|e| e.starts_with('!'));
+        allow.iter().any(|p| event_pattern_matches(p, event))
+            && !deny
+                .iter()
+                .any(|p| event_pattern_matches(p[1..].trim(), event))
+    }
+
+    /// The sensitivity tier of a Zoom event type from `ZOOM_EVENT_TIERS`.
+    /// Entries without `=` are ignored; unknown tier names are treated as
+    /// `privileged`.
+    pub fn zoom_event_tier(&self, event: &str) -> ZoomEventTier {
+        self.zoom_event_tiers
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .find(|(pattern, _)| event_pattern_matches(pattern.trim(), event))
+            .map(|(_, tier)| ZoomEventTier::parse(tier))
+            .unwrap_or_default()
+    }
+
+    /// Returns true if `url` is an n8n test-mode webhook URL
+    /// (`/{N8N_ENDPOINT_WEBHOOK_TEST}/...`) rather than a production one.
+    pub fn is_test_webhook_url(&self, url: &str) -> bool {
+        url.contains(&format!("/{}/", self.n8n_endpoint_webhook_test))
+    }
+
+    /// `(site, base_url)` pairs from `JIRA_SITE_URLS`. Entries without `=` are
+    /// ignored; site names are lowercased.
+    pub fn jira_sites(&self) -> Vec<(String, String)> {
+        self.jira_site_urls
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .map(|(name, url)| (name.trim().to_lowercase(), url.trim().to_string()))
+            .filter(|(name, url)| !name.is_empty() && !url.is_empty())
+            .collect()
+    }
+
+    /// Whether inbound Jira signature verification is enabled (any unscoped
+    /// or site secret is configured).
+    pub fn jira_signature_required(&self) -> bool {
+        !self.jira_webhook_secret.is_empty() || !self.jira_site_secrets.is_empty()
+    }
+
+    /// Secrets that may sign a Jira event for `site`: its `JIRA_SITE_SECRETS`
+    /// entries if it has any, else `JIRA_WEBHOOK_SECRET` — but only for events
+    /// on `/jira/events` (`site_in_path` false). A site named in the path with
+    /// no secrets of its own gets none, so its events fail verification.
+    pub fn jira_secrets_for_site(&self, site: &str, site_in_path: bool) -> Vec<String> {
+        let scoped: Vec<String> = self
+            .jira_site_secrets
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .filter(|(name, _)| !site.is_empty() && name.trim().to_lowercase() == site)
+            .map(|(_, secret)| secret.trim().to_string())
+            .filter(|secret| !secret.is_empty())
+            .collect();
+        if !scoped.is_empty() || site_in_path {
+            scoped
+        } else {
+            self.jira_webhook_secret.clone()
+        }
+    }
+
+    /// The `GITHUB_ENTERPRISE_HOST` hostname, trimmed and lowercased, or
+    /// `None` when unset.
+    pub fn github_enterprise_host(&self) -> Option<String> {
+        self.github_enterprise_host
+            .as_deref()
+            .map(|host| host.trim().to_lowercase())
+            .filter(|host| !host.is_empty())
+    }
+
+    /// Whether inbound GitHub signature verification is enabled (any global
+    /// or scoped secret is configured).
+    pub fn github_signature_required(&self) -> bool {
+        !self.github_webhook_secret.is_empty() || !self.github_webhook_scoped_secrets.is_empty()
+    }
+
+    /// Secrets an inbound GitHub event for `owner`/`repository` may be signed
+    /// with, as `(key, secret)` pairs: repository-scoped secrets first, then
+    /// owner-scoped, then the global `GITHUB_WEBHOOK_SECRET` list. The key
+    /// names the secret in logs without revealing it (`acme/api`, `acme`,
+    /// `global[1]`).
+    pub fn github_inbound_secrets(
+        &self,
+        owner: Option<&str>,
+        repository: Option<&str>,
+    ) -> Vec<(String, String)> {
+        let scoped: Vec<(String, &str)> = self
+            .github_webhook_scoped_secrets
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .map(|(scope, secret)| (scope.trim().to_lowercase(), secret.trim()))
+            .filter(|(scope, secret)| !scope.is_empty() && !secret.is_empty())
+            .collect();
+        let owner = owner.map(str::to_lowercase);
+        let full_name = owner
+            .as_ref()
+            .zip(repository)
+            .map(|(o, r)| format!("{o}/{}", r.to_lowercase()));
+
+        let mut secrets = Vec::new();
+        for wanted in [full_name, owner].into_iter().flatten() {
+            secrets.extend(
+                scoped
+                    .iter()
+                    .filter(|(scope, _)| *scope == wanted)
+                    .map(|(scope, secret)| (scope.clone(), secret.to_string())),
+            );
+        }
+        secrets.extend(
+            self.github_webhook_secret
+                .iter()
+                .enumerate()
+                .map(|(i, secret)| (format!("global[{i}]"), secret.clone())),
+        );
+        secrets
+    }
+
+    /// The cipher for webhook secrets stored in the database, from
+    /// `WEBHOOK_SECRET_KEY` or `WEBHOOK_SECRET_KEY_FILE`. Returns `None` when
+    /// neither is set (secrets are stored in plaintext).
+    pub fn secret_cipher(&self) -> Result<Option<SecretCipher>, SecretCipherError> {
+        let key = match (&self.webhook_secret_key, &self.webhook_secret_key_file) {
+            (Some(key), _) => key.clone(),
+            (None, Some(path)) => {
+                std::fs::read_to_string(path).map_err(|e| SecretCipherError::KeyFile {
+                    path: path.clone(),
+                    error: e.to_string(),
+                })?
+            }
+            (None, None) => return Ok(None),
+        };
+        SecretCipher::new(&key, &self.webhook_secret_previous_keys).map(Some)
     }
 
     /// Lowercase emails from `ZOOM_PRIVILEGED_USERS` for host-routing bypass lookups.
@@ -140,6 +449,71 @@ impl Config {
             .filter(|id| !id.is_empty())
             .collect()
     }
+
+    /// Secret Tokens that may sign a Zoom request for `account_id`: its
+    /// `ZOOM_ACCOUNT_SECRETS` entries if it has any, else `ZOOM_WEBHOOK_SECRET`.
+    pub fn zoom_secrets_for_account(&self, account_id: Option<&str>) -> Vec<String> {
+        let scoped: Vec<String> = account_id
+            .map(|account_id| {
+                self.zoom_account_secrets
+                    .iter()
+                    .filter_map(|entry| entry.split_once('='))
+                    .filter(|(id, _)| id.trim() == account_id)
+                    .map(|(_, secret)| secret.trim().to_string())
+                    .filter(|secret| !secret.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if scoped.is_empty() {
+            self.zoom_webhook_secret.clone()
+        } else {
+            scoped
+        }
+    }
+
+    /// Routing modes from `ZOOM_ROUTING_MODES` enabled for the given Zoom
+    /// event type. Unknown mode names are ignored.
+    pub fn zoom_routing_modes(&self, event: &str) -> Vec<ZoomRoutingMode> {
+        let mut modes = Vec::new();
+        for (pattern, mode) in self
+            .zoom_routing_modes
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+        {
+            if let Some(mode) = ZoomRoutingMode::parse(mode)
+                && event_pattern_matches(pattern.trim(), event)
+                && !modes.contains(&mode)
+            {
+                modes.push(mode);
+            }
+        }
+        modes
+    }
+
+    /// Email configured for a Zoom user ID in `ZOOM_USER_EMAILS`.
+    pub fn zoom_user_email(&self, user_id: &str) -> Option<&str> {
+        self.zoom_user_emails
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .find(|(id, _)| id.trim() == user_id)
+            .map(|(_, email)| email.trim())
+    }
+
+    /// JSON pointers from `ZOOM_REDACT_FIELDS` whose event pattern matches
+    /// the given Zoom event type.
+    pub fn zoom_redact_pointers(&self, event: &str) -> Vec<&str> {
+        self.zoom_redact_fields
+            .iter()
+            .filter_map(|entry| entry.split_once('='))
+            .filter(|(pattern, _)| event_pattern_matches(pattern.trim(), event))
+            .map(|(_, pointer)| pointer.trim())
+            .collect()
+    }
+
+    /// Parsed `ZOOM_REDACTION_MODE`.
+    pub fn zoom_redaction_mode(&self) -> ZoomRedactionMode {
+        ZoomRedactionMode::parse(&self.zoom_redaction_mode)
+    }
 }
 
 #[cfg(test)]
@@ -149,15 +523,38 @@ impl Config {
             n8n_api_url: "http://localhost:5678".to_string(),
             n8n_api_key: "test-key".to_string(),
             listen_addr: "0.0.0.0:3000".to_string(),
+            internal_listen_addr: None,
             refresh_interval_secs: 600,
             n8n_endpoint_webhook: "webhook".to_string(),
             n8n_endpoint_webhook_test: "webhook-test".to_string(),
-            github_webhook_secret: None,
+            github_webhook_secret: vec![],
+            github_webhook_scoped_secrets: vec![],
+            jira_webhook_secret: vec![],
+            jira_site_secrets: vec![],
+            jira_site_urls: vec![],
+            github_mock_token: vec![],
+            jira_mock_user: None,
+            jira_mock_token: None,
             database_path: ":memory:".to_string(),
-            zoom_webhook_secret: "test-zoom-secret".to_string(),
+            zoom_webhook_secret: vec!["test-zoom-secret".to_string()],
+            zoom_replay_window_secs: 300,
             zoom_allowed_events: vec!["meeting.started".to_string()],
+            zoom_account_secrets: vec![],
+            zoom_event_tiers: vec![],
             zoom_privileged_users: vec![],
             zoom_privileged_workflow_ids: vec![],
+            zoom_routing_modes: vec![],
+            zoom_user_emails: vec![],
+            zoom_redact_fields: vec![],
+            zoom_redaction_mode: "mask".to_string(),
+            jira_jql_fail_open: true,
+            n8n_trigger_reconcile: true,
+            webhook_secret_gc_interval_secs: 3600,
+            webhook_secret_gc_grace_secs: 86400,
+            webhook_secret_key: None,
+            github_enterprise_host: None,
+            webhook_secret_key_file: None,
+            webhook_secret_previous_keys: vec![],
         }
     }
 }
@@ -185,4 +582,210 @@ mod tests {
             vec!["a".to_string(), "b".to_string()]
         );
     }
+
+    #[test]
+    fn test_github_inbound_secrets_order_and_scope() {
+        let mut config = Config::test_default();
+        config.github_webhook_secret = vec!["old".to_string(), "new".to_string()];
+        config.github_webhook_scoped_secrets = vec![
+            "Acme=acme-secret".to_string(),
+            "acme/API=api-secret".to_string(),
+            "other/api=other-secret".to_string(),
+            "malformed".to_string(),
+        ];
+        let keys = |owner, repository| -> Vec<String> {
+            config
+                .github_inbound_secrets(owner, repository)
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect()
+        };
+
+        assert_eq!(
+            keys(Some("acme"), Some("api")),
+            vec!["acme/api", "acme", "global[0]", "global[1]"]
+        );
+        assert_eq!(
+            keys(Some("ACME"), None),
+            vec!["acme", "global[0]", "global[1]"]
+        );
+        assert_eq!(keys(None, None), vec!["global[0]", "global[1]"]);
+        assert_eq!(
+            config.github_inbound_secrets(Some("acme"), Some("api"))[0].1,
+            "api-secret"
+        );
+    }
+
+    #[test]
+    fn test_zoom_allowlist_patterns_and_deny_rules() {
+        let mut config = Config::test_default();
+        config.zoom_allowed_events = vec![
+            "meeting.*".to_string(),
+            "recording.completed".to_string(),
+            "!meeting.chat_message_sent".to_string(),
+        ];
+
+        assert!(config.is_zoom_event_allowed("meeting.started"));
+        assert!(config.is_zoom_event_allowed("meeting.participant_joined"));
+        assert!(config.is_zoom_event_allowed("recording.completed"));
+        assert!(!config.is_zoom_event_allowed("meeting.chat_message_sent"));
+        assert!(!config.is_zoom_event_allowed("webinar.started"));
+
+        // Deny rules alone allow nothing
+        config.zoom_allowed_events = vec!["!meeting.started".to_string()];
+        assert!(!config.is_zoom_event_allowed("meeting.ended"));
+    }
+
+    #[test]
+    fn test_zoom_event_tiers_first_match_wins() {
+        let mut config = Config::test_default();
+        config.zoom_event_tiers = vec![
+            "recording.started=host".to_string(),
+            "recording.*=privileged".to_string(),
+            "webinar.*=secret".to_string(),
+            "malformed".to_string(),
+        ];
+
+        assert_eq!(
+            config.zoom_event_tier("recording.completed"),
+            ZoomEventTier::Privileged
+        );
+        assert_eq!(
+            config.zoom_event_tier("recording.started"),
+            ZoomEventTier::Host
+        );
+        assert_eq!(
+            config.zoom_event_tier("meeting.started"),
+            ZoomEventTier::Host
+        );
+        // Unknown tier names fail closed
+        assert_eq!(
+            config.zoom_event_tier("webinar.started"),
+            ZoomEventTier::Privileged
+        );
+    }
+
+    #[test]
+    fn test_zoom_redact_pointers_per_event() {
+        let mut config = Config::test_default();
+        config.zoom_redact_fields = vec![
+            "recording.completed=/download_token".to_string(),
+            "recording.*=/payload/object/password".to_string(),
+            "malformed".to_string(),
+        ];
+
+        assert_eq!(
+            config.zoom_redact_pointers("recording.completed"),
+            vec!["/download_token", "/payload/object/password"]
+        );
+        assert_eq!(
+            config.zoom_redact_pointers("recording.started"),
+            vec!["/payload/object/password"]
+        );
+        assert!(config.zoom_redact_pointers("meeting.started").is_empty());
+    }
+
+    #[test]
+    fn test_zoom_secrets_for_account() {
+        let mut config = Config::test_default();
+        config.zoom_account_secrets = vec![
+            "sub1=sub-secret-old".to_string(),
+            "sub1=sub-secret-new".to_string(),
+            "other=".to_string(),
+        ];
+
+        assert_eq!(
+            config.zoom_secrets_for_account(Some("sub1")),
+            vec!["sub-secret-old", "sub-secret-new"]
+        );
+        assert_eq!(
+            config.zoom_secrets_for_account(Some("prod")),
+            vec!["test-zoom-secret"]
+        );
+        assert_eq!(
+            config.zoom_secrets_for_account(Some("other")),
+            vec!["test-zoom-secret"]
+        );
+        assert_eq!(
+            config.zoom_secrets_for_account(None),
+            vec!["test-zoom-secret"]
+        );
+    }
+
+    #[test]
+    fn test_zoom_routing_modes_per_event() {
+        let mut config = Config::test_default();
+        config.zoom_routing_modes = vec![
+            "meeting.participant_*=participant".to_string(),
+            "recording.completed=alternative_host".to_string(),
+            "*=host_id".to_string(),
+            "*=everyone".to_string(),
+        ];
+
+        assert_eq!(
+            config.zoom_routing_modes("meeting.participant_joined"),
+            vec![ZoomRoutingMode::Participant, ZoomRoutingMode::HostId]
+        );
+        assert_eq!(
+            config.zoom_routing_modes("recording.completed"),
+            vec![ZoomRoutingMode::AlternativeHost, ZoomRoutingMode::HostId]
+        );
+    }
+
+    #[test]
+    fn test_zoom_user_email_lookup() {
+        let mut config = Config::test_default();
+        config.zoom_user_emails = vec!["abc123 = host@example.com".to_string()];
+
+        assert_eq!(config.zoom_user_email("abc123"), Some("host@example.com"));
+        assert_eq!(config.zoom_user_email("other"), None);
+    }
+
+    #[test]
+    fn test_jira_secrets_for_site() {
+        let mut config = Config::test_default();
+        assert!(!config.jira_signature_required());
+
+        config.jira_webhook_secret = vec!["shared".to_string()];
+        config.jira_site_secrets = vec![
+            "Acme=acme-old".to_string(),
+            "acme = acme-new".to_string(),
+            "dc=".to_string(),
+        ];
+        assert!(config.jira_signature_required());
+
+        assert_eq!(
+            config.jira_secrets_for_site("acme", true),
+            vec!["acme-old", "acme-new"]
+        );
+        assert_eq!(
+            config.jira_secrets_for_site("acme", false),
+            vec!["acme-old", "acme-new"]
+        );
+        // Unscoped secrets only cover the un-prefixed route
+        assert_eq!(config.jira_secrets_for_site("dc", false), vec!["shared"]);
+        assert_eq!(config.jira_secrets_for_site("", false), vec!["shared"]);
+        assert!(config.jira_secrets_for_site("dc", true).is_empty());
+    }
+
+    #[test]
+    fn test_jira_sites_parsing() {
+        let mut config = Config::test_default();
+        config.jira_site_urls = vec![
+            "Acme=https://acme.atlassian.net".to_string(),
+            "dc = https://jira.corp.example.com".to_string(),
+            "malformed".to_string(),
+        ];
+
+        assert_eq!(
+            config.jira_sites(),
+            vec![
+                ("acme".to_string(), "https://acme.atlassian.net".to_string()),
+                (
+                    "dc".to_string(),
+                    "https://jira.corp.example.com".to_string()
+                ),
+            ]
+        );
+    }
 }
@@ -1,5 +1,8 @@
+use aes_gcm::aead::{Aead, AeadCore, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
 use hmac::{Hmac, Mac};
-use sha2::Sha256;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
 
 /// Verify an HMAC-SHA256 signature against a raw body and shared secret.
 ///
@@ -30,6 +33,21 @@ pub fn verify_hmac_sha256(secret: &str, body: &[u8], signature_header: &str) ->
     mac.verify_slice(&expected_bytes).is_ok()
 }
 
+/// Find which of several shared secrets produced an HMAC-SHA256 signature.
+///
+/// Used when a provider endpoint accepts deliveries signed with different
+/// secrets (e.g. one per Jira site). Returns the index of the first secret
+/// that verifies, or `None` if none do.
+pub fn find_hmac_sha256_secret(
+    secrets: &[String],
+    body: &[u8],
+    signature_header: &str,
+) -> Option<usize> {
+    secrets
+        .iter()
+        .position(|secret| verify_hmac_sha256(secret, body, signature_header))
+}
+
 /// Compute an HMAC-SHA256 signature for a body and return it in `sha256=<hex>` format.
 ///
 /// This is used for re-signing payloads (e.g., GitHub webhooks) before
@@ -40,6 +58,17 @@ pub fn compute_hmac_sha256(secret: &str, body: &[u8]) -> String {
     format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
 }
 
+/// Compute a legacy HMAC-SHA1 signature for a body and return it in
+/// `sha1=<hex>` format.
+///
+/// GitHub still sends this as `X-Hub-Signature` alongside
+/// `X-Hub-Signature-256`, so forwarded GitHub payloads are re-signed with both.
+pub fn compute_hmac_sha1(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key");
+    mac.update(body);
+    format!("sha1={}", hex::encode(mac.finalize().into_bytes()))
+}
+
 /// Verify a Zoom webhook signature (`x-zm-signature` header).
 ///
 /// Zoom signs the message `v0:{timestamp}:{body}` with HMAC-SHA256 and sends
@@ -54,6 +83,22 @@ pub fn verify_zoom_webhook_signature(
     constant_time_eq(signature.as_bytes(), expected.as_bytes())
 }
 
+/// Find which of several Zoom Secret Tokens produced an `x-zm-signature`.
+///
+/// Accepting more than one secret lets the Zoom app's Secret Token be
+/// regenerated without rejecting deliveries signed with the old one.
+/// Returns the index of the first secret that verifies.
+pub fn find_zoom_webhook_secret(
+    secrets: &[String],
+    body: &[u8],
+    timestamp: &str,
+    signature: &str,
+) -> Option<usize> {
+    secrets
+        .iter()
+        .position(|secret| verify_zoom_webhook_signature(secret, body, timestamp, signature))
+}
+
 /// Compute a Zoom webhook signature in `v0=<hex>` format.
 pub fn compute_zoom_webhook_signature(secret: &str, timestamp: &str, body: &[u8]) -> String {
     let message = format!(
@@ -75,6 +120,213 @@ pub fn compute_zoom_url_validation_token(secret: &str, plain_token: &str) -> Str
     hex::encode(mac.finalize().into_bytes())
 }
 
+/// Compare a presented credential with an expected one in constant time
+/// (for credentials of equal length).
+pub fn credentials_match(presented: &str, expected: &str) -> bool {
+    constant_time_eq(presented.as_bytes(), expected.as_bytes())
+}
+
+/// Prefix of webhook secrets encrypted by [`SecretCipher`].
+const ENCRYPTED_SECRET_PREFIX: &str = "enc:v1:";
+
+/// AES-GCM nonce length in bytes.
+const NONCE_LEN: usize = 12;
+
+/// Errors from loading secret encryption keys or decrypting stored secrets.
+#[derive(Debug, thiserror::Error)]
+pub enum SecretCipherError {
+    #[error("invalid encryption key: {0}")]
+    InvalidKey(String),
+
+    #[error("failed to read encryption key file {path}: {error}")]
+    KeyFile { path: String, error: String },
+
+    #[error("secret was encrypted with unknown key {0}")]
+    UnknownKey(String),
+
+    #[error("malformed encrypted secret")]
+    Malformed,
+
+    #[error("failed to decrypt secret")]
+    Decrypt,
+}
+
+/// A 256-bit key encryption key and its short ID (the first 8 hex digits of
+/// its SHA-256 hash), which is stored with each secret it encrypts.
+#[derive(Clone)]
+struct SecretKey {
+    id: String,
+    cipher: Aes256Gcm,
+}
+
+impl SecretKey {
+    fn from_hex(hex_key: &str) -> Result<Self, SecretCipherError> {
+        let bytes = hex::decode(hex_key.trim())
+            .map_err(|e| SecretCipherError::InvalidKey(format!("not hex: {e}")))?;
+        if bytes.len() != 32 {
+            return Err(SecretCipherError::InvalidKey(format!(
+                "expected 32 bytes (64 hex characters), got {}",
+                bytes.len()
+            )));
+        }
+        Ok(Self {
+            id: hex::encode(&Sha256::digest(&bytes)[..4]),
+            cipher: aes_key_init(&bytes),
+        })
+    }
+}
+
+/// Envelope encryption for webhook secrets stored in SQLite.
+///
+/// Each secret is encrypted with its own random AES-256-GCM data key, and the
+/// data key is encrypted ("wrapped") with the configured key encryption key.
+/// The stored value is
+/// `enc:v1:{key_id}:{hex(nonce || wrapped data key)}:{hex(nonce || ciphertext)}`.
+/// Rotating the key encryption key only re-wraps the data keys.
+///
+/// Values without the `enc:v1:` prefix are plaintext secrets written before
+/// encryption was enabled; they are returned unchanged.
+#[derive(Clone)]
+pub struct SecretCipher {
+    current: SecretKey,
+    /// Older keys, used only to decrypt secrets not yet rotated
+    previous: Vec<SecretKey>,
+}
+
+impl SecretCipher {
+    /// Build a cipher from hex-encoded 256-bit keys: the current key, used
+    /// for encryption, and previous keys still accepted for decryption.
+    pub fn new(current_hex: &str, previous_hex: &[String]) -> Result<Self, SecretCipherError> {
+        Ok(Self {
+            current: SecretKey::from_hex(current_hex)?,
+            previous: previous_hex
+                .iter()
+                .map(|k| SecretKey::from_hex(k))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// ID of the key new secrets are encrypted with.
+    pub fn key_id(&self) -> &str {
+        &self.current.id
+    }
+
+    /// Returns true if `stored` is an encrypted secret (of any key).
+    pub fn is_encrypted(stored: &str) -> bool {
+        stored.starts_with(ENCRYPTED_SECRET_PREFIX)
+    }
+
+    /// Returns true if `stored` is encrypted with the current key.
+    pub fn is_current(&self, stored: &str) -> bool {
+        stored
+            .strip_prefix(ENCRYPTED_SECRET_PREFIX)
+            .and_then(|rest| rest.split(':').next())
+            .is_some_and(|id| id == self.current.id)
+    }
+
+    /// Encrypt a secret with a fresh data key wrapped by the current key.
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        // `KeyInit` is called by path: its `new_from_slice` clashes with `Mac`'s
+        let data_key = <Aes256Gcm as aes_gcm::KeyInit>::generate_key(&mut OsRng);
+        let data_cipher = aes_key_init(&data_key);
+        let sealed = seal(&data_cipher, plaintext.as_bytes());
+        let wrapped = seal(&self.current.cipher, data_key.as_slice());
+        format!(
+            "{ENCRYPTED_SECRET_PREFIX}{}:{}:{}",
+            self.current.id,
+            hex::encode(wrapped),
+            hex::encode(sealed)
+        )
+    }
+
+    /// Decrypt a stored secret. Plaintext values are returned unchanged.
+    pub fn decrypt(&self, stored: &str) -> Result<String, SecretCipherError> {
+        let Some((key, wrapped, sealed)) = self.parse(stored)? else {
+            return Ok(stored.to_string());
+        };
+        let data_key = open(&key.cipher, &wrapped)?;
+        if data_key.len() != 32 {
+            return Err(SecretCipherError::Malformed);
+        }
+        let data_cipher = aes_key_init(&data_key);
+        let plaintext = open(&data_cipher, &sealed)?;
+        String::from_utf8(plaintext).map_err(|_| SecretCipherError::Malformed)
+    }
+
+    /// Re-encrypt a stored secret under the current key: plaintext is
+    /// encrypted, and secrets of previous keys have their data key
+    /// re-wrapped. Returns `None` if it already uses the current key.
+    pub fn reencrypt(&self, stored: &str) -> Result<Option<String>, SecretCipherError> {
+        if self.is_current(stored) {
+            return Ok(None);
+        }
+        let Some((key, wrapped, sealed)) = self.parse(stored)? else {
+            return Ok(Some(self.encrypt(stored)));
+        };
+        let data_key = open(&key.cipher, &wrapped)?;
+        let rewrapped = seal(&self.current.cipher, &data_key);
+        Ok(Some(format!(
+            "{ENCRYPTED_SECRET_PREFIX}{}:{}:{}",
+            self.current.id,
+            hex::encode(rewrapped),
+            hex::encode(sealed)
+        )))
+    }
+
+    /// Split an encrypted value into its key, wrapped data key and sealed
+    /// secret. Returns `None` for plaintext values.
+    #[allow(clippy::type_complexity)]
+    fn parse(
+        &self,
+        stored: &str,
+    ) -> Result<Option<(&SecretKey, Vec<u8>, Vec<u8>)>, SecretCipherError> {
+        let Some(rest) = stored.strip_prefix(ENCRYPTED_SECRET_PREFIX) else {
+            return Ok(None);
+        };
+        let mut parts = rest.split(':');
+        let (Some(key_id), Some(wrapped), Some(sealed), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(SecretCipherError::Malformed);
+        };
+        let key = std::iter::once(&self.current)
+            .chain(&self.previous)
+            .find(|k| k.id == key_id)
+            .ok_or_else(|| SecretCipherError::UnknownKey(key_id.to_string()))?;
+        let wrapped = hex::decode(wrapped).map_err(|_| SecretCipherError::Malformed)?;
+        let sealed = hex::decode(sealed).map_err(|_| SecretCipherError::Malformed)?;
+        Ok(Some((key, wrapped, sealed)))
+    }
+}
+
+/// Build an AES-256-GCM cipher from a 32-byte key.
+fn aes_key_init(key: &[u8]) -> Aes256Gcm {
+    <Aes256Gcm as aes_gcm::KeyInit>::new(Key::<Aes256Gcm>::from_slice(key))
+}
+
+/// Encrypt with a random nonce, returning `nonce || ciphertext`.
+fn seal(cipher: &Aes256Gcm, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let mut out = nonce.to_vec();
+    out.extend(
+        cipher
+            .encrypt(&nonce, plaintext)
+            .expect("AES-GCM encryption of a short secret cannot fail"),
+    );
+    out
+}
+
+/// Decrypt `nonce || ciphertext` produced by [`seal`].
+fn open(cipher: &Aes256Gcm, sealed: &[u8]) -> Result<Vec<u8>, SecretCipherError> {
+    if sealed.len() < NONCE_LEN {
+        return Err(SecretCipherError::Malformed);
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| SecretCipherError::Decrypt)
+}
+
 fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
@@ -167,6 +419,15 @@ mod tests {
         assert_eq!(signature, ATLASSIAN_TEST_SIGNATURE);
     }
 
+    #[test]
+    fn test_compute_hmac_sha1_test_vector() {
+        // Same secret and payload as the SHA-256 vector above
+        assert_eq!(
+            compute_hmac_sha1(ATLASSIAN_TEST_SECRET, ATLASSIAN_TEST_PAYLOAD.as_bytes()),
+            "sha1=c515f330a339e006a451ec24110648f028c87a11"
+        );
+    }
+
     #[test]
     fn test_compute_and_verify_roundtrip() {
         let secret = "my-test-secret";
@@ -205,6 +466,23 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_find_zoom_webhook_secret_returns_matching_index() {
+        let timestamp = "1739923528";
+        let body = b"{}";
+        let signature = compute_zoom_webhook_signature("new-secret", timestamp, body);
+        let secrets = vec!["old-secret".to_string(), "new-secret".to_string()];
+
+        assert_eq!(
+            find_zoom_webhook_secret(&secrets, body, timestamp, &signature),
+            Some(1)
+        );
+        assert_eq!(
+            find_zoom_webhook_secret(&secrets[..1], body, timestamp, &signature),
+            None
+        );
+    }
+
     #[test]
     fn test_zoom_url_validation_token() {
         let token = compute_zoom_url_validation_token("my-secret", "plain-token-123");
@@ -212,6 +490,43 @@ mod tests {
         assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
     }
 
+    #[test]
+    fn test_find_hmac_sha256_secret_returns_matching_index() {
+        let secrets = vec![
+            "site-a-secret".to_string(),
+            ATLASSIAN_TEST_SECRET.to_string(),
+        ];
+        assert_eq!(
+            find_hmac_sha256_secret(
+                &secrets,
+                ATLASSIAN_TEST_PAYLOAD.as_bytes(),
+                ATLASSIAN_TEST_SIGNATURE
+            ),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_find_hmac_sha256_secret_none_match() {
+        let secrets = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            find_hmac_sha256_secret(
+                &secrets,
+                ATLASSIAN_TEST_PAYLOAD.as_bytes(),
+                ATLASSIAN_TEST_SIGNATURE
+            ),
+            None
+        );
+        assert_eq!(
+            find_hmac_sha256_secret(
+                &[],
+                ATLASSIAN_TEST_PAYLOAD.as_bytes(),
+                ATLASSIAN_TEST_SIGNATURE
+            ),
+            None
+        );
+    }
+
     #[test]
     fn test_github_style_signature_verification() {
         // GitHub uses sha256=<hex> format for X-Hub-Signature-256
@@ -221,4 +536,78 @@ mod tests {
         assert!(signature.starts_with("sha256="));
         assert!(verify_hmac_sha256(secret, body, &signature));
     }
+
+    #[test]
+    fn test_credentials_match() {
+        assert!(credentials_match("dud-token", "dud-token"));
+        assert!(!credentials_match("dud-tokeN", "dud-token"));
+        assert!(!credentials_match("dud", "dud-token"));
+        assert!(!credentials_match("", "dud-token"));
+    }
+
+    // ── SecretCipher tests ──────────────────────────────────────────────
+
+    const KEY_A: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const KEY_B: &str = "1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100";
+
+    #[test]
+    fn test_secret_cipher_roundtrip() {
+        let cipher = SecretCipher::new(KEY_A, &[]).unwrap();
+        let stored = cipher.encrypt("n8n-webhook-secret");
+
+        assert!(SecretCipher::is_encrypted(&stored));
+        assert!(!stored.contains("n8n-webhook-secret"));
+        assert_eq!(cipher.decrypt(&stored).unwrap(), "n8n-webhook-secret");
+        // Fresh data key and nonces each time
+        assert_ne!(cipher.encrypt("n8n-webhook-secret"), stored);
+    }
+
+    #[test]
+    fn test_secret_cipher_passes_plaintext_through() {
+        let cipher = SecretCipher::new(KEY_A, &[]).unwrap();
+        assert_eq!(
+            cipher.decrypt("legacy-plaintext").unwrap(),
+            "legacy-plaintext"
+        );
+    }
+
+    #[test]
+    fn test_secret_cipher_rotation_rewraps_data_key() {
+        let old = SecretCipher::new(KEY_A, &[]).unwrap();
+        let stored = old.encrypt("s3cret");
+
+        let rotated = SecretCipher::new(KEY_B, &[KEY_A.to_string()]).unwrap();
+        assert!(!rotated.is_current(&stored));
+        assert_eq!(rotated.decrypt(&stored).unwrap(), "s3cret");
+
+        let rewrapped = rotated.reencrypt(&stored).unwrap().unwrap();
+        assert!(rotated.is_current(&rewrapped));
+        assert_eq!(rotated.reencrypt(&rewrapped).unwrap(), None);
+
+        // The old key is no longer needed
+        let new_only = SecretCipher::new(KEY_B, &[]).unwrap();
+        assert_eq!(new_only.decrypt(&rewrapped).unwrap(), "s3cret");
+        assert!(matches!(
+            new_only.decrypt(&stored),
+            Err(SecretCipherError::UnknownKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_secret_cipher_rejects_tampered_and_invalid_input() {
+        let cipher = SecretCipher::new(KEY_A, &[]).unwrap();
+        let mut stored = cipher.encrypt("s3cret");
+        let last = stored.pop().unwrap();
+        stored.push(if last == '0' { '1' } else { '0' });
+        assert!(matches!(
+            cipher.decrypt(&stored),
+            Err(SecretCipherError::Decrypt)
+        ));
+        assert!(matches!(
+            cipher.decrypt("enc:v1:garbage"),
+            Err(SecretCipherError::Malformed)
+        ));
+        assert!(SecretCipher::new("abcd", &[]).is_err());
+        assert!(SecretCipher::new("not-hex", &[]).is_err());
+    }
 }
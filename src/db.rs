@@ -3,9 +3,10 @@ use rusqlite::{Connection, OptionalExtension};
 use std::collections::HashSet;
 use tracing::{debug, info, warn};
 
+use crate::crypto::SecretCipher;
 use crate::github::GitHubTriggerConfig;
-use crate::jira::JiraTriggerConfig;
-use crate::slack::SlackTriggerConfig;
+use crate::jira::{JiraTriggerConfig, jql_project_scope};
+use crate::slack::{SlackThreadFilter, SlackTriggerConfig};
 use crate::zoom::ZoomTriggerConfig;
 
 /// Lightweight SQLite-backed store for webhook secrets and trigger metadata.
@@ -16,9 +17,15 @@ use crate::zoom::ZoomTriggerConfig;
 /// n8n's `staticData` to be populated.
 ///
 /// **Trigger metadata** is written by the periodic sync job (same data that was
-/// previously kept in `Arc<RwLock<Vec<TriggerConfig>>>`).
+/// previously kept in `Arc<RwLock<Vec<TriggerConfig>>>`). GitHub and Jira
+/// trigger rows are also written directly by the provider mocks when n8n
+/// registers or deletes a webhook; the sync only reconciles those.
+///
+/// With a [`SecretCipher`] (`WEBHOOK_SECRET_KEY`), webhook secrets are
+/// encrypted before they are written and decrypted when read.
 pub struct Database {
     conn: Mutex<Connection>,
+    cipher: Option<SecretCipher>,
 }
 
 // ── Row types returned by query methods ─────────────────────────────────────
@@ -31,6 +38,14 @@ pub struct GitHubTriggerRow {
     pub events: Vec<String>,
     /// HMAC secret from `webhook_secrets` (if captured by provider mock or staticData fallback).
     pub secret: Option<String>,
+    /// `event.action` filters from the `unihook.actions` annotation.
+    pub actions: Vec<String>,
+    /// Registered through the GitHub Enterprise Server API (`/api/v3`)
+    /// rather than github.com's.
+    pub enterprise: bool,
+    /// Hostname of the GHES instance the hook was registered for (`""` for
+    /// github.com, or when no host was known at registration).
+    pub enterprise_host: String,
 }
 
 /// A Jira trigger row from the database.
@@ -39,6 +54,85 @@ pub struct JiraTriggerRow {
     pub workflow_name: String,
     pub workflow_active: bool,
     pub events: Vec<String>,
+    pub jql_filter: Option<String>,
+    /// Jira site the trigger's credential points at (`""` for the default site).
+    pub site: String,
+    pub exclude_body: bool,
+    pub include_fields: Vec<String>,
+    pub projects: Vec<String>,
+}
+
+/// A GitHub repository hook registration as received by the provider mock.
+pub struct GitHubHookRegistration {
+    pub owner: String,
+    pub repository: String,
+    /// n8n webhook ID extracted from `config.url`
+    pub webhook_id: String,
+    pub name: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub config_url: String,
+    pub content_type: String,
+    /// `config.secret`; `None` keeps the stored secret (PATCH without a secret)
+    pub secret: Option<String>,
+    /// Whether `config_url` is n8n's production (not test-mode) webhook URL
+    pub production: bool,
+    /// Registered through the GitHub Enterprise Server API (`/api/v3`)
+    pub enterprise: bool,
+    /// Hostname of the GHES instance (`GITHUB_ENTERPRISE_HOST`), lowercased
+    pub enterprise_host: String,
+}
+
+/// A GitHub repository hook captured by the provider mock.
+///
+/// The hook `id` is the ID of the hook's `webhook_secrets` row, so hook IDs
+/// handed out before hooks were persisted keep resolving to their secret.
+pub struct GitHubHookRow {
+    pub id: i64,
+    pub owner: String,
+    pub repository: String,
+    pub webhook_id: String,
+    pub name: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub config_url: String,
+    pub content_type: String,
+    /// HMAC secret from `webhook_secrets`
+    pub secret: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Registered through the GitHub Enterprise Server API (`/api/v3`)
+    pub enterprise: bool,
+    /// Hostname of the GHES instance (`GITHUB_ENTERPRISE_HOST`), lowercased
+    pub enterprise_host: String,
+}
+
+/// A Jira webhook registration as received by the provider mock.
+pub struct JiraWebhookRegistration {
+    /// Site from the mock path the credential used (`""` for the default site)
+    pub site: String,
+    /// n8n webhook ID extracted from `url` (`""` if it couldn't be parsed)
+    pub webhook_id: String,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<String>,
+    pub filters: serde_json::Value,
+    pub exclude_body: bool,
+    /// Whether `url` is n8n's production (not test-mode) webhook URL
+    pub production: bool,
+}
+
+/// A Jira webhook registration captured by the provider mock.
+pub struct JiraWebhookRow {
+    pub id: i64,
+    pub site: String,
+    pub webhook_id: String,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<String>,
+    /// Raw `filters` object from the registration (e.g. `issue-related-events-section`)
+    pub filters: serde_json::Value,
+    pub exclude_body: bool,
 }
 
 /// A Zoom trigger row from the database.
@@ -53,6 +147,8 @@ pub struct ZoomTriggerRow {
     #[allow(dead_code)]
     pub project_id: String,
     pub project_type: String,
+    /// Zoom account the trigger is scoped to (`unihook.account`)
+    pub account_id: Option<String>,
 }
 
 /// A Slack trigger row from the database.
@@ -63,10 +159,84 @@ pub struct SlackTriggerRow {
     pub event_type: String,
     pub channels: Vec<String>,
     pub watch_whole_workspace: bool,
+    /// `None` when the trigger has no `unihook.channel_types` filter
+    pub channel_types: Option<Vec<String>>,
+    pub thread_filter: SlackThreadFilter,
+}
+
+/// A webhook secret removed by `collect_orphaned_webhook_secrets`.
+#[derive(Debug)]
+pub struct DeletedWebhookSecret {
+    pub webhook_id: String,
+    pub provider: String,
+    pub created_at: Option<String>,
+    pub orphaned_at: String,
+}
+
+/// What a `collect_orphaned_webhook_secrets` pass changed.
+#[derive(Debug, Default)]
+pub struct WebhookSecretGcReport {
+    /// Webhook IDs whose secrets were found orphaned and quarantined
+    pub quarantined: Vec<String>,
+    /// Webhook IDs whose quarantined secrets are referenced by a trigger again
+    pub restored: Vec<String>,
+    /// Secrets deleted after their grace period
+    pub deleted: Vec<DeletedWebhookSecret>,
+}
+
+/// What an `encrypt_webhook_secrets` pass changed.
+#[derive(Debug, Default)]
+pub struct SecretEncryptionReport {
+    /// Plaintext secrets that were encrypted
+    pub encrypted: usize,
+    /// Secrets of a previous key re-encrypted under the current key
+    pub rotated: usize,
+    /// Webhook IDs whose secrets couldn't be decrypted (unknown key, or no
+    /// key configured)
+    pub unreadable: Vec<String>,
 }
 
 type TriggerDedupSortKey = (String, bool, String, String);
 
+const ZOOM_TRIGGER_MIGRATIONS: &[&str] = &[
+    "ALTER TABLE zoom_triggers ADD COLUMN owner_email TEXT",
+    "ALTER TABLE zoom_triggers ADD COLUMN project_id TEXT NOT NULL DEFAULT ''",
+    "ALTER TABLE zoom_triggers ADD COLUMN project_type TEXT NOT NULL DEFAULT ''",
+    "ALTER TABLE zoom_triggers ADD COLUMN account_id TEXT",
+];
+
+const WEBHOOK_SECRET_MIGRATIONS: &[&str] =
+    &["ALTER TABLE webhook_secrets ADD COLUMN orphaned_at TEXT"];
+
+const GITHUB_TRIGGER_MIGRATIONS: &[&str] = &[
+    "ALTER TABLE github_triggers ADD COLUMN actions TEXT NOT NULL DEFAULT '[]'",
+    "ALTER TABLE github_triggers ADD COLUMN enterprise BOOLEAN NOT NULL DEFAULT 0",
+    "ALTER TABLE github_triggers ADD COLUMN installation_id INTEGER",
+    "ALTER TABLE github_triggers ADD COLUMN enterprise_host TEXT NOT NULL DEFAULT ''",
+];
+
+const GITHUB_HOOK_MIGRATIONS: &[&str] = &[
+    "ALTER TABLE github_hooks ADD COLUMN enterprise BOOLEAN NOT NULL DEFAULT 0",
+    "ALTER TABLE github_hooks ADD COLUMN enterprise_host TEXT NOT NULL DEFAULT ''",
+];
+
+const JIRA_TRIGGER_MIGRATIONS: &[&str] = &[
+    "ALTER TABLE jira_triggers ADD COLUMN jql_filter TEXT",
+    "ALTER TABLE jira_triggers ADD COLUMN site TEXT NOT NULL DEFAULT ''",
+    "ALTER TABLE jira_triggers ADD COLUMN exclude_body BOOLEAN NOT NULL DEFAULT 0",
+    "ALTER TABLE jira_triggers ADD COLUMN include_fields TEXT NOT NULL DEFAULT '[]'",
+    "ALTER TABLE jira_triggers ADD COLUMN projects TEXT NOT NULL DEFAULT '[]'",
+];
+
+const JIRA_WEBHOOK_MIGRATIONS: &[&str] =
+    &["ALTER TABLE jira_webhooks ADD COLUMN production BOOLEAN NOT NULL DEFAULT 1"];
+
+const SLACK_TRIGGER_MIGRATIONS: &[&str] = &[
+    "ALTER TABLE slack_triggers ADD COLUMN channel_types TEXT NOT NULL DEFAULT '[]'",
+    "ALTER TABLE slack_triggers ADD COLUMN thread_filter TEXT NOT NULL DEFAULT 'any'",
+    "ALTER TABLE slack_triggers ADD COLUMN channel_types_set BOOLEAN NOT NULL DEFAULT 0",
+];
+
 impl Database {
     /// Open (or create) the database at `path` and run migrations.
     /// Use `":memory:"` for an in-memory database (useful for tests).
@@ -76,12 +246,19 @@ impl Database {
         conn.execute_batch("PRAGMA journal_mode=WAL;")?;
         let db = Self {
             conn: Mutex::new(conn),
+            cipher: None,
         };
         db.create_schema()?;
         info!(path = %path, "Database opened and schema verified");
         Ok(db)
     }
 
+    /// Encrypt webhook secrets with `cipher` (see `Config::secret_cipher`).
+    pub fn with_secret_cipher(mut self, cipher: Option<SecretCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
     fn create_schema(&self) -> Result<(), rusqlite::Error> {
         let conn = self.conn.lock();
         conn.execute_batch(
@@ -91,7 +268,8 @@ impl Database {
                 webhook_id TEXT NOT NULL UNIQUE,
                 provider TEXT NOT NULL,
                 secret TEXT NOT NULL,
-                created_at TEXT DEFAULT (datetime('now'))
+                created_at TEXT DEFAULT (datetime('now')),
+                orphaned_at TEXT
             );
 
             CREATE TABLE IF NOT EXISTS github_triggers (
@@ -102,6 +280,38 @@ impl Database {
                 owner TEXT NOT NULL DEFAULT '',
                 repository TEXT NOT NULL DEFAULT '',
                 events TEXT NOT NULL DEFAULT '[]',
+                actions TEXT NOT NULL DEFAULT '[]',
+                enterprise BOOLEAN NOT NULL DEFAULT 0,
+                installation_id INTEGER,
+                enterprise_host TEXT NOT NULL DEFAULT '',
+                updated_at TEXT DEFAULT (datetime('now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS github_installations (
+                installation_id INTEGER PRIMARY KEY,
+                account_login TEXT NOT NULL DEFAULT '',
+                updated_at TEXT DEFAULT (datetime('now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS github_installation_repositories (
+                installation_id INTEGER NOT NULL,
+                full_name TEXT NOT NULL COLLATE NOCASE,
+                PRIMARY KEY (installation_id, full_name)
+            );
+
+            CREATE TABLE IF NOT EXISTS github_hooks (
+                id INTEGER PRIMARY KEY,
+                owner TEXT NOT NULL DEFAULT '',
+                repository TEXT NOT NULL DEFAULT '',
+                webhook_id TEXT NOT NULL UNIQUE,
+                name TEXT NOT NULL DEFAULT 'web',
+                events TEXT NOT NULL DEFAULT '[]',
+                active BOOLEAN NOT NULL DEFAULT 1,
+                config_url TEXT NOT NULL DEFAULT '',
+                content_type TEXT NOT NULL DEFAULT 'json',
+                enterprise BOOLEAN NOT NULL DEFAULT 0,
+                enterprise_host TEXT NOT NULL DEFAULT '',
+                created_at TEXT DEFAULT (datetime('now')),
                 updated_at TEXT DEFAULT (datetime('now'))
             );
 
@@ -111,9 +321,27 @@ impl Database {
                 workflow_name TEXT NOT NULL,
                 workflow_active BOOLEAN NOT NULL DEFAULT 0,
                 events TEXT NOT NULL DEFAULT '[]',
+                jql_filter TEXT,
+                site TEXT NOT NULL DEFAULT '',
+                exclude_body BOOLEAN NOT NULL DEFAULT 0,
+                include_fields TEXT NOT NULL DEFAULT '[]',
+                projects TEXT NOT NULL DEFAULT '[]',
                 updated_at TEXT DEFAULT (datetime('now'))
             );
 
+            CREATE TABLE IF NOT EXISTS jira_webhooks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                site TEXT NOT NULL DEFAULT '',
+                webhook_id TEXT NOT NULL DEFAULT '',
+                name TEXT NOT NULL DEFAULT '',
+                url TEXT NOT NULL UNIQUE,
+                events TEXT NOT NULL DEFAULT '[]',
+                filters TEXT NOT NULL DEFAULT '{}',
+                exclude_body BOOLEAN NOT NULL DEFAULT 0,
+                production BOOLEAN NOT NULL DEFAULT 1,
+                created_at TEXT DEFAULT (datetime('now'))
+            );
+
             CREATE TABLE IF NOT EXISTS slack_triggers (
                 webhook_id TEXT PRIMARY KEY,
                 workflow_id TEXT NOT NULL,
@@ -122,6 +350,9 @@ impl Database {
                 event_type TEXT NOT NULL DEFAULT '',
                 channels TEXT NOT NULL DEFAULT '[]',
                 watch_whole_workspace BOOLEAN NOT NULL DEFAULT 0,
+                channel_types TEXT NOT NULL DEFAULT '[]',
+                channel_types_set BOOLEAN NOT NULL DEFAULT 0,
+                thread_filter TEXT NOT NULL DEFAULT 'any',
                 updated_at TEXT DEFAULT (datetime('now'))
             );
 
@@ -134,20 +365,34 @@ impl Database {
                 owner_email TEXT,
                 project_id TEXT NOT NULL DEFAULT '',
                 project_type TEXT NOT NULL DEFAULT '',
+                account_id TEXT,
+                updated_at TEXT DEFAULT (datetime('now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS zoom_users (
+                user_id TEXT PRIMARY KEY,
+                email TEXT NOT NULL,
                 updated_at TEXT DEFAULT (datetime('now'))
             );
             ",
         )?;
-        Self::apply_zoom_trigger_migrations(&conn)?;
+        Self::apply_column_migrations(&conn, WEBHOOK_SECRET_MIGRATIONS)?;
+        Self::apply_column_migrations(&conn, ZOOM_TRIGGER_MIGRATIONS)?;
+        Self::apply_column_migrations(&conn, GITHUB_TRIGGER_MIGRATIONS)?;
+        Self::apply_column_migrations(&conn, GITHUB_HOOK_MIGRATIONS)?;
+        Self::apply_column_migrations(&conn, JIRA_TRIGGER_MIGRATIONS)?;
+        Self::apply_column_migrations(&conn, JIRA_WEBHOOK_MIGRATIONS)?;
+        Self::apply_column_migrations(&conn, SLACK_TRIGGER_MIGRATIONS)?;
         Ok(())
     }
 
-    fn apply_zoom_trigger_migrations(conn: &rusqlite::Connection) -> Result<(), rusqlite::Error> {
-        let migrations = [
-            "ALTER TABLE zoom_triggers ADD COLUMN owner_email TEXT",
-            "ALTER TABLE zoom_triggers ADD COLUMN project_id TEXT NOT NULL DEFAULT ''",
-            "ALTER TABLE zoom_triggers ADD COLUMN project_type TEXT NOT NULL DEFAULT ''",
-        ];
+    /// Add columns introduced after a table was first created. `ADD COLUMN`
+    /// on an up-to-date table fails with "duplicate column name", which is
+    /// ignored so the migrations are idempotent.
+    fn apply_column_migrations(
+        conn: &rusqlite::Connection,
+        migrations: &[&str],
+    ) -> Result<(), rusqlite::Error> {
         for sql in migrations {
             if let Err(e) = conn.execute(sql, []) {
                 let msg = e.to_string();
@@ -164,13 +409,16 @@ impl Database {
     /// Insert or update a webhook secret captured by a provider mock endpoint.
     ///
     /// Returns the numeric row ID (used as the external "hook id" in mock API
-    /// responses so n8n can reference it during DELETE).
+    /// responses so n8n can reference it during DELETE). The GitHub mock
+    /// stores secrets through `upsert_github_hook`, alongside the hook.
+    #[cfg(test)]
     pub fn upsert_webhook_secret(
         &self,
         webhook_id: &str,
         provider: &str,
         secret: &str,
     ) -> Result<i64, rusqlite::Error> {
+        let secret = self.seal_secret(secret);
         let conn = self.conn.lock();
 
         // Check for an existing row so we can preserve its stable numeric id
@@ -212,6 +460,7 @@ impl Database {
         provider: &str,
         secret: &str,
     ) -> Result<(), rusqlite::Error> {
+        let secret = self.seal_secret(secret);
         let conn = self.conn.lock();
         conn.execute(
             "INSERT OR IGNORE INTO webhook_secrets (webhook_id, provider, secret) VALUES (?1, ?2, ?3)",
@@ -221,7 +470,9 @@ impl Database {
     }
 
     /// Delete a webhook secret by its numeric row ID (the "hook id" from mock
-    /// API responses).
+    /// API responses). The provider mock deletes through `delete_github_hook`,
+    /// which also removes the hook registration.
+    #[cfg(test)]
     pub fn delete_webhook_secret_by_id(&self, id: i64) -> Result<bool, rusqlite::Error> {
         let conn = self.conn.lock();
         let deleted = conn.execute(
@@ -239,19 +490,215 @@ impl Database {
     #[cfg(test)]
     pub fn get_webhook_secret(&self, webhook_id: &str) -> Result<Option<String>, rusqlite::Error> {
         let conn = self.conn.lock();
-        conn.query_row(
-            "SELECT secret FROM webhook_secrets WHERE webhook_id = ?1",
-            rusqlite::params![webhook_id],
-            |row| row.get(0),
-        )
-        .optional()
+        let stored = conn
+            .query_row(
+                "SELECT secret FROM webhook_secrets WHERE webhook_id = ?1",
+                rusqlite::params![webhook_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(self.reveal_secret(webhook_id, stored))
+    }
+
+    /// Encrypt a secret for storage (unchanged without a cipher). The empty
+    /// placeholder of hooks registered without a secret stays empty.
+    fn seal_secret(&self, secret: &str) -> String {
+        match &self.cipher {
+            Some(cipher) if !secret.is_empty() => cipher.encrypt(secret),
+            _ => secret.to_string(),
+        }
+    }
+
+    /// Decrypt a stored secret. Secrets that can't be decrypted are logged
+    /// and treated as missing, like a hook without a secret.
+    fn reveal_secret(&self, webhook_id: &str, stored: Option<String>) -> Option<String> {
+        let stored = stored?;
+        if !SecretCipher::is_encrypted(&stored) {
+            return Some(stored);
+        }
+        let Some(cipher) = &self.cipher else {
+            warn!(
+                webhook_id = %webhook_id,
+                "Webhook secret is encrypted but WEBHOOK_SECRET_KEY is not set"
+            );
+            return None;
+        };
+        match cipher.decrypt(&stored) {
+            Ok(secret) => Some(secret),
+            Err(e) => {
+                warn!(error = %e, webhook_id = %webhook_id, "Failed to decrypt webhook secret");
+                None
+            }
+        }
+    }
+
+    fn reveal_trigger_secrets(&self, mut rows: Vec<GitHubTriggerRow>) -> Vec<GitHubTriggerRow> {
+        for row in &mut rows {
+            row.secret = self.reveal_secret(&row.webhook_id, row.secret.take());
+        }
+        rows
+    }
+
+    fn reveal_hook_secret(&self, mut row: GitHubHookRow) -> GitHubHookRow {
+        row.secret = self.reveal_secret(&row.webhook_id, row.secret.take());
+        row
+    }
+
+    /// Encrypt stored webhook secrets with the configured key.
+    ///
+    /// Plaintext secrets (written before encryption was enabled) are always
+    /// encrypted. With `rotate`, secrets encrypted with a previous key
+    /// (`WEBHOOK_SECRET_PREVIOUS_KEYS`) are re-encrypted under the current
+    /// one, after which the previous key is no longer needed. Without a key,
+    /// nothing is changed and encrypted secrets are reported as unreadable.
+    pub fn encrypt_webhook_secrets(
+        &self,
+        rotate: bool,
+    ) -> Result<SecretEncryptionReport, rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        let rows: Vec<(i64, String, String)> = {
+            let mut stmt =
+                tx.prepare("SELECT id, webhook_id, secret FROM webhook_secrets ORDER BY id")?;
+            stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        let mut report = SecretEncryptionReport::default();
+        for (id, webhook_id, stored) in rows {
+            let encrypted = SecretCipher::is_encrypted(&stored);
+            let Some(cipher) = &self.cipher else {
+                if encrypted {
+                    report.unreadable.push(webhook_id);
+                }
+                continue;
+            };
+            if stored.is_empty() || (encrypted && !rotate) {
+                continue;
+            }
+            match cipher.reencrypt(&stored) {
+                Ok(Some(sealed)) => {
+                    tx.execute(
+                        "UPDATE webhook_secrets SET secret = ?1 WHERE id = ?2",
+                        rusqlite::params![sealed, id],
+                    )?;
+                    if encrypted {
+                        report.rotated += 1;
+                    } else {
+                        report.encrypted += 1;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(error = %e, webhook_id = %webhook_id, "Failed to re-encrypt webhook secret");
+                    report.unreadable.push(webhook_id);
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(report)
+    }
+
+    /// Garbage-collect webhook secrets that no trigger references.
+    ///
+    /// A secret is orphaned when its `webhook_id` is in none of the trigger
+    /// tables, e.g. after a workflow was deleted without n8n deregistering
+    /// its webhook (trigger sync removes its row, even when a hook
+    /// registration created it), or for hooks registered under an
+    /// `unknown-*` fallback ID.
+    /// Orphaned secrets are first quarantined (`orphaned_at` is set) and are
+    /// deleted, together with any GitHub hook registration sharing their ID,
+    /// once they have been orphaned for `grace_secs`. A quarantined secret
+    /// whose webhook has a trigger again is restored.
+    pub fn collect_orphaned_webhook_secrets(
+        &self,
+        grace_secs: u64,
+    ) -> Result<WebhookSecretGcReport, rusqlite::Error> {
+        const ORPHANED: &str = "webhook_id NOT IN ( \
+            SELECT webhook_id FROM github_triggers \
+            UNION SELECT webhook_id FROM jira_triggers \
+            UNION SELECT webhook_id FROM slack_triggers \
+            UNION SELECT webhook_id FROM zoom_triggers)";
+        const EXPIRED: &str = "orphaned_at IS NOT NULL \
+            AND orphaned_at <= datetime('now', '-' || ?1 || ' seconds')";
+
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        let webhook_ids = |sql: &str| -> Result<Vec<String>, rusqlite::Error> {
+            let mut stmt = tx.prepare(sql)?;
+            let rows = stmt
+                .query_map([], |row| row.get(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(rows)
+        };
+        let mut report = WebhookSecretGcReport {
+            restored: webhook_ids(&format!(
+                "SELECT webhook_id FROM webhook_secrets \
+                 WHERE orphaned_at IS NOT NULL AND NOT ({ORPHANED}) ORDER BY webhook_id"
+            ))?,
+            quarantined: webhook_ids(&format!(
+                "SELECT webhook_id FROM webhook_secrets \
+                 WHERE orphaned_at IS NULL AND {ORPHANED} ORDER BY webhook_id"
+            ))?,
+            deleted: Vec::new(),
+        };
+        tx.execute(
+            &format!(
+                "UPDATE webhook_secrets SET orphaned_at = NULL \
+                 WHERE orphaned_at IS NOT NULL AND NOT ({ORPHANED})"
+            ),
+            [],
+        )?;
+        tx.execute(
+            &format!(
+                "UPDATE webhook_secrets SET orphaned_at = datetime('now') \
+                 WHERE orphaned_at IS NULL AND {ORPHANED}"
+            ),
+            [],
+        )?;
+
+        {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT webhook_id, provider, created_at, orphaned_at FROM webhook_secrets \
+                 WHERE {EXPIRED} ORDER BY webhook_id"
+            ))?;
+            report.deleted = stmt
+                .query_map(rusqlite::params![grace_secs as i64], |row| {
+                    Ok(DeletedWebhookSecret {
+                        webhook_id: row.get(0)?,
+                        provider: row.get(1)?,
+                        created_at: row.get(2)?,
+                        orphaned_at: row.get(3)?,
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+        tx.execute(
+            &format!(
+                "DELETE FROM github_hooks WHERE id IN \
+                 (SELECT id FROM webhook_secrets WHERE {EXPIRED})"
+            ),
+            rusqlite::params![grace_secs as i64],
+        )?;
+        tx.execute(
+            &format!("DELETE FROM webhook_secrets WHERE {EXPIRED}"),
+            rusqlite::params![grace_secs as i64],
+        )?;
+        tx.commit()?;
+        Ok(report)
     }
 
     // ── GitHub triggers ─────────────────────────────────────────────────
 
-    /// Replace all GitHub trigger rows with the supplied set (inside a
+    /// Reconcile GitHub trigger rows with the supplied set from n8n (inside a
     /// transaction). This is called by the periodic sync job.
     ///
+    /// Rows for the supplied triggers are inserted or updated with n8n's
+    /// metadata. n8n reports every workflow, active or not, so all other rows
+    /// belong to deleted workflows and are removed, including rows created by
+    /// a provider-mock hook registration. A trigger's `enterprise` flag and
+    /// GHES host come from its hook registration.
+    ///
     /// If n8n returns multiple trigger nodes with the same `webhook_id`, only one
     /// row per id is kept (active workflows win, then lexicographic `workflow_id`)
     /// so the sync transaction does not abort with a UNIQUE constraint error.
@@ -262,16 +709,29 @@ impl Database {
         let triggers = dedupe_github_triggers(triggers);
         let mut conn = self.conn.lock();
         let tx = conn.transaction()?;
-        tx.execute("DELETE FROM github_triggers", [])?;
+        delete_unreported_triggers(&tx, "github_triggers", &triggers, |t| &t.webhook_id)?;
         {
             let mut stmt = tx.prepare(
                 "INSERT INTO github_triggers \
-                 (webhook_id, workflow_id, workflow_name, workflow_active, owner, repository, events) \
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                 (webhook_id, workflow_id, workflow_name, workflow_active, owner, repository, events, \
+                  actions, enterprise, installation_id, enterprise_host) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, COALESCE( \
+                   (SELECT enterprise FROM github_hooks WHERE webhook_id = ?1), 0), ?9, \
+                   COALESCE((SELECT enterprise_host FROM github_hooks WHERE webhook_id = ?1), \
+                   '')) \
+                 ON CONFLICT(webhook_id) DO UPDATE SET \
+                 workflow_id = excluded.workflow_id, workflow_name = excluded.workflow_name, \
+                 workflow_active = excluded.workflow_active, owner = excluded.owner, \
+                 repository = excluded.repository, events = excluded.events, \
+                 actions = excluded.actions, enterprise = excluded.enterprise, \
+                 installation_id = excluded.installation_id, \
+                 enterprise_host = excluded.enterprise_host, updated_at = datetime('now')",
             )?;
             for t in &triggers {
                 let events_json =
                     serde_json::to_string(&t.events).unwrap_or_else(|_| "[]".to_string());
+                let actions_json =
+                    serde_json::to_string(&t.actions).unwrap_or_else(|_| "[]".to_string());
                 stmt.execute(rusqlite::params![
                     t.webhook_id,
                     t.workflow_id,
@@ -280,6 +740,8 @@ impl Database {
                     t.owner,
                     t.repository,
                     events_json,
+                    actions_json,
+                    t.installation_id,
                 ])?;
             }
         }
@@ -292,8 +754,12 @@ impl Database {
     /// `webhook_secrets` to include the HMAC secret (if available).
     ///
     /// When `owner` and `repository` are both `Some`, the query filters by
-    /// case-insensitive match. When either is `None`, only triggers with empty
-    /// owner/repository are returned (for rare org-level events).
+    /// case-insensitive match. When only `owner` is given (repository-less
+    /// org-level events), every trigger for that owner matches, plus triggers
+    /// with empty owner/repository. With no owner, only the latter match.
+    ///
+    /// Installation-scoped triggers are never returned here; see
+    /// `query_github_installation_triggers`.
     pub fn query_github_triggers(
         &self,
         owner: Option<&str>,
@@ -301,43 +767,87 @@ impl Database {
     ) -> Result<Vec<GitHubTriggerRow>, rusqlite::Error> {
         let conn = self.conn.lock();
 
-        let base_sql = "\
-            SELECT gt.webhook_id, gt.workflow_name, gt.workflow_active, \
-                   gt.events, ws.secret \
-            FROM github_triggers gt \
-            LEFT JOIN webhook_secrets ws ON gt.webhook_id = ws.webhook_id";
+        let base_sql = format!("{GITHUB_TRIGGER_SELECT} WHERE gt.installation_id IS NULL AND");
 
         match (owner, repository) {
             (Some(o), Some(r)) => {
                 let sql = format!(
-                    "{base_sql} WHERE LOWER(gt.owner) = LOWER(?1) AND LOWER(gt.repository) = LOWER(?2)"
+                    "{base_sql} LOWER(gt.owner) = LOWER(?1) AND LOWER(gt.repository) = LOWER(?2)"
                 );
                 let mut stmt = conn.prepare(&sql)?;
                 let rows = stmt
                     .query_map(rusqlite::params![o, r], Self::map_github_row)?
                     .collect::<Result<Vec<_>, _>>()?;
-                Ok(rows)
+                Ok(self.reveal_trigger_secrets(rows))
+            }
+            // Repository-less org-level events: triggers for that owner, plus
+            // the owner-less catch-alls
+            (Some(o), None) => {
+                let sql = format!(
+                    "{base_sql} (LOWER(gt.owner) = LOWER(?1) \
+                     OR (gt.owner = '' AND gt.repository = ''))"
+                );
+                let mut stmt = conn.prepare(&sql)?;
+                let rows = stmt
+                    .query_map(rusqlite::params![o], Self::map_github_row)?
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.reveal_trigger_secrets(rows))
             }
             _ => {
-                let sql = format!("{base_sql} WHERE gt.owner = '' AND gt.repository = ''");
+                let sql = format!("{base_sql} gt.owner = '' AND gt.repository = ''");
                 let mut stmt = conn.prepare(&sql)?;
                 let rows = stmt
                     .query_map([], Self::map_github_row)?
                     .collect::<Result<Vec<_>, _>>()?;
-                Ok(rows)
+                Ok(self.reveal_trigger_secrets(rows))
             }
         }
     }
 
+    /// Query the GitHub triggers scoped to an App installation (the
+    /// `unihook.installation` annotation) that an event matches.
+    ///
+    /// A trigger matches when the event was delivered for its installation,
+    /// or when the event's repository (`owner/name`) is one of the
+    /// installation's repositories in the local installation map.
+    pub fn query_github_installation_triggers(
+        &self,
+        installation_id: Option<u64>,
+        full_name: Option<&str>,
+    ) -> Result<Vec<GitHubTriggerRow>, rusqlite::Error> {
+        if installation_id.is_none() && full_name.is_none() {
+            return Ok(Vec::new());
+        }
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(&format!(
+            "{GITHUB_TRIGGER_SELECT} WHERE gt.installation_id = ?1 \
+             OR gt.installation_id IN ( \
+               SELECT installation_id FROM github_installation_repositories \
+               WHERE full_name = ?2)"
+        ))?;
+        let rows = stmt
+            .query_map(
+                rusqlite::params![installation_id.map(|id| id as i64), full_name],
+                Self::map_github_row,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.reveal_trigger_secrets(rows))
+    }
+
     fn map_github_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<GitHubTriggerRow> {
         let events_json: String = row.get(3)?;
         let events: Vec<String> = serde_json::from_str(&events_json).unwrap_or_default();
+        let actions_json: String = row.get(5)?;
+        let actions: Vec<String> = serde_json::from_str(&actions_json).unwrap_or_default();
         Ok(GitHubTriggerRow {
             webhook_id: row.get(0)?,
             workflow_name: row.get(1)?,
             workflow_active: row.get(2)?,
             events,
             secret: row.get(4)?,
+            actions,
+            enterprise: row.get(6)?,
+            enterprise_host: row.get(7)?,
         })
     }
 
@@ -349,12 +859,325 @@ impl Database {
         Ok(count as usize)
     }
 
+    // ── GitHub hooks ────────────────────────────────────────────────────
+
+    /// Store a hook registration from the provider mock, together with its
+    /// secret, and return the stored hook.
+    ///
+    /// Re-registering the same `webhook_id` updates the hook in place and
+    /// keeps its ID, matching the existing secret upsert. The hook's
+    /// `github_triggers` row is created or updated in the same transaction.
+    pub fn upsert_github_hook(
+        &self,
+        hook: &GitHubHookRegistration,
+    ) -> Result<GitHubHookRow, rusqlite::Error> {
+        let events_json = serde_json::to_string(&hook.events).unwrap_or_else(|_| "[]".to_string());
+        let secret = hook.secret.as_deref().map(|s| self.seal_secret(s));
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        let existing: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM webhook_secrets WHERE webhook_id = ?1",
+                rusqlite::params![hook.webhook_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let id = match existing {
+            Some(id) => {
+                if let Some(ref secret) = secret {
+                    tx.execute(
+                        "UPDATE webhook_secrets SET secret = ?1, provider = 'github' WHERE id = ?2",
+                        rusqlite::params![secret, id],
+                    )?;
+                }
+                // Re-registering restarts any garbage-collection grace period
+                tx.execute(
+                    "UPDATE webhook_secrets SET orphaned_at = NULL WHERE id = ?1",
+                    rusqlite::params![id],
+                )?;
+                id
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO webhook_secrets (webhook_id, provider, secret) \
+                     VALUES (?1, 'github', ?2)",
+                    rusqlite::params![hook.webhook_id, secret.as_deref().unwrap_or("")],
+                )?;
+                tx.last_insert_rowid()
+            }
+        };
+        tx.execute(
+            "INSERT INTO github_hooks \
+             (id, owner, repository, webhook_id, name, events, active, config_url, content_type, \
+              enterprise, enterprise_host) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11) \
+             ON CONFLICT(id) DO UPDATE SET \
+             owner = excluded.owner, repository = excluded.repository, \
+             webhook_id = excluded.webhook_id, name = excluded.name, \
+             events = excluded.events, active = excluded.active, \
+             config_url = excluded.config_url, content_type = excluded.content_type, \
+             enterprise = excluded.enterprise, enterprise_host = excluded.enterprise_host, \
+             updated_at = datetime('now')",
+            rusqlite::params![
+                id,
+                hook.owner,
+                hook.repository,
+                hook.webhook_id,
+                hook.name,
+                events_json,
+                hook.active,
+                hook.config_url,
+                hook.content_type,
+                hook.enterprise,
+                hook.enterprise_host,
+            ],
+        )?;
+        upsert_github_hook_trigger(&tx, hook)?;
+        let row = tx.query_row(
+            &format!("{GITHUB_HOOK_SELECT} WHERE gh.id = ?1"),
+            rusqlite::params![id],
+            github_hook_from_row,
+        )?;
+        tx.commit()?;
+        debug!(id = row.id, webhook_id = %row.webhook_id, "Stored GitHub hook registration");
+        Ok(self.reveal_hook_secret(row))
+    }
+
+    /// Apply a `PATCH` to an existing hook. Returns `None` if no hook has
+    /// this ID. A changed `config.url` moves the secret and the trigger row
+    /// to the new webhook ID.
+    pub fn update_github_hook(
+        &self,
+        id: i64,
+        hook: &GitHubHookRegistration,
+    ) -> Result<Option<GitHubHookRow>, rusqlite::Error> {
+        let events_json = serde_json::to_string(&hook.events).unwrap_or_else(|_| "[]".to_string());
+        let secret = hook.secret.as_deref().map(|s| self.seal_secret(s));
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        let previous_webhook_id: Option<String> = tx
+            .query_row(
+                "SELECT webhook_id FROM github_hooks WHERE id = ?1",
+                rusqlite::params![id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(previous_webhook_id) = previous_webhook_id else {
+            return Ok(None);
+        };
+        tx.execute(
+            "UPDATE github_hooks SET webhook_id = ?2, name = ?3, events = ?4, active = ?5, \
+             config_url = ?6, content_type = ?7, updated_at = datetime('now') WHERE id = ?1",
+            rusqlite::params![
+                id,
+                hook.webhook_id,
+                hook.name,
+                events_json,
+                hook.active,
+                hook.config_url,
+                hook.content_type,
+            ],
+        )?;
+        tx.execute(
+            "UPDATE webhook_secrets SET webhook_id = ?2, secret = COALESCE(?3, secret) \
+             WHERE id = ?1",
+            rusqlite::params![id, hook.webhook_id, secret],
+        )?;
+        if previous_webhook_id != hook.webhook_id {
+            tx.execute(
+                "DELETE FROM github_triggers WHERE webhook_id = ?1",
+                rusqlite::params![previous_webhook_id],
+            )?;
+        }
+        upsert_github_hook_trigger(&tx, hook)?;
+        let row = tx.query_row(
+            &format!("{GITHUB_HOOK_SELECT} WHERE gh.id = ?1"),
+            rusqlite::params![id],
+            github_hook_from_row,
+        )?;
+        tx.commit()?;
+        Ok(Some(self.reveal_hook_secret(row)))
+    }
+
+    /// List the hooks registered on a repository (case-insensitive), oldest
+    /// first. github.com and GitHub Enterprise Server repositories with the
+    /// same name are distinct.
+    pub fn list_github_hooks(
+        &self,
+        enterprise: bool,
+        owner: &str,
+        repository: &str,
+    ) -> Result<Vec<GitHubHookRow>, rusqlite::Error> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(&format!(
+            "{GITHUB_HOOK_SELECT} WHERE LOWER(gh.owner) = LOWER(?1) \
+             AND LOWER(gh.repository) = LOWER(?2) AND gh.enterprise = ?3 ORDER BY gh.id"
+        ))?;
+        let rows = stmt
+            .query_map(
+                rusqlite::params![owner, repository, enterprise],
+                github_hook_from_row,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows
+            .into_iter()
+            .map(|row| self.reveal_hook_secret(row))
+            .collect())
+    }
+
+    /// Fetch a single hook by ID, scoped to its repository.
+    pub fn get_github_hook(
+        &self,
+        enterprise: bool,
+        owner: &str,
+        repository: &str,
+        id: i64,
+    ) -> Result<Option<GitHubHookRow>, rusqlite::Error> {
+        let conn = self.conn.lock();
+        conn.query_row(
+            &format!(
+                "{GITHUB_HOOK_SELECT} WHERE gh.id = ?1 AND LOWER(gh.owner) = LOWER(?2) \
+                 AND LOWER(gh.repository) = LOWER(?3) AND gh.enterprise = ?4"
+            ),
+            rusqlite::params![id, owner, repository, enterprise],
+            github_hook_from_row,
+        )
+        .optional()
+        .map(|row| row.map(|row| self.reveal_hook_secret(row)))
+    }
+
+    /// Delete a hook, its secret, and its trigger row. Returns true if the
+    /// hook or secret existed (hooks registered before hooks were persisted
+    /// only have a secret row).
+    pub fn delete_github_hook(&self, id: i64) -> Result<bool, rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM github_triggers WHERE webhook_id = \
+             (SELECT webhook_id FROM webhook_secrets WHERE id = ?1)",
+            rusqlite::params![id],
+        )?;
+        let hooks = tx.execute(
+            "DELETE FROM github_hooks WHERE id = ?1",
+            rusqlite::params![id],
+        )?;
+        let secrets = tx.execute(
+            "DELETE FROM webhook_secrets WHERE id = ?1",
+            rusqlite::params![id],
+        )?;
+        tx.commit()?;
+        Ok(hooks + secrets > 0)
+    }
+
+    // ── GitHub App installations ────────────────────────────────────────
+
+    /// Record an App installation and replace its repository list (the
+    /// `installation.created` event lists every repository the App can access).
+    pub fn replace_github_installation(
+        &self,
+        installation_id: u64,
+        account_login: &str,
+        repositories: &[String],
+    ) -> Result<(), rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        upsert_github_installation(&tx, installation_id, account_login)?;
+        tx.execute(
+            "DELETE FROM github_installation_repositories WHERE installation_id = ?1",
+            rusqlite::params![installation_id as i64],
+        )?;
+        insert_github_installation_repositories(&tx, installation_id, repositories)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Add repositories to an App installation, recording the installation
+    /// if it isn't known yet.
+    pub fn add_github_installation_repositories(
+        &self,
+        installation_id: u64,
+        account_login: &str,
+        repositories: &[String],
+    ) -> Result<(), rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        upsert_github_installation(&tx, installation_id, account_login)?;
+        insert_github_installation_repositories(&tx, installation_id, repositories)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Remove repositories from an App installation.
+    pub fn remove_github_installation_repositories(
+        &self,
+        installation_id: u64,
+        repositories: &[String],
+    ) -> Result<(), rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "DELETE FROM github_installation_repositories \
+                 WHERE installation_id = ?1 AND full_name = ?2",
+            )?;
+            for full_name in repositories {
+                stmt.execute(rusqlite::params![installation_id as i64, full_name])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Forget an App installation and its repositories. Returns true if the
+    /// installation was known.
+    pub fn delete_github_installation(
+        &self,
+        installation_id: u64,
+    ) -> Result<bool, rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM github_installation_repositories WHERE installation_id = ?1",
+            rusqlite::params![installation_id as i64],
+        )?;
+        let deleted = tx.execute(
+            "DELETE FROM github_installations WHERE installation_id = ?1",
+            rusqlite::params![installation_id as i64],
+        )?;
+        tx.commit()?;
+        Ok(deleted > 0)
+    }
+
+    /// Repositories (`owner/name`) recorded for an App installation, sorted.
+    #[cfg(test)]
+    pub fn list_github_installation_repositories(
+        &self,
+        installation_id: u64,
+    ) -> Result<Vec<String>, rusqlite::Error> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            "SELECT full_name FROM github_installation_repositories \
+             WHERE installation_id = ?1 ORDER BY full_name",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![installation_id as i64], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
     // ── Jira triggers ───────────────────────────────────────────────────
 
-    /// Replace all Jira trigger rows with the supplied set.
+    /// Reconcile Jira trigger rows with the supplied set from n8n.
+    ///
+    /// Rows for the supplied triggers are inserted or updated with n8n's
+    /// metadata. All other rows, including those created by a provider-mock
+    /// webhook registration, belong to workflows n8n no longer reports and
+    /// are removed, together with those workflows' webhook registrations
+    /// (Jira registrations hold no secret for garbage collection to reclaim).
     ///
     /// Duplicate `webhook_id` values from n8n are collapsed to one row each so
-    /// the SQLite UNIQUE constraint cannot roll back the entire sync.
+    /// the SQLite UNIQUE constraint cannot roll back the entire sync. Each
+    /// trigger's `site` is taken from its most recent webhook registration.
     pub fn sync_jira_triggers(
         &self,
         triggers: &[JiraTriggerConfig],
@@ -362,22 +1185,40 @@ impl Database {
         let triggers = dedupe_jira_triggers(triggers);
         let mut conn = self.conn.lock();
         let tx = conn.transaction()?;
-        tx.execute("DELETE FROM jira_triggers", [])?;
+        delete_unreported_triggers(&tx, "jira_triggers", &triggers, |t| &t.webhook_id)?;
+        delete_unreported_triggers(&tx, "jira_webhooks", &triggers, |t| &t.webhook_id)?;
         {
             let mut stmt = tx.prepare(
                 "INSERT INTO jira_triggers \
-                 (webhook_id, workflow_id, workflow_name, workflow_active, events) \
-                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                 (webhook_id, workflow_id, workflow_name, workflow_active, events, jql_filter, \
+                  exclude_body, include_fields, projects, site) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, COALESCE( \
+                   (SELECT site FROM jira_webhooks WHERE webhook_id = ?1 ORDER BY id DESC LIMIT 1), \
+                   '')) \
+                 ON CONFLICT(webhook_id) DO UPDATE SET \
+                 workflow_id = excluded.workflow_id, workflow_name = excluded.workflow_name, \
+                 workflow_active = excluded.workflow_active, events = excluded.events, \
+                 jql_filter = excluded.jql_filter, exclude_body = excluded.exclude_body, \
+                 include_fields = excluded.include_fields, projects = excluded.projects, \
+                 site = excluded.site, updated_at = datetime('now')",
             )?;
             for t in &triggers {
                 let events_json =
                     serde_json::to_string(&t.events).unwrap_or_else(|_| "[]".to_string());
+                let include_fields_json =
+                    serde_json::to_string(&t.include_fields).unwrap_or_else(|_| "[]".to_string());
+                let projects_json =
+                    serde_json::to_string(&t.projects).unwrap_or_else(|_| "[]".to_string());
                 stmt.execute(rusqlite::params![
                     t.webhook_id,
                     t.workflow_id,
                     t.workflow_name,
                     t.workflow_active,
                     events_json,
+                    t.jql_filter,
+                    t.exclude_body,
+                    include_fields_json,
+                    projects_json,
                 ])?;
             }
         }
@@ -390,18 +1231,30 @@ impl Database {
     pub fn query_jira_triggers(&self) -> Result<Vec<JiraTriggerRow>, rusqlite::Error> {
         let conn = self.conn.lock();
         let mut stmt = conn.prepare(
-            "SELECT webhook_id, workflow_name, workflow_active, events \
+            "SELECT webhook_id, workflow_name, workflow_active, events, jql_filter, site, \
+             exclude_body, include_fields, projects \
              FROM jira_triggers",
         )?;
         let rows = stmt
             .query_map([], |row| {
                 let events_json: String = row.get(3)?;
                 let events: Vec<String> = serde_json::from_str(&events_json).unwrap_or_default();
+                let include_fields_json: String = row.get(7)?;
+                let include_fields: Vec<String> =
+                    serde_json::from_str(&include_fields_json).unwrap_or_default();
+                let projects_json: String = row.get(8)?;
+                let projects: Vec<String> =
+                    serde_json::from_str(&projects_json).unwrap_or_default();
                 Ok(JiraTriggerRow {
                     webhook_id: row.get(0)?,
                     workflow_name: row.get(1)?,
                     workflow_active: row.get(2)?,
                     events,
+                    jql_filter: row.get(4)?,
+                    site: row.get(5)?,
+                    exclude_body: row.get(6)?,
+                    include_fields,
+                    projects,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -416,29 +1269,155 @@ impl Database {
         Ok(count as usize)
     }
 
-    // ── Slack triggers ──────────────────────────────────────────────────
+    // ── Jira webhook registrations ──────────────────────────────────────
 
-    /// Replace all Slack trigger rows with the supplied set.
+    /// Store a Jira webhook registration and return the stored row.
     ///
-    /// Duplicate `webhook_id` values from n8n are collapsed to one row each so
-    /// the SQLite UNIQUE constraint cannot roll back the entire sync.
-    pub fn sync_slack_triggers(
+    /// Registrations are keyed by `url`: re-registering the same n8n webhook
+    /// URL updates the existing row and keeps its ID, so repeated activations
+    /// don't accumulate phantom registrations. The registration's
+    /// `jira_triggers` row is created or updated right away.
+    pub fn upsert_jira_webhook(
         &self,
-        triggers: &[SlackTriggerConfig],
-    ) -> Result<(), rusqlite::Error> {
-        let triggers = dedupe_slack_triggers(triggers);
+        hook: &JiraWebhookRegistration,
+    ) -> Result<JiraWebhookRow, rusqlite::Error> {
+        let events_json = serde_json::to_string(&hook.events).unwrap_or_else(|_| "[]".to_string());
+        let filters_json = hook.filters.to_string();
         let mut conn = self.conn.lock();
         let tx = conn.transaction()?;
-        tx.execute("DELETE FROM slack_triggers", [])?;
+        tx.execute(
+            "INSERT INTO jira_webhooks \
+             (site, webhook_id, name, url, events, filters, exclude_body, production) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) \
+             ON CONFLICT(url) DO UPDATE SET \
+             site = excluded.site, webhook_id = excluded.webhook_id, \
+             name = excluded.name, events = excluded.events, \
+             filters = excluded.filters, exclude_body = excluded.exclude_body, \
+             production = excluded.production",
+            rusqlite::params![
+                hook.site,
+                hook.webhook_id,
+                hook.name,
+                hook.url,
+                events_json,
+                filters_json,
+                hook.exclude_body,
+                hook.production,
+            ],
+        )?;
+        if !hook.webhook_id.is_empty() {
+            upsert_jira_webhook_trigger(&tx, hook)?;
+        }
+        let row = tx.query_row(
+            "SELECT id, site, webhook_id, name, url, events, filters, exclude_body \
+             FROM jira_webhooks WHERE url = ?1",
+            rusqlite::params![hook.url],
+            jira_webhook_from_row,
+        )?;
+        tx.commit()?;
+        debug!(id = row.id, site = %row.site, url = %row.url, "Stored Jira webhook registration");
+        Ok(row)
+    }
+
+    /// List the Jira webhook registrations made through one site's mock
+    /// path, oldest first.
+    pub fn list_jira_webhooks(&self, site: &str) -> Result<Vec<JiraWebhookRow>, rusqlite::Error> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            "SELECT id, site, webhook_id, name, url, events, filters, exclude_body \
+             FROM jira_webhooks WHERE site = ?1 ORDER BY id",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![site], jira_webhook_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    /// Fetch a single Jira webhook registration by ID, scoped to the site
+    /// whose mock path it was registered through.
+    pub fn get_jira_webhook(
+        &self,
+        site: &str,
+        id: i64,
+    ) -> Result<Option<JiraWebhookRow>, rusqlite::Error> {
+        let conn = self.conn.lock();
+        conn.query_row(
+            "SELECT id, site, webhook_id, name, url, events, filters, exclude_body \
+             FROM jira_webhooks WHERE id = ?1 AND site = ?2",
+            rusqlite::params![id, site],
+            jira_webhook_from_row,
+        )
+        .optional()
+    }
+
+    /// Delete a Jira webhook registration by ID, scoped to the site whose
+    /// mock path it was registered through. Returns false if the site has no
+    /// registration with this ID.
+    ///
+    /// The trigger row goes with the last registration for its webhook ID;
+    /// while others remain (e.g. a test-mode registration), only its active
+    /// flag is recomputed from them.
+    pub fn delete_jira_webhook(&self, site: &str, id: i64) -> Result<bool, rusqlite::Error> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        let webhook_id: Option<String> = tx
+            .query_row(
+                "SELECT webhook_id FROM jira_webhooks WHERE id = ?1 AND site = ?2",
+                rusqlite::params![id, site],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(webhook_id) = webhook_id else {
+            return Ok(false);
+        };
+        tx.execute(
+            "DELETE FROM jira_webhooks WHERE id = ?1",
+            rusqlite::params![id],
+        )?;
+        if !webhook_id.is_empty() {
+            tx.execute(
+                "DELETE FROM jira_triggers WHERE webhook_id = ?1 \
+                 AND NOT EXISTS (SELECT 1 FROM jira_webhooks WHERE webhook_id = ?1)",
+                rusqlite::params![webhook_id],
+            )?;
+            tx.execute(
+                "UPDATE jira_triggers SET workflow_active = EXISTS \
+                 (SELECT 1 FROM jira_webhooks WHERE webhook_id = ?1 AND production = 1) \
+                 WHERE webhook_id = ?1",
+                rusqlite::params![webhook_id],
+            )?;
+        }
+        tx.commit()?;
+        Ok(true)
+    }
+
+    // ── Slack triggers ──────────────────────────────────────────────────
+
+    /// Replace all Slack trigger rows with the supplied set.
+    ///
+    /// Duplicate `webhook_id` values from n8n are collapsed to one row each so
+    /// the SQLite UNIQUE constraint cannot roll back the entire sync.
+    pub fn sync_slack_triggers(
+        &self,
+        triggers: &[SlackTriggerConfig],
+    ) -> Result<(), rusqlite::Error> {
+        let triggers = dedupe_slack_triggers(triggers);
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM slack_triggers", [])?;
         {
             let mut stmt = tx.prepare(
                 "INSERT INTO slack_triggers \
-                 (webhook_id, workflow_id, workflow_name, workflow_active, event_type, channels, watch_whole_workspace) \
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                 (webhook_id, workflow_id, workflow_name, workflow_active, event_type, channels, \
+                  watch_whole_workspace, channel_types, channel_types_set, thread_filter) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
             )?;
             for t in &triggers {
                 let channels_json =
                     serde_json::to_string(&t.channels).unwrap_or_else(|_| "[]".to_string());
+                let channel_types_json =
+                    serde_json::to_string(t.channel_types.as_deref().unwrap_or_default())
+                        .unwrap_or_else(|_| "[]".to_string());
                 stmt.execute(rusqlite::params![
                     t.webhook_id,
                     t.workflow_id,
@@ -447,6 +1426,9 @@ impl Database {
                     t.event_type,
                     channels_json,
                     t.watch_whole_workspace,
+                    channel_types_json,
+                    t.channel_types.is_some(),
+                    t.thread_filter.as_str(),
                 ])?;
             }
         }
@@ -460,7 +1442,8 @@ impl Database {
         let conn = self.conn.lock();
         let mut stmt = conn.prepare(
             "SELECT webhook_id, workflow_name, workflow_active, \
-                    event_type, channels, watch_whole_workspace \
+                    event_type, channels, watch_whole_workspace, \
+                    channel_types, channel_types_set, thread_filter \
              FROM slack_triggers",
         )?;
         let rows = stmt
@@ -468,6 +1451,15 @@ impl Database {
                 let channels_json: String = row.get(4)?;
                 let channels: Vec<String> =
                     serde_json::from_str(&channels_json).unwrap_or_default();
+                let channel_types_json: String = row.get(6)?;
+                let channel_types_set: bool = row.get(7)?;
+                // Rows written before `channel_types_set` existed use a
+                // non-empty list to mean "filter"
+                let channel_types: Vec<String> =
+                    serde_json::from_str(&channel_types_json).unwrap_or_default();
+                let channel_types =
+                    (channel_types_set || !channel_types.is_empty()).then_some(channel_types);
+                let thread_filter: String = row.get(8)?;
                 Ok(SlackTriggerRow {
                     webhook_id: row.get(0)?,
                     workflow_name: row.get(1)?,
@@ -475,6 +1467,8 @@ impl Database {
                     event_type: row.get(3)?,
                     channels,
                     watch_whole_workspace: row.get(5)?,
+                    channel_types,
+                    thread_filter: SlackThreadFilter::parse(&thread_filter).unwrap_or_default(),
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -504,8 +1498,8 @@ impl Database {
             let mut stmt = tx.prepare(
                 "INSERT INTO zoom_triggers \
                  (webhook_id, workflow_id, workflow_name, workflow_active, events, \
-                  owner_email, project_id, project_type) \
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                  owner_email, project_id, project_type, account_id) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
             )?;
             for t in &triggers {
                 let events_json =
@@ -519,6 +1513,7 @@ impl Database {
                     t.owner_email,
                     t.project_id,
                     t.project_type,
+                    t.account_id,
                 ])?;
             }
         }
@@ -532,7 +1527,7 @@ impl Database {
         let conn = self.conn.lock();
         let mut stmt = conn.prepare(
             "SELECT webhook_id, workflow_id, workflow_name, workflow_active, events, \
-                    owner_email, project_id, project_type \
+                    owner_email, project_id, project_type, account_id \
              FROM zoom_triggers",
         )?;
         let rows = stmt
@@ -548,6 +1543,7 @@ impl Database {
                     owner_email: row.get(5)?,
                     project_id: row.get(6)?,
                     project_type: row.get(7)?,
+                    account_id: row.get(8)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -561,6 +1557,32 @@ impl Database {
             conn.query_row("SELECT COUNT(*) FROM zoom_triggers", [], |row| row.get(0))?;
         Ok(count as usize)
     }
+
+    // ── Zoom users ──────────────────────────────────────────────────────
+
+    /// Record a Zoom user ID's email, learned from a verified event that
+    /// carries both `host_id` and `host_email`.
+    pub fn upsert_zoom_user(&self, user_id: &str, email: &str) -> Result<(), rusqlite::Error> {
+        let conn = self.conn.lock();
+        conn.execute(
+            "INSERT INTO zoom_users (user_id, email) VALUES (?1, ?2) \
+             ON CONFLICT(user_id) DO UPDATE SET email = excluded.email, \
+                updated_at = datetime('now')",
+            rusqlite::params![user_id, email],
+        )?;
+        Ok(())
+    }
+
+    /// Look up the email recorded for a Zoom user ID.
+    pub fn get_zoom_user_email(&self, user_id: &str) -> Result<Option<String>, rusqlite::Error> {
+        let conn = self.conn.lock();
+        conn.query_row(
+            "SELECT email FROM zoom_users WHERE user_id = ?1",
+            [user_id],
+            |row| row.get(0),
+        )
+        .optional()
+    }
 }
 
 fn dedupe_slack_triggers(triggers: &[SlackTriggerConfig]) -> Vec<SlackTriggerConfig> {
@@ -593,6 +1615,195 @@ fn dedupe_github_triggers(triggers: &[GitHubTriggerConfig]) -> Vec<GitHubTrigger
     )
 }
 
+/// Delete the rows of a trigger or registration table whose webhook ID is not
+/// among the triggers n8n reported, so routing stops for deleted workflows
+/// even when a provider-mock registration created the row.
+fn delete_unreported_triggers<T>(
+    conn: &Connection,
+    table: &str,
+    reported: &[T],
+    webhook_id: impl Fn(&T) -> &str,
+) -> Result<(), rusqlite::Error> {
+    let reported: HashSet<&str> = reported.iter().map(webhook_id).collect();
+    let stored: Vec<String> = conn
+        .prepare(&format!("SELECT webhook_id FROM {table}"))?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    for id in stored.iter().filter(|id| !reported.contains(id.as_str())) {
+        conn.execute(
+            &format!("DELETE FROM {table} WHERE webhook_id = ?1"),
+            rusqlite::params![id],
+        )?;
+        debug!(table = %table, webhook_id = %id, "Removed trigger n8n no longer reports");
+    }
+    Ok(())
+}
+
+/// Create or update the `github_triggers` row for a hook registration.
+///
+/// Test-mode registrations never clear the active flag of a production
+/// registration for the same webhook. Hooks whose webhook ID couldn't be
+/// read from `config.url` (the `unknown-*` fallback) get no trigger row.
+fn upsert_github_hook_trigger(
+    conn: &Connection,
+    hook: &GitHubHookRegistration,
+) -> Result<(), rusqlite::Error> {
+    if !hook.config_url.contains(&hook.webhook_id) {
+        return Ok(());
+    }
+    let events_json = serde_json::to_string(&hook.events).unwrap_or_else(|_| "[]".to_string());
+    conn.execute(
+        "INSERT INTO github_triggers \
+         (webhook_id, workflow_id, workflow_name, workflow_active, owner, repository, events, \
+          enterprise, enterprise_host) \
+         VALUES (?1, '', '', ?2, ?3, ?4, ?5, ?7, ?8) \
+         ON CONFLICT(webhook_id) DO UPDATE SET \
+         owner = excluded.owner, repository = excluded.repository, events = excluded.events, \
+         enterprise = excluded.enterprise, enterprise_host = excluded.enterprise_host, \
+         workflow_active = CASE WHEN ?6 THEN excluded.workflow_active \
+                           ELSE github_triggers.workflow_active END, \
+         updated_at = datetime('now')",
+        rusqlite::params![
+            hook.webhook_id,
+            hook.production && hook.active,
+            hook.owner,
+            hook.repository,
+            events_json,
+            hook.production,
+            hook.enterprise,
+            hook.enterprise_host,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Create or update the `jira_triggers` row for a webhook registration.
+///
+/// The JQL filter comes from the registration's
+/// `issue-related-events-section` filter; the project scope is derived from
+/// it unless the row was already reconciled with n8n (whose
+/// `unihook.projects` annotation takes precedence). The row is active while
+/// any production registration exists for the webhook.
+fn upsert_jira_webhook_trigger(
+    conn: &Connection,
+    hook: &JiraWebhookRegistration,
+) -> Result<(), rusqlite::Error> {
+    let jql_filter = hook
+        .filters
+        .get("issue-related-events-section")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    let projects = jql_filter.map(jql_project_scope).unwrap_or_default();
+    let events_json = serde_json::to_string(&hook.events).unwrap_or_else(|_| "[]".to_string());
+    let projects_json = serde_json::to_string(&projects).unwrap_or_else(|_| "[]".to_string());
+    conn.execute(
+        "INSERT INTO jira_triggers \
+         (webhook_id, workflow_id, workflow_name, workflow_active, events, jql_filter, site, \
+          exclude_body, projects) \
+         VALUES (?1, '', '', \
+           EXISTS (SELECT 1 FROM jira_webhooks WHERE webhook_id = ?1 AND production = 1), \
+           ?2, ?3, ?4, ?5, ?6) \
+         ON CONFLICT(webhook_id) DO UPDATE SET \
+         workflow_active = excluded.workflow_active, events = excluded.events, \
+         jql_filter = excluded.jql_filter, site = excluded.site, \
+         exclude_body = excluded.exclude_body, \
+         projects = CASE WHEN jira_triggers.workflow_id = '' THEN excluded.projects \
+                    ELSE jira_triggers.projects END, \
+         updated_at = datetime('now')",
+        rusqlite::params![
+            hook.webhook_id,
+            events_json,
+            jql_filter,
+            hook.site,
+            hook.exclude_body,
+            projects_json,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Record an App installation (or refresh its account login).
+fn upsert_github_installation(
+    conn: &Connection,
+    installation_id: u64,
+    account_login: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO github_installations (installation_id, account_login) VALUES (?1, ?2) \
+         ON CONFLICT(installation_id) DO UPDATE SET \
+         account_login = CASE WHEN excluded.account_login = '' \
+                         THEN github_installations.account_login \
+                         ELSE excluded.account_login END, \
+         updated_at = datetime('now')",
+        rusqlite::params![installation_id as i64, account_login],
+    )?;
+    Ok(())
+}
+
+fn insert_github_installation_repositories(
+    conn: &Connection,
+    installation_id: u64,
+    repositories: &[String],
+) -> Result<(), rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "INSERT OR IGNORE INTO github_installation_repositories (installation_id, full_name) \
+         VALUES (?1, ?2)",
+    )?;
+    for full_name in repositories {
+        stmt.execute(rusqlite::params![installation_id as i64, full_name])?;
+    }
+    Ok(())
+}
+
+const GITHUB_TRIGGER_SELECT: &str = "\
+    SELECT gt.webhook_id, gt.workflow_name, gt.workflow_active, \
+           gt.events, ws.secret, gt.actions, gt.enterprise, gt.enterprise_host \
+    FROM github_triggers gt \
+    LEFT JOIN webhook_secrets ws ON gt.webhook_id = ws.webhook_id";
+
+const GITHUB_HOOK_SELECT: &str = "\
+    SELECT gh.id, gh.owner, gh.repository, gh.webhook_id, gh.name, gh.events, gh.active, \
+           gh.config_url, gh.content_type, ws.secret, gh.created_at, gh.updated_at, \
+           gh.enterprise, gh.enterprise_host \
+    FROM github_hooks gh \
+    LEFT JOIN webhook_secrets ws ON ws.id = gh.id";
+
+fn github_hook_from_row(row: &rusqlite::Row<'_>) -> Result<GitHubHookRow, rusqlite::Error> {
+    let events_json: String = row.get(5)?;
+    Ok(GitHubHookRow {
+        id: row.get(0)?,
+        owner: row.get(1)?,
+        repository: row.get(2)?,
+        webhook_id: row.get(3)?,
+        name: row.get(4)?,
+        events: serde_json::from_str(&events_json).unwrap_or_default(),
+        active: row.get(6)?,
+        config_url: row.get(7)?,
+        content_type: row.get(8)?,
+        secret: row.get(9)?,
+        created_at: row.get::<_, Option<String>>(10)?.unwrap_or_default(),
+        updated_at: row.get::<_, Option<String>>(11)?.unwrap_or_default(),
+        enterprise: row.get(12)?,
+        enterprise_host: row.get(13)?,
+    })
+}
+
+fn jira_webhook_from_row(row: &rusqlite::Row<'_>) -> Result<JiraWebhookRow, rusqlite::Error> {
+    let events_json: String = row.get(5)?;
+    let filters_json: String = row.get(6)?;
+    Ok(JiraWebhookRow {
+        id: row.get(0)?,
+        site: row.get(1)?,
+        webhook_id: row.get(2)?,
+        name: row.get(3)?,
+        url: row.get(4)?,
+        events: serde_json::from_str(&events_json).unwrap_or_default(),
+        filters: serde_json::from_str(&filters_json).unwrap_or_else(|_| serde_json::json!({})),
+        exclude_body: row.get(7)?,
+    })
+}
+
 fn dedupe_jira_triggers(triggers: &[JiraTriggerConfig]) -> Vec<JiraTriggerConfig> {
     dedupe_by_webhook_id(
         triggers.to_vec(),
@@ -745,6 +1956,222 @@ mod tests {
         assert!(!db.delete_webhook_secret_by_id(id).unwrap()); // second delete returns false
     }
 
+    // ── github_hooks tests ──────────────────────────────────────────────
+
+    fn github_hook(webhook_id: &str, secret: Option<&str>) -> GitHubHookRegistration {
+        GitHubHookRegistration {
+            owner: "Test-Owner".to_string(),
+            repository: "test-repo".to_string(),
+            webhook_id: webhook_id.to_string(),
+            name: "web".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            config_url: format!("http://n8n:5678/webhook/{webhook_id}/webhook"),
+            content_type: "json".to_string(),
+            secret: secret.map(str::to_string),
+            production: true,
+            enterprise: false,
+            enterprise_host: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_github_hook_shares_id_with_secret() {
+        let db = open_memory_db();
+
+        let row = db
+            .upsert_github_hook(&github_hook("wh1", Some("s1")))
+            .unwrap();
+        assert_eq!(row.secret.as_deref(), Some("s1"));
+        assert_eq!(row.events, vec!["push"]);
+        assert!(row.active);
+
+        // Re-registering keeps the ID and updates the secret
+        let again = db
+            .upsert_github_hook(&github_hook("wh1", Some("s2")))
+            .unwrap();
+        assert_eq!(again.id, row.id);
+        assert_eq!(db.get_webhook_secret("wh1").unwrap().as_deref(), Some("s2"));
+    }
+
+    #[test]
+    fn test_upsert_github_hook_adopts_existing_secret_row() {
+        let db = open_memory_db();
+        let secret_id = db.upsert_webhook_secret("wh1", "github", "old").unwrap();
+
+        let row = db.upsert_github_hook(&github_hook("wh1", None)).unwrap();
+
+        assert_eq!(row.id, secret_id);
+        assert_eq!(row.secret.as_deref(), Some("old"));
+    }
+
+    #[test]
+    fn test_list_and_get_github_hooks_scoped_to_repo() {
+        let db = open_memory_db();
+        let a = db.upsert_github_hook(&github_hook("wh-a", None)).unwrap();
+        let mut other = github_hook("wh-b", None);
+        other.repository = "other-repo".to_string();
+        let b = db.upsert_github_hook(&other).unwrap();
+
+        let rows = db
+            .list_github_hooks(false, "test-owner", "TEST-REPO")
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, a.id);
+
+        assert!(
+            db.get_github_hook(false, "test-owner", "test-repo", a.id)
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            db.get_github_hook(false, "test-owner", "test-repo", b.id)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_update_github_hook() {
+        let db = open_memory_db();
+        let row = db
+            .upsert_github_hook(&github_hook("wh1", Some("s1")))
+            .unwrap();
+
+        let mut patch = github_hook("wh2", None);
+        patch.events = vec!["issues".to_string()];
+        patch.active = false;
+        let updated = db.update_github_hook(row.id, &patch).unwrap().unwrap();
+
+        assert_eq!(updated.id, row.id);
+        assert_eq!(updated.webhook_id, "wh2");
+        assert_eq!(updated.events, vec!["issues"]);
+        assert!(!updated.active);
+        // Secret kept and moved to the new webhook ID
+        assert_eq!(db.get_webhook_secret("wh2").unwrap().as_deref(), Some("s1"));
+        assert!(db.update_github_hook(9999, &patch).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_delete_github_hook_removes_secret() {
+        let db = open_memory_db();
+        let row = db
+            .upsert_github_hook(&github_hook("wh1", Some("s1")))
+            .unwrap();
+
+        assert!(db.delete_github_hook(row.id).unwrap());
+        assert!(db.get_webhook_secret("wh1").unwrap().is_none());
+        assert!(
+            db.list_github_hooks(false, "test-owner", "test-repo")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(!db.delete_github_hook(row.id).unwrap());
+    }
+
+    #[test]
+    fn test_github_hook_registration_creates_trigger() {
+        let db = open_memory_db();
+        let row = db
+            .upsert_github_hook(&github_hook("wh1", Some("s1")))
+            .unwrap();
+
+        let rows = db
+            .query_github_triggers(Some("test-owner"), Some("test-repo"))
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "wh1");
+        assert!(rows[0].workflow_active);
+        assert_eq!(rows[0].events, vec!["push"]);
+        assert_eq!(rows[0].secret.as_deref(), Some("s1"));
+
+        db.delete_github_hook(row.id).unwrap();
+        assert_eq!(db.count_github_triggers().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_github_hook_update_moves_trigger() {
+        let db = open_memory_db();
+        let row = db.upsert_github_hook(&github_hook("wh1", None)).unwrap();
+
+        let mut patch = github_hook("wh2", None);
+        patch.active = false;
+        db.update_github_hook(row.id, &patch).unwrap();
+
+        let rows = db
+            .query_github_triggers(Some("test-owner"), Some("test-repo"))
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "wh2");
+        assert!(!rows[0].workflow_active);
+    }
+
+    #[test]
+    fn test_github_test_mode_hook_does_not_activate_trigger() {
+        let db = open_memory_db();
+        let mut test_hook = github_hook("wh1", None);
+        test_hook.config_url = "http://n8n:5678/webhook-test/wh1/webhook".to_string();
+        test_hook.production = false;
+        db.upsert_github_hook(&test_hook).unwrap();
+
+        let rows = db.query_github_triggers(Some("test-owner"), Some("test-repo"));
+        assert!(!rows.unwrap()[0].workflow_active);
+
+        // A test-mode re-registration keeps an active production trigger active
+        db.upsert_github_hook(&github_hook("wh1", None)).unwrap();
+        db.upsert_github_hook(&test_hook).unwrap();
+        let rows = db.query_github_triggers(Some("test-owner"), Some("test-repo"));
+        assert!(rows.unwrap()[0].workflow_active);
+    }
+
+    #[test]
+    fn test_github_hook_without_webhook_id_gets_no_trigger() {
+        let db = open_memory_db();
+        let mut hook = github_hook("unknown-test-owner-test-repo", None);
+        hook.config_url = "http://example.com/not-n8n".to_string();
+        db.upsert_github_hook(&hook).unwrap();
+
+        assert_eq!(db.count_github_triggers().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_github_sync_reconciles_registered_triggers() {
+        let db = open_memory_db();
+        db.upsert_github_hook(&github_hook("wh-hook", None))
+            .unwrap();
+        db.upsert_github_hook(&github_hook("wh-n8n-too", None))
+            .unwrap();
+        let trigger = |id: &str| GitHubTriggerConfig {
+            webhook_id: id.to_string(),
+            workflow_id: format!("wf-{id}"),
+            workflow_name: format!("Workflow {id}"),
+            workflow_active: true,
+            events: vec!["pull_request".to_string()],
+            owner: "test-owner".to_string(),
+            repository: "test-repo".to_string(),
+            webhook_secret: None,
+            actions: vec!["pull_request.opened".to_string()],
+            installation_id: None,
+        };
+        db.sync_github_triggers(&[
+            trigger("wh-hook"),
+            trigger("wh-n8n-too"),
+            trigger("wh-n8n-only"),
+        ])
+        .unwrap();
+        // n8n no longer reports two workflows, one of them hook-backed
+        db.sync_github_triggers(&[trigger("wh-n8n-too")]).unwrap();
+
+        let rows = db
+            .query_github_triggers(Some("test-owner"), Some("test-repo"))
+            .unwrap();
+        let ids: Vec<&str> = rows.iter().map(|r| r.webhook_id.as_str()).collect();
+        assert_eq!(ids, vec!["wh-n8n-too"]);
+        // Reconciled row picks up n8n's metadata and annotations
+        assert_eq!(rows[0].workflow_name, "Workflow wh-n8n-too");
+        assert_eq!(rows[0].actions, vec!["pull_request.opened"]);
+    }
+
     // ── github_triggers tests ───────────────────────────────────────────
 
     #[test]
@@ -760,6 +2187,8 @@ mod tests {
             owner: "test-owner".to_string(),
             repository: "test-repo".to_string(),
             webhook_secret: None,
+            actions: vec!["pull_request.opened".to_string()],
+            installation_id: None,
         }];
 
         db.sync_github_triggers(&triggers).unwrap();
@@ -770,6 +2199,7 @@ mod tests {
         assert_eq!(rows.len(), 1);
         assert_eq!(rows[0].webhook_id, "wh1");
         assert_eq!(rows[0].events, vec!["push"]);
+        assert_eq!(rows[0].actions, vec!["pull_request.opened"]);
         assert!(rows[0].secret.is_none());
     }
 
@@ -789,6 +2219,8 @@ mod tests {
             owner: "test-owner".to_string(),
             repository: "test-repo".to_string(),
             webhook_secret: None,
+            actions: vec![],
+            installation_id: None,
         }];
         db.sync_github_triggers(&triggers).unwrap();
 
@@ -811,6 +2243,8 @@ mod tests {
             owner: "Test-Owner".to_string(),
             repository: "Test-Repo".to_string(),
             webhook_secret: None,
+            actions: vec![],
+            installation_id: None,
         }];
         db.sync_github_triggers(&triggers).unwrap();
 
@@ -820,6 +2254,43 @@ mod tests {
         assert_eq!(rows.len(), 1);
     }
 
+    #[test]
+    fn test_github_query_owner_only_for_org_events() {
+        let db = open_memory_db();
+
+        let trigger = |id: &str, owner: &str, repository: &str| GitHubTriggerConfig {
+            webhook_id: id.to_string(),
+            workflow_id: format!("wf-{id}"),
+            workflow_name: id.to_string(),
+            workflow_active: true,
+            events: vec!["organization".to_string()],
+            owner: owner.to_string(),
+            repository: repository.to_string(),
+            webhook_secret: None,
+            actions: vec![],
+            installation_id: None,
+        };
+        db.sync_github_triggers(&[
+            trigger("acme-repo", "Acme", "project"),
+            trigger("other-repo", "other", "project"),
+            trigger("catch-all", "", ""),
+        ])
+        .unwrap();
+
+        let mut ids: Vec<String> = db
+            .query_github_triggers(Some("acme"), None)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.webhook_id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["acme-repo", "catch-all"]);
+
+        let rows = db.query_github_triggers(None, None).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "catch-all");
+    }
+
     #[test]
     fn test_sync_replaces_all_github_triggers() {
         let db = open_memory_db();
@@ -833,6 +2304,8 @@ mod tests {
             owner: "o".to_string(),
             repository: "r".to_string(),
             webhook_secret: None,
+            actions: vec![],
+            installation_id: None,
         }];
         db.sync_github_triggers(&triggers1).unwrap();
         assert_eq!(db.count_github_triggers().unwrap(), 1);
@@ -846,6 +2319,8 @@ mod tests {
             owner: "o".to_string(),
             repository: "r".to_string(),
             webhook_secret: None,
+            actions: vec![],
+            installation_id: None,
         }];
         db.sync_github_triggers(&triggers2).unwrap();
         assert_eq!(db.count_github_triggers().unwrap(), 1);
@@ -854,6 +2329,344 @@ mod tests {
         assert_eq!(rows[0].webhook_id, "wh-new");
     }
 
+    // ── webhook secret garbage collection tests ─────────────────────────
+
+    #[test]
+    fn test_orphaned_secret_quarantined_then_deleted() {
+        let db = open_memory_db();
+        db.upsert_github_hook(&github_hook("wh-live", Some("s1")))
+            .unwrap();
+        db.upsert_webhook_secret("wh-deleted-workflow", "github", "s2")
+            .unwrap();
+
+        // Long grace period: quarantined, not deleted
+        let report = db.collect_orphaned_webhook_secrets(86400).unwrap();
+        assert_eq!(report.quarantined, vec!["wh-deleted-workflow"]);
+        assert!(report.deleted.is_empty());
+        assert!(
+            db.get_webhook_secret("wh-deleted-workflow")
+                .unwrap()
+                .is_some()
+        );
+
+        // A second pass doesn't re-report it
+        let report = db.collect_orphaned_webhook_secrets(86400).unwrap();
+        assert!(report.quarantined.is_empty());
+
+        let report = db.collect_orphaned_webhook_secrets(0).unwrap();
+        assert_eq!(report.deleted.len(), 1);
+        assert_eq!(report.deleted[0].webhook_id, "wh-deleted-workflow");
+        assert_eq!(report.deleted[0].provider, "github");
+        assert!(
+            db.get_webhook_secret("wh-deleted-workflow")
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            db.get_webhook_secret("wh-live").unwrap().as_deref(),
+            Some("s1")
+        );
+    }
+
+    #[test]
+    fn test_deleted_workflow_hook_secret_quarantined_then_deleted() {
+        let db = open_memory_db();
+        let hook = db
+            .upsert_github_hook(&github_hook("wh-gone", Some("s1")))
+            .unwrap();
+        db.upsert_github_hook(&github_hook("wh-kept", Some("s2")))
+            .unwrap();
+        db.sync_github_triggers(&[
+            installation_trigger("wh-gone", None),
+            installation_trigger("wh-kept", None),
+        ])
+        .unwrap();
+        assert!(
+            db.collect_orphaned_webhook_secrets(86400)
+                .unwrap()
+                .quarantined
+                .is_empty()
+        );
+
+        // The workflow is deleted in n8n without deregistering its hook
+        db.sync_github_triggers(&[installation_trigger("wh-kept", None)])
+            .unwrap();
+
+        let report = db.collect_orphaned_webhook_secrets(86400).unwrap();
+        assert_eq!(report.quarantined, vec!["wh-gone"]);
+        assert!(report.deleted.is_empty());
+
+        let report = db.collect_orphaned_webhook_secrets(0).unwrap();
+        let deleted: Vec<&str> = report
+            .deleted
+            .iter()
+            .map(|d| d.webhook_id.as_str())
+            .collect();
+        assert_eq!(deleted, vec!["wh-gone"]);
+        assert!(db.get_webhook_secret("wh-gone").unwrap().is_none());
+        assert!(
+            db.get_github_hook(false, "test-owner", "test-repo", hook.id)
+                .unwrap()
+                .is_none()
+        );
+        assert!(db.get_webhook_secret("wh-kept").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_deleted_workflow_jira_registration_removed_on_sync() {
+        let db = open_memory_db();
+        db.upsert_jira_webhook(&sample_jira_webhook("acme", "jh-gone"))
+            .unwrap();
+        let kept = db
+            .upsert_jira_webhook(&sample_jira_webhook("acme", "jh-kept"))
+            .unwrap();
+        let trigger = JiraTriggerConfig {
+            webhook_id: "jh-kept".to_string(),
+            workflow_id: "wf1".to_string(),
+            workflow_name: "Jira Test".to_string(),
+            workflow_active: true,
+            events: vec!["*".to_string()],
+            jql_filter: None,
+            exclude_body: false,
+            include_fields: vec![],
+            projects: vec![],
+        };
+
+        db.sync_jira_triggers(&[trigger]).unwrap();
+
+        let hooks = db.list_jira_webhooks("acme").unwrap();
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0].id, kept.id);
+        let rows = db.query_jira_triggers().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "jh-kept");
+    }
+
+    #[test]
+    fn test_orphaned_fallback_hook_deleted_with_registration() {
+        let db = open_memory_db();
+        // config.url without a webhook ID: stored under the fallback ID, no trigger
+        let hook = db
+            .upsert_github_hook(&GitHubHookRegistration {
+                config_url: "http://n8n:5678/custom".to_string(),
+                ..github_hook("unknown-test-owner-test-repo", Some("s"))
+            })
+            .unwrap();
+
+        let report = db.collect_orphaned_webhook_secrets(0).unwrap();
+
+        assert_eq!(report.deleted.len(), 1);
+        assert!(
+            db.get_github_hook(false, "test-owner", "test-repo", hook.id)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_quarantined_secret_restored_when_trigger_returns() {
+        let db = open_memory_db();
+        db.upsert_webhook_secret("wh1", "github", "s").unwrap();
+        db.collect_orphaned_webhook_secrets(86400).unwrap();
+
+        db.sync_github_triggers(&[installation_trigger("wh1", None)])
+            .unwrap();
+        let report = db.collect_orphaned_webhook_secrets(0).unwrap();
+
+        assert_eq!(report.restored, vec!["wh1"]);
+        assert!(report.deleted.is_empty());
+        assert!(db.get_webhook_secret("wh1").unwrap().is_some());
+    }
+
+    // ── webhook secret encryption tests ─────────────────────────────────
+
+    const SECRET_KEY_A: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const SECRET_KEY_B: &str = "1f1e1d1c1b1a191817161514131211100f0e0d0c0b0a09080706050403020100";
+
+    fn secret_cipher(current: &str, previous: &[&str]) -> Option<SecretCipher> {
+        let previous: Vec<String> = previous.iter().map(|k| k.to_string()).collect();
+        Some(SecretCipher::new(current, &previous).unwrap())
+    }
+
+    fn stored_secret(db: &Database, webhook_id: &str) -> String {
+        db.conn
+            .lock()
+            .query_row(
+                "SELECT secret FROM webhook_secrets WHERE webhook_id = ?1",
+                rusqlite::params![webhook_id],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_webhook_secrets_encrypted_at_rest() {
+        let db = open_memory_db().with_secret_cipher(secret_cipher(SECRET_KEY_A, &[]));
+        let hook = db
+            .upsert_github_hook(&github_hook("wh1", Some("hook-secret")))
+            .unwrap();
+        assert_eq!(hook.secret.as_deref(), Some("hook-secret"));
+
+        let stored = stored_secret(&db, "wh1");
+        assert!(stored.starts_with("enc:v1:"));
+        assert!(!stored.contains("hook-secret"));
+
+        let rows = db
+            .query_github_triggers(Some("test-owner"), Some("test-repo"))
+            .unwrap();
+        assert_eq!(rows[0].secret.as_deref(), Some("hook-secret"));
+        let hooks = db
+            .list_github_hooks(false, "test-owner", "test-repo")
+            .unwrap();
+        assert_eq!(hooks[0].secret.as_deref(), Some("hook-secret"));
+        assert_eq!(
+            db.get_webhook_secret("wh1").unwrap().as_deref(),
+            Some("hook-secret")
+        );
+    }
+
+    #[test]
+    fn test_encrypt_webhook_secrets_migrates_plaintext() {
+        let db = open_memory_db();
+        db.upsert_webhook_secret("wh1", "github", "legacy").unwrap();
+        // Hooks registered without a secret keep their empty placeholder
+        db.upsert_github_hook(&github_hook("wh2", None)).unwrap();
+
+        let db = db.with_secret_cipher(secret_cipher(SECRET_KEY_A, &[]));
+        let report = db.encrypt_webhook_secrets(false).unwrap();
+
+        assert_eq!(report.encrypted, 1);
+        assert!(report.unreadable.is_empty());
+        assert!(stored_secret(&db, "wh1").starts_with("enc:v1:"));
+        assert_eq!(stored_secret(&db, "wh2"), "");
+        assert_eq!(
+            db.get_webhook_secret("wh1").unwrap().as_deref(),
+            Some("legacy")
+        );
+        // Already encrypted: nothing left to do
+        assert_eq!(db.encrypt_webhook_secrets(false).unwrap().encrypted, 0);
+    }
+
+    #[test]
+    fn test_encrypt_webhook_secrets_rotates_to_current_key() {
+        let db = open_memory_db().with_secret_cipher(secret_cipher(SECRET_KEY_A, &[]));
+        db.upsert_webhook_secret("wh1", "github", "s1").unwrap();
+
+        let db = db.with_secret_cipher(secret_cipher(SECRET_KEY_B, &[SECRET_KEY_A]));
+        // Readable with the previous key, but only rotated on request
+        assert_eq!(db.get_webhook_secret("wh1").unwrap().as_deref(), Some("s1"));
+        assert_eq!(db.encrypt_webhook_secrets(false).unwrap().rotated, 0);
+        assert_eq!(db.encrypt_webhook_secrets(true).unwrap().rotated, 1);
+
+        // The previous key is no longer needed
+        let db = db.with_secret_cipher(secret_cipher(SECRET_KEY_B, &[]));
+        assert_eq!(db.get_webhook_secret("wh1").unwrap().as_deref(), Some("s1"));
+    }
+
+    #[test]
+    fn test_encrypted_secret_unreadable_without_key() {
+        let db = open_memory_db().with_secret_cipher(secret_cipher(SECRET_KEY_A, &[]));
+        db.upsert_webhook_secret("wh1", "github", "s1").unwrap();
+
+        let db = db.with_secret_cipher(None);
+        assert!(db.get_webhook_secret("wh1").unwrap().is_none());
+        let report = db.encrypt_webhook_secrets(false).unwrap();
+        assert_eq!(report.unreadable, vec!["wh1"]);
+        assert!(stored_secret(&db, "wh1").starts_with("enc:v1:"));
+    }
+
+    // ── GitHub App installation tests ───────────────────────────────────
+
+    fn installation_trigger(webhook_id: &str, installation_id: Option<i64>) -> GitHubTriggerConfig {
+        GitHubTriggerConfig {
+            webhook_id: webhook_id.to_string(),
+            workflow_id: format!("wf-{webhook_id}"),
+            workflow_name: webhook_id.to_string(),
+            workflow_active: true,
+            events: vec!["push".to_string()],
+            owner: "acme".to_string(),
+            repository: "api".to_string(),
+            webhook_secret: None,
+            actions: vec![],
+            installation_id,
+        }
+    }
+
+    #[test]
+    fn test_github_installation_repository_map() {
+        let db = open_memory_db();
+        db.replace_github_installation(
+            42,
+            "acme",
+            &["acme/api".to_string(), "acme/web".to_string()],
+        )
+        .unwrap();
+        db.add_github_installation_repositories(42, "", &["acme/docs".to_string()])
+            .unwrap();
+        db.remove_github_installation_repositories(42, &["ACME/web".to_string()])
+            .unwrap();
+        assert_eq!(
+            db.list_github_installation_repositories(42).unwrap(),
+            vec!["acme/api", "acme/docs"]
+        );
+
+        // A later `created` replaces the list
+        db.replace_github_installation(42, "acme", &["acme/web".to_string()])
+            .unwrap();
+        assert_eq!(
+            db.list_github_installation_repositories(42).unwrap(),
+            vec!["acme/web"]
+        );
+
+        assert!(db.delete_github_installation(42).unwrap());
+        assert!(
+            db.list_github_installation_repositories(42)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(!db.delete_github_installation(42).unwrap());
+    }
+
+    #[test]
+    fn test_installation_scoped_triggers_excluded_from_repository_query() {
+        let db = open_memory_db();
+        db.sync_github_triggers(&[
+            installation_trigger("wh-repo", None),
+            installation_trigger("wh-app", Some(42)),
+        ])
+        .unwrap();
+
+        let rows = db.query_github_triggers(Some("acme"), Some("api")).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "wh-repo");
+    }
+
+    #[test]
+    fn test_query_github_installation_triggers() {
+        let db = open_memory_db();
+        db.sync_github_triggers(&[
+            installation_trigger("wh-app", Some(42)),
+            installation_trigger("wh-other-app", Some(7)),
+        ])
+        .unwrap();
+        db.replace_github_installation(42, "acme", &["acme/api".to_string()])
+            .unwrap();
+
+        let ids = |installation_id: Option<u64>, full_name: Option<&str>| -> Vec<String> {
+            db.query_github_installation_triggers(installation_id, full_name)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.webhook_id)
+                .collect()
+        };
+        // Delivered for the installation (e.g. an org-level event)
+        assert_eq!(ids(Some(42), None), vec!["wh-app"]);
+        // Delivered by a repository webhook for a repository in the installation
+        assert_eq!(ids(None, Some("Acme/API")), vec!["wh-app"]);
+        assert!(ids(None, Some("acme/web")).is_empty());
+        assert!(ids(None, None).is_empty());
+    }
+
     // ── jira_triggers tests ─────────────────────────────────────────────
 
     #[test]
@@ -866,6 +2679,10 @@ mod tests {
             workflow_name: "Jira Test".to_string(),
             workflow_active: true,
             events: vec!["jira:issue_created".to_string()],
+            jql_filter: Some("project = PROJ".to_string()),
+            exclude_body: true,
+            include_fields: vec!["issue.key".to_string()],
+            projects: vec!["PROJ".to_string()],
         }];
         db.sync_jira_triggers(&triggers).unwrap();
 
@@ -873,6 +2690,229 @@ mod tests {
         assert_eq!(rows.len(), 1);
         assert_eq!(rows[0].webhook_id, "jh1");
         assert_eq!(rows[0].events, vec!["jira:issue_created"]);
+        assert_eq!(rows[0].jql_filter.as_deref(), Some("project = PROJ"));
+        assert!(rows[0].exclude_body);
+        assert_eq!(rows[0].include_fields, vec!["issue.key"]);
+        assert_eq!(rows[0].projects, vec!["PROJ"]);
+    }
+
+    // ── jira_webhooks tests ─────────────────────────────────────────────
+
+    fn sample_jira_webhook(site: &str, webhook_id: &str) -> JiraWebhookRegistration {
+        JiraWebhookRegistration {
+            site: site.to_string(),
+            webhook_id: webhook_id.to_string(),
+            name: format!("n8n-{webhook_id}"),
+            url: format!("http://n8n/webhook/{webhook_id}/webhook"),
+            events: vec!["jira:issue_created".to_string()],
+            filters: serde_json::json!({}),
+            exclude_body: false,
+            production: true,
+        }
+    }
+
+    #[test]
+    fn test_upsert_and_list_jira_webhooks() {
+        let db = open_memory_db();
+
+        let filters = serde_json::json!({"issue-related-events-section": "project = PROJ"});
+        let mut first = sample_jira_webhook("", "a");
+        first.filters = filters.clone();
+        first.exclude_body = true;
+        let row1 = db.upsert_jira_webhook(&first).unwrap();
+        let row2 = db
+            .upsert_jira_webhook(&sample_jira_webhook("", "b"))
+            .unwrap();
+        assert_ne!(row1.id, row2.id);
+
+        let rows = db.list_jira_webhooks("").unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].id, row1.id);
+        assert_eq!(rows[0].name, "n8n-a");
+        assert_eq!(rows[0].webhook_id, "a");
+        assert_eq!(rows[0].events, vec!["jira:issue_created"]);
+        assert_eq!(rows[0].filters, filters);
+        assert!(rows[0].exclude_body);
+        assert!(!rows[1].exclude_body);
+    }
+
+    #[test]
+    fn test_upsert_jira_webhook_same_url_keeps_id() {
+        let db = open_memory_db();
+
+        let row1 = db
+            .upsert_jira_webhook(&sample_jira_webhook("", "a"))
+            .unwrap();
+        let mut updated = sample_jira_webhook("", "a");
+        updated.name = "second".to_string();
+        updated.events = vec!["comment_created".to_string()];
+        let row2 = db.upsert_jira_webhook(&updated).unwrap();
+
+        assert_eq!(row1.id, row2.id);
+        let rows = db.list_jira_webhooks("").unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].name, "second");
+        assert_eq!(rows[0].events, vec!["comment_created"]);
+    }
+
+    #[test]
+    fn test_get_and_delete_jira_webhook() {
+        let db = open_memory_db();
+        let id = db
+            .upsert_jira_webhook(&sample_jira_webhook("", "x"))
+            .unwrap()
+            .id;
+
+        assert_eq!(
+            db.get_jira_webhook("", id).unwrap().unwrap().url,
+            "http://n8n/webhook/x/webhook"
+        );
+        assert!(db.delete_jira_webhook("", id).unwrap());
+        assert!(db.get_jira_webhook("", id).unwrap().is_none());
+        assert!(!db.delete_jira_webhook("", id).unwrap());
+    }
+
+    #[test]
+    fn test_get_and_delete_jira_webhook_scoped_by_site() {
+        let db = open_memory_db();
+        let id = db
+            .upsert_jira_webhook(&sample_jira_webhook("dc", "x"))
+            .unwrap()
+            .id;
+
+        assert!(db.get_jira_webhook("acme", id).unwrap().is_none());
+        assert!(!db.delete_jira_webhook("acme", id).unwrap());
+        assert_eq!(db.count_jira_triggers().unwrap(), 1);
+        assert!(db.get_jira_webhook("dc", id).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_list_jira_webhooks_scoped_by_site() {
+        let db = open_memory_db();
+        db.upsert_jira_webhook(&sample_jira_webhook("acme", "a"))
+            .unwrap();
+        db.upsert_jira_webhook(&sample_jira_webhook("dc", "b"))
+            .unwrap();
+
+        let acme = db.list_jira_webhooks("acme").unwrap();
+        assert_eq!(acme.len(), 1);
+        assert_eq!(acme[0].webhook_id, "a");
+        assert!(db.list_jira_webhooks("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_jira_trigger_site_from_registration() {
+        let db = open_memory_db();
+        let trigger = |webhook_id: &str| JiraTriggerConfig {
+            webhook_id: webhook_id.to_string(),
+            workflow_id: "wf1".to_string(),
+            workflow_name: "Jira Test".to_string(),
+            workflow_active: true,
+            events: vec!["*".to_string()],
+            jql_filter: None,
+            exclude_body: false,
+            include_fields: vec![],
+            projects: vec![],
+        };
+
+        // Registration before sync: site is filled in during the sync
+        db.upsert_jira_webhook(&sample_jira_webhook("acme", "jh1"))
+            .unwrap();
+        db.sync_jira_triggers(&[trigger("jh1"), trigger("jh2")])
+            .unwrap();
+
+        // Registration after sync: existing trigger row is updated in place
+        db.upsert_jira_webhook(&sample_jira_webhook("dc", "jh2"))
+            .unwrap();
+
+        let mut rows = db.query_jira_triggers().unwrap();
+        rows.sort_by(|a, b| a.webhook_id.cmp(&b.webhook_id));
+        assert_eq!(rows[0].site, "acme");
+        assert_eq!(rows[1].site, "dc");
+    }
+
+    #[test]
+    fn test_jira_webhook_registration_creates_trigger() {
+        let db = open_memory_db();
+        let mut hook = sample_jira_webhook("acme", "jh1");
+        hook.filters = serde_json::json!({"issue-related-events-section": "project IN (OPS, DEV)"});
+        hook.exclude_body = true;
+        let id = db.upsert_jira_webhook(&hook).unwrap().id;
+
+        let rows = db.query_jira_triggers().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "jh1");
+        assert!(rows[0].workflow_active);
+        assert_eq!(rows[0].site, "acme");
+        assert_eq!(rows[0].events, vec!["jira:issue_created"]);
+        assert_eq!(rows[0].jql_filter.as_deref(), Some("project IN (OPS, DEV)"));
+        assert_eq!(rows[0].projects, vec!["OPS", "DEV"]);
+        assert!(rows[0].exclude_body);
+
+        db.delete_jira_webhook("acme", id).unwrap();
+        assert_eq!(db.count_jira_triggers().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_jira_trigger_active_follows_production_registrations() {
+        let db = open_memory_db();
+        let mut test_hook = sample_jira_webhook("", "jh1");
+        test_hook.url = "http://n8n/webhook-test/jh1/webhook".to_string();
+        test_hook.production = false;
+        db.upsert_jira_webhook(&test_hook).unwrap();
+        assert!(!db.query_jira_triggers().unwrap()[0].workflow_active);
+
+        let prod_id = db
+            .upsert_jira_webhook(&sample_jira_webhook("", "jh1"))
+            .unwrap()
+            .id;
+        assert!(db.query_jira_triggers().unwrap()[0].workflow_active);
+
+        // Deleting the production registration keeps the test-mode trigger
+        db.delete_jira_webhook("", prod_id).unwrap();
+        let rows = db.query_jira_triggers().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert!(!rows[0].workflow_active);
+    }
+
+    #[test]
+    fn test_jira_sync_reconciles_registered_triggers() {
+        let db = open_memory_db();
+        let mut hook = sample_jira_webhook("acme", "jh1");
+        hook.filters = serde_json::json!({"issue-related-events-section": "project = OPS"});
+        db.upsert_jira_webhook(&hook).unwrap();
+        let trigger = |webhook_id: &str| JiraTriggerConfig {
+            webhook_id: webhook_id.to_string(),
+            workflow_id: "wf1".to_string(),
+            workflow_name: "Jira Test".to_string(),
+            workflow_active: true,
+            events: vec!["jira:issue_created".to_string()],
+            jql_filter: Some("project = OPS".to_string()),
+            exclude_body: false,
+            include_fields: vec!["issue.key".to_string()],
+            projects: vec!["OPS".to_string(), "DEV".to_string()],
+        };
+        db.sync_jira_triggers(&[trigger("jh1"), trigger("jh2")])
+            .unwrap();
+        db.sync_jira_triggers(&[trigger("jh1")]).unwrap();
+
+        // Only the reported trigger survives, with its registration's site
+        let rows = db.query_jira_triggers().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].workflow_name, "Jira Test");
+        assert_eq!(rows[0].include_fields, vec!["issue.key"]);
+        assert_eq!(rows[0].site, "acme");
+
+        // Re-registering keeps the annotation-derived project scope
+        db.upsert_jira_webhook(&hook).unwrap();
+        assert_eq!(
+            db.query_jira_triggers().unwrap()[0].projects,
+            vec!["OPS", "DEV"]
+        );
+
+        // A deleted workflow's trigger goes even though it is registered
+        db.sync_jira_triggers(&[]).unwrap();
+        assert_eq!(db.count_jira_triggers().unwrap(), 0);
     }
 
     // ── zoom_triggers tests ─────────────────────────────────────────────
@@ -887,6 +2927,7 @@ mod tests {
             owner_email: Some("owner@example.com".to_string()),
             project_id: "proj1".to_string(),
             project_type: "personal".to_string(),
+            account_id: None,
         }
     }
 
@@ -903,6 +2944,32 @@ mod tests {
         assert_eq!(rows[0].events, vec!["meeting.started"]);
         assert_eq!(rows[0].owner_email.as_deref(), Some("owner@example.com"));
         assert_eq!(rows[0].project_type, "personal");
+        assert!(rows[0].account_id.is_none());
+    }
+
+    #[test]
+    fn test_sync_zoom_triggers_stores_account() {
+        let db = open_memory_db();
+
+        let mut trigger = sample_zoom_trigger("zh1", "wf1", "Subsidiary");
+        trigger.account_id = Some("sub1".to_string());
+        db.sync_zoom_triggers(&[trigger]).unwrap();
+
+        let rows = db.query_zoom_triggers().unwrap();
+        assert_eq!(rows[0].account_id.as_deref(), Some("sub1"));
+    }
+
+    #[test]
+    fn test_zoom_user_email_cache() {
+        let db = open_memory_db();
+        assert_eq!(db.get_zoom_user_email("u1").unwrap(), None);
+
+        db.upsert_zoom_user("u1", "old@example.com").unwrap();
+        db.upsert_zoom_user("u1", "new@example.com").unwrap();
+        assert_eq!(
+            db.get_zoom_user_email("u1").unwrap().as_deref(),
+            Some("new@example.com")
+        );
     }
 
     #[test]
@@ -919,6 +2986,7 @@ mod tests {
                 owner_email: None,
                 project_id: String::new(),
                 project_type: String::new(),
+                account_id: None,
             },
             ZoomTriggerConfig {
                 webhook_id: "same".to_string(),
@@ -929,6 +2997,7 @@ mod tests {
                 owner_email: Some("owner@example.com".to_string()),
                 project_id: "proj1".to_string(),
                 project_type: "personal".to_string(),
+                account_id: None,
             },
         ];
         db.sync_zoom_triggers(&triggers).unwrap();
@@ -953,6 +3022,8 @@ mod tests {
             event_type: "message".to_string(),
             channels: vec!["C123".to_string()],
             watch_whole_workspace: false,
+            channel_types: Some(vec!["im".to_string()]),
+            thread_filter: SlackThreadFilter::Replies,
         }];
         db.sync_slack_triggers(&triggers).unwrap();
 
@@ -961,6 +3032,32 @@ mod tests {
         assert_eq!(rows[0].event_type, "message");
         assert_eq!(rows[0].channels, vec!["C123"]);
         assert!(!rows[0].watch_whole_workspace);
+        assert_eq!(rows[0].channel_types, Some(vec!["im".to_string()]));
+        assert_eq!(rows[0].thread_filter, SlackThreadFilter::Replies);
+    }
+
+    #[test]
+    fn test_sync_slack_triggers_keeps_empty_channel_type_filter() {
+        let db = open_memory_db();
+
+        let trigger = |webhook_id: &str, channel_types| SlackTriggerConfig {
+            webhook_id: webhook_id.to_string(),
+            workflow_id: "wf1".to_string(),
+            workflow_name: "Slack Test".to_string(),
+            workflow_active: true,
+            event_type: "message".to_string(),
+            channels: vec![],
+            watch_whole_workspace: true,
+            channel_types,
+            thread_filter: SlackThreadFilter::Any,
+        };
+        db.sync_slack_triggers(&[trigger("sh-none", None), trigger("sh-empty", Some(vec![]))])
+            .unwrap();
+
+        let rows = db.query_slack_triggers().unwrap();
+        let row = |id: &str| rows.iter().find(|r| r.webhook_id == id).unwrap();
+        assert_eq!(row("sh-none").channel_types, None);
+        assert_eq!(row("sh-empty").channel_types, Some(vec![]));
     }
 
     #[test]
@@ -976,6 +3073,8 @@ mod tests {
                 event_type: "message".to_string(),
                 channels: vec![],
                 watch_whole_workspace: true,
+                channel_types: None,
+                thread_filter: SlackThreadFilter::Any,
             },
             SlackTriggerConfig {
                 webhook_id: "same".to_string(),
@@ -985,6 +3084,8 @@ mod tests {
                 event_type: "any_event".to_string(),
                 channels: vec![],
                 watch_whole_workspace: true,
+                channel_types: None,
+                thread_filter: SlackThreadFilter::Any,
             },
         ];
         db.sync_slack_triggers(&triggers).unwrap();
@@ -994,4 +3095,37 @@ mod tests {
         assert_eq!(rows[0].workflow_name, "Active dup");
         assert!(rows[0].workflow_active);
     }
+
+    #[test]
+    fn test_slack_trigger_migrations_upgrade_existing_table() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE slack_triggers (
+                webhook_id TEXT PRIMARY KEY,
+                workflow_id TEXT NOT NULL,
+                workflow_name TEXT NOT NULL,
+                workflow_active BOOLEAN NOT NULL DEFAULT 0,
+                event_type TEXT NOT NULL DEFAULT '',
+                channels TEXT NOT NULL DEFAULT '[]',
+                watch_whole_workspace BOOLEAN NOT NULL DEFAULT 0
+            );
+            INSERT INTO slack_triggers (webhook_id, workflow_id, workflow_name)
+            VALUES ('sh-old', 'wf1', 'Old');",
+        )
+        .unwrap();
+
+        Database::apply_column_migrations(&conn, SLACK_TRIGGER_MIGRATIONS).unwrap();
+        // Second run must be a no-op
+        Database::apply_column_migrations(&conn, SLACK_TRIGGER_MIGRATIONS).unwrap();
+
+        let (channel_types, thread_filter): (String, String) = conn
+            .query_row(
+                "SELECT channel_types, thread_filter FROM slack_triggers WHERE webhook_id = 'sh-old'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(channel_types, "[]");
+        assert_eq!(thread_filter, "any");
+    }
 }
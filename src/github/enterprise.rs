@@ -0,0 +1,42 @@
+use axum::http::HeaderMap;
+
+/// Path prefix of the GitHub Enterprise Server REST API (`https://<host>/api/v3`).
+pub const GHES_API_PREFIX: &str = "/api/v3";
+
+/// GitHub Enterprise Server version the provider mock reports.
+pub const GHES_VERSION: &str = "3.14.0";
+
+/// Header GitHub Enterprise Server adds to webhook deliveries and API responses
+/// with the instance version.
+pub const GHES_VERSION_HEADER: &str = "x-github-enterprise-version";
+
+/// Header GitHub Enterprise Server adds to webhook deliveries with the
+/// instance's hostname. github.com deliveries don't carry it.
+pub const GHES_HOST_HEADER: &str = "x-github-enterprise-host";
+
+/// The GitHub Enterprise Server host a webhook delivery came from, or `None`
+/// for github.com deliveries.
+pub fn enterprise_host(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(GHES_HOST_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|h| !h.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enterprise_host_from_delivery_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(enterprise_host(&headers), None);
+
+        headers.insert(GHES_HOST_HEADER, "ghe.example.com".parse().unwrap());
+        assert_eq!(enterprise_host(&headers), Some("ghe.example.com"));
+
+        headers.insert(GHES_HOST_HEADER, " ".parse().unwrap());
+        assert_eq!(enterprise_host(&headers), None);
+    }
+}
@@ -1,5 +1,7 @@
+pub mod enterprise;
 pub mod models;
 pub mod triggers;
 
+pub use enterprise::*;
 pub use models::*;
 pub use triggers::*;
@@ -19,6 +19,24 @@ pub struct GitHubWebhookPayload {
     /// Action field (e.g., "opened", "closed") -- present on most events except push/ping
     pub action: Option<String>,
 
+    /// Organization the event belongs to (org webhooks and org-owned repositories)
+    pub organization: Option<GitHubOwner>,
+
+    /// GitHub App installation (present on events delivered to an App)
+    pub installation: Option<GitHubInstallation>,
+
+    /// Repositories the App can access (`installation` events)
+    pub repositories: Option<Vec<GitHubRepositoryRef>>,
+
+    /// Repositories added to the installation (`installation_repositories` events)
+    pub repositories_added: Option<Vec<GitHubRepositoryRef>>,
+
+    /// Repositories removed from the installation (`installation_repositories` events)
+    pub repositories_removed: Option<Vec<GitHubRepositoryRef>>,
+
+    /// User who triggered the event
+    pub sender: Option<GitHubOwner>,
+
     /// Capture any additional fields (we don't need them for routing,
     /// but this lets us inspect the payload in logs if needed)
     #[serde(flatten)]
@@ -39,6 +57,13 @@ pub struct GitHubRepository {
     pub owner: GitHubOwner,
 }
 
+/// Abbreviated repository entry listed on App installation events
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitHubRepositoryRef {
+    /// Full repository name (e.g., "n8n-io/n8n")
+    pub full_name: String,
+}
+
 /// Repository owner from a GitHub webhook payload
 #[derive(Debug, Clone, Deserialize)]
 #[allow(dead_code)]
@@ -47,6 +72,18 @@ pub struct GitHubOwner {
     pub login: String,
 }
 
+/// GitHub App installation from a webhook payload
+#[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)]
+pub struct GitHubInstallation {
+    /// Installation ID
+    pub id: Option<u64>,
+
+    /// Account (organization or user) the App is installed on; only sent on
+    /// `installation` and `installation_repositories` events
+    pub account: Option<GitHubOwner>,
+}
+
 impl GitHubWebhookPayload {
     /// Check if this payload is a ping event.
     ///
@@ -56,6 +93,27 @@ impl GitHubWebhookPayload {
     pub fn is_ping(&self) -> bool {
         self.hook_id.is_some() && self.action.is_none()
     }
+
+    /// Owner login used for routing.
+    ///
+    /// The repository owner when the event has a repository; otherwise the
+    /// organization (`organization.login`) or App installation account
+    /// (`installation.account.login`) for repository-less org-level events
+    /// such as `organization`, `membership`, `team`, and `installation`.
+    pub fn owner_login(&self) -> Option<&str> {
+        if let Some(repo) = &self.repository {
+            return Some(repo.owner.login.as_str());
+        }
+        self.organization
+            .as_ref()
+            .or_else(|| self.installation.as_ref()?.account.as_ref())
+            .map(|o| o.login.as_str())
+    }
+
+    /// GitHub App installation ID, when the event was delivered to an App.
+    pub fn installation_id(&self) -> Option<u64> {
+        self.installation.as_ref()?.id
+    }
 }
 
 #[cfg(test)]
@@ -231,4 +289,88 @@ mod tests {
         assert_eq!(payload.extra["custom_field"], "value");
         assert_eq!(payload.extra["number"], 42);
     }
+
+    #[test]
+    fn test_owner_login_prefers_repository_owner() {
+        let json = r#"{
+            "action": "added",
+            "member": { "login": "newbie" },
+            "repository": {
+                "name": "project",
+                "full_name": "org/project",
+                "owner": { "login": "org" }
+            },
+            "organization": { "login": "org" }
+        }"#;
+
+        let payload: GitHubWebhookPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.owner_login(), Some("org"));
+    }
+
+    #[test]
+    fn test_owner_login_for_org_level_events() {
+        // (event, payload) pairs as delivered by an organization webhook
+        let cases = [
+            (
+                "organization",
+                r#"{"action": "member_added", "membership": {"user": {"login": "u"}}, "organization": {"login": "acme"}}"#,
+            ),
+            (
+                "membership",
+                r#"{"action": "added", "scope": "team", "member": {"login": "u"}, "team": {"name": "core"}, "organization": {"login": "acme"}}"#,
+            ),
+            (
+                "team",
+                r#"{"action": "created", "team": {"name": "core"}, "organization": {"login": "acme"}}"#,
+            ),
+            (
+                "member",
+                r#"{"action": "added", "member": {"login": "u"}, "organization": {"login": "acme"}}"#,
+            ),
+            (
+                "installation",
+                r#"{"action": "created", "installation": {"id": 1, "account": {"login": "acme"}}}"#,
+            ),
+        ];
+
+        for (event, json) in cases {
+            let payload: GitHubWebhookPayload = serde_json::from_str(json).unwrap();
+            assert!(payload.repository.is_none(), "{event}");
+            assert_eq!(payload.owner_login(), Some("acme"), "{event}");
+        }
+    }
+
+    #[test]
+    fn test_owner_login_none_without_owner_info() {
+        let payload: GitHubWebhookPayload = serde_json::from_str(r#"{"action": "x"}"#).unwrap();
+        assert!(payload.owner_login().is_none());
+    }
+
+    #[test]
+    fn test_parse_installation_repositories_event() {
+        let json = r#"{
+            "action": "added",
+            "installation": { "id": 42, "account": { "login": "acme" } },
+            "repository_selection": "selected",
+            "repositories_added": [
+                { "id": 1, "name": "api", "full_name": "acme/api", "private": true }
+            ],
+            "repositories_removed": [],
+            "sender": { "login": "octocat" }
+        }"#;
+
+        let payload: GitHubWebhookPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.installation_id(), Some(42));
+        let added = payload.repositories_added.as_ref().unwrap();
+        assert_eq!(added[0].full_name, "acme/api");
+        assert!(payload.repositories_removed.unwrap().is_empty());
+        assert!(payload.repositories.is_none());
+        assert_eq!(payload.sender.unwrap().login, "octocat");
+    }
+
+    #[test]
+    fn test_installation_id_absent_for_repository_webhooks() {
+        let payload: GitHubWebhookPayload = serde_json::from_str(r#"{"action": "x"}"#).unwrap();
+        assert!(payload.installation_id().is_none());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::n8n::{Workflow, WorkflowNode};
+use tracing::warn;
 
 /// Extracted configuration from a GitHub Trigger node
 #[derive(Debug, Clone)]
@@ -45,6 +46,17 @@ pub struct GitHubTriggerConfig {
     /// secret). When our middleware forwards events to n8n, we must re-sign the
     /// payload with this secret so n8n's verification passes.
     pub webhook_secret: Option<String>,
+
+    /// `event.action` filters from the node's `unihook.actions` annotation
+    /// (e.g. `pull_request.opened`). An event type with filters only receives
+    /// the listed actions; event types without filters receive every action.
+    pub actions: Vec<String>,
+
+    /// GitHub App installation from the node's `unihook.installation`
+    /// annotation. An installation-scoped trigger receives events delivered
+    /// for that installation, or for any repository in it, instead of
+    /// matching on `owner`/`repository`.
+    pub installation_id: Option<i64>,
 }
 
 /// Extract a resource locator value from a node parameter.
@@ -122,6 +134,10 @@ pub fn parse_github_trigger(
     // payloads for n8n's signature verification
     let webhook_secret = extract_webhook_secret(workflow, &node.name);
 
+    // Optional action-level filters from the node's notes
+    let actions = parse_action_filters(&node.annotations().get_list("actions"), workflow);
+    let installation_id = parse_installation_id(node.annotations().get("installation"), workflow);
+
     Some(GitHubTriggerConfig {
         webhook_id: webhook_id.clone(),
         workflow_id: workflow.id.clone(),
@@ -131,9 +147,47 @@ pub fn parse_github_trigger(
         owner,
         repository,
         webhook_secret,
+        actions,
+        installation_id,
     })
 }
 
+/// Parse the `unihook.installation` annotation as a GitHub App installation ID.
+fn parse_installation_id(value: Option<&str>, workflow: &Workflow) -> Option<i64> {
+    let value = value?.trim();
+    match value.parse::<i64>() {
+        Ok(id) if id > 0 => Some(id),
+        _ => {
+            warn!(
+                workflow_id = %workflow.id,
+                value = %value,
+                "Ignoring unihook.installation (expected a numeric installation ID)"
+            );
+            None
+        }
+    }
+}
+
+/// Keep well-formed `event.action` filters, dropping malformed entries.
+fn parse_action_filters(values: &[String], workflow: &Workflow) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| match value.split_once('.') {
+            Some((event, action)) if !event.is_empty() && !action.is_empty() => {
+                Some(value.clone())
+            }
+            _ => {
+                warn!(
+                    workflow_id = %workflow.id,
+                    value = %value,
+                    "Ignoring unihook.actions entry (expected event.action, e.g. pull_request.opened)"
+                );
+                None
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +215,7 @@ mod tests {
             name: "GitHub Trigger".to_string(),
             parameters: params,
             webhook_id: webhook_id.map(|s| s.to_string()),
+            notes: None,
         }
     }
 
@@ -302,6 +357,7 @@ mod tests {
             name: "HTTP Request".to_string(),
             parameters: json!({}),
             webhook_id: Some("webhook-123".to_string()),
+            notes: None,
         };
         let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
 
@@ -448,4 +504,65 @@ mod tests {
 
         assert_eq!(config.webhook_secret, None);
     }
+
+    #[test]
+    fn test_parse_github_trigger_action_annotation() {
+        let mut node = create_github_trigger_node(
+            Some("webhook-gh-actions"),
+            json!({
+                "events": ["pull_request", "issues"],
+                "owner": "n8n-io",
+                "repository": "n8n"
+            }),
+        );
+        node.notes = Some(
+            "PR bot\nunihook.actions: pull_request.opened, issues.closed, bogus, .x".to_string(),
+        );
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_github_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.actions, vec!["pull_request.opened", "issues.closed"]);
+    }
+
+    #[test]
+    fn test_parse_github_trigger_without_action_annotation() {
+        let node = create_github_trigger_node(
+            Some("webhook-gh-no-actions"),
+            json!({ "events": ["pull_request"], "owner": "o", "repository": "r" }),
+        );
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_github_trigger(&workflow, &node).unwrap();
+
+        assert!(config.actions.is_empty());
+    }
+
+    #[test]
+    fn test_parse_github_trigger_installation_annotation() {
+        let mut node = create_github_trigger_node(
+            Some("webhook-gh-app"),
+            json!({ "events": ["push"], "owner": "acme", "repository": "api" }),
+        );
+        node.notes = Some("unihook.installation: 42".to_string());
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_github_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.installation_id, Some(42));
+    }
+
+    #[test]
+    fn test_parse_github_trigger_ignores_invalid_installation() {
+        let mut node = create_github_trigger_node(
+            Some("webhook-gh-app"),
+            json!({ "events": ["push"], "owner": "acme", "repository": "api" }),
+        );
+        node.notes = Some("unihook.installation: acme".to_string());
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_github_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.installation_id, None);
+    }
 }
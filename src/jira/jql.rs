@@ -0,0 +1,708 @@
+use serde_json::Value;
+
+/// A parsed JQL filter that can be evaluated locally against a webhook's
+/// `issue` object.
+///
+/// When n8n registers a Jira webhook with a JQL `filter`, Jira applies it
+/// server-side. Through Unihook a single webhook receives every event, so the
+/// filter has to be re-applied here. Only a practical subset is supported:
+///
+/// - Fields: `project`, `issuetype` (`type`), `status`, `labels`, `priority`,
+///   `assignee`, `component`
+/// - Operators: `=`, `!=`, `IN`, `NOT IN`, `IS [NOT] EMPTY`
+/// - Boolean logic: `AND`, `OR`, `NOT`, parentheses
+///
+/// A trailing `ORDER BY` clause is accepted and ignored. Anything else
+/// (functions, other fields, `~`, `WAS`, `CHANGED`, ...) fails to parse with a
+/// [`JqlError`] so the caller can decide whether to fail open or closed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JqlFilter {
+    expr: JqlExpr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum JqlExpr {
+    And(Box<JqlExpr>, Box<JqlExpr>),
+    Or(Box<JqlExpr>, Box<JqlExpr>),
+    Not(Box<JqlExpr>),
+    Clause {
+        field: JqlField,
+        negated: bool,
+        values: Vec<JqlValue>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JqlField {
+    Project,
+    IssueType,
+    Status,
+    Labels,
+    Priority,
+    Assignee,
+    Component,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum JqlValue {
+    Literal(String),
+    Empty,
+}
+
+/// Reasons a JQL string is outside the supported subset.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum JqlError {
+    #[error("empty JQL filter")]
+    Empty,
+
+    #[error("unsupported JQL field: {0}")]
+    UnsupportedField(String),
+
+    #[error("unsupported JQL operator: {0}")]
+    UnsupportedOperator(String),
+
+    #[error("unsupported JQL function: {0}()")]
+    UnsupportedFunction(String),
+
+    #[error("unexpected JQL token: {0}")]
+    UnexpectedToken(String),
+
+    #[error("unexpected end of JQL")]
+    UnexpectedEnd,
+
+    #[error("unterminated string in JQL")]
+    UnterminatedString,
+}
+
+impl JqlFilter {
+    /// Parse a JQL string into a filter.
+    pub fn parse(jql: &str) -> Result<Self, JqlError> {
+        let tokens = tokenize(jql)?;
+        let tokens = strip_order_by(tokens);
+        if tokens.is_empty() {
+            return Err(JqlError::Empty);
+        }
+
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        if let Some(tok) = parser.peek() {
+            return Err(JqlError::UnexpectedToken(tok.to_string()));
+        }
+        Ok(Self { expr })
+    }
+
+    /// Evaluate the filter against a Jira `issue` object (the value of the
+    /// webhook payload's `issue` key).
+    pub fn matches(&self, issue: &Value) -> bool {
+        eval(&self.expr, issue.get("fields").unwrap_or(&Value::Null))
+    }
+}
+
+/// Project values a JQL filter requires, from a `project = X` or
+/// `project IN (X, Y)` clause that every matching issue must satisfy.
+///
+/// Works on the token stream rather than the parsed filter, so the project
+/// can be recovered even when other clauses use unsupported syntax (e.g.
+/// `project = PROJ AND assignee = currentUser()`). Returns an empty list when
+/// there is no such clause, or when `OR` or a unary `NOT` could make the
+/// project clause optional.
+pub fn jql_project_scope(jql: &str) -> Vec<String> {
+    let Ok(tokens) = tokenize(jql) else {
+        return Vec::new();
+    };
+    let tokens = strip_order_by(tokens);
+    let is_word = |tok: Option<&Token>, kw: &str| matches!(tok, Some(Token::Word(w)) if w.eq_ignore_ascii_case(kw));
+
+    for (i, tok) in tokens.iter().enumerate() {
+        if is_word(Some(tok), "or") {
+            return Vec::new();
+        }
+        // `NOT IN` and `IS NOT EMPTY` are operators; any other NOT negates an
+        // expression that might contain the project clause.
+        if is_word(Some(tok), "not")
+            && !["in", "empty", "null"]
+                .iter()
+                .any(|kw| is_word(tokens.get(i + 1), kw))
+        {
+            return Vec::new();
+        }
+    }
+
+    for (i, tok) in tokens.iter().enumerate() {
+        let at_clause_start =
+            i == 0 || tokens[i - 1] == Token::LParen || is_word(tokens.get(i - 1), "and");
+        if !at_clause_start || !is_word(Some(tok), "project") {
+            continue;
+        }
+        match tokens.get(i + 1) {
+            Some(Token::Op(op)) if op == "=" => {
+                if let Some(value) = scope_value(tokens.get(i + 2), tokens.get(i + 3)) {
+                    return vec![value];
+                }
+            }
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("in") => {
+                if tokens.get(i + 2) != Some(&Token::LParen) {
+                    continue;
+                }
+                let mut values = Vec::new();
+                for (j, t) in tokens.iter().enumerate().skip(i + 3) {
+                    match t {
+                        Token::RParen => return values,
+                        Token::Comma => {}
+                        _ => match scope_value(Some(t), tokens.get(j + 1)) {
+                            Some(value) => values.push(value),
+                            None => break,
+                        },
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Vec::new()
+}
+
+/// A literal project value (not a function call or EMPTY) from a token.
+fn scope_value(tok: Option<&Token>, next: Option<&Token>) -> Option<String> {
+    match tok? {
+        Token::Quoted(s) => Some(s.clone()),
+        Token::Word(w)
+            if next != Some(&Token::LParen)
+                && !w.eq_ignore_ascii_case("empty")
+                && !w.eq_ignore_ascii_case("null") =>
+        {
+            Some(w.clone())
+        }
+        _ => None,
+    }
+}
+
+// ── Evaluation ──────────────────────────────────────────────────────────
+
+fn eval(expr: &JqlExpr, fields: &Value) -> bool {
+    match expr {
+        JqlExpr::And(a, b) => eval(a, fields) && eval(b, fields),
+        JqlExpr::Or(a, b) => eval(a, fields) || eval(b, fields),
+        JqlExpr::Not(e) => !eval(e, fields),
+        JqlExpr::Clause {
+            field,
+            negated,
+            values,
+        } => {
+            let actual = field_values(*field, fields);
+            let hit = values.iter().any(|v| match v {
+                JqlValue::Empty => actual.is_empty(),
+                JqlValue::Literal(s) => actual.iter().any(|a| a.eq_ignore_ascii_case(s)),
+            });
+            if !*negated {
+                return hit;
+            }
+            // Like Jira, `!=` / `NOT IN` never match an empty field unless the
+            // value list itself mentions EMPTY.
+            if actual.is_empty() && !values.contains(&JqlValue::Empty) {
+                return false;
+            }
+            !hit
+        }
+    }
+}
+
+/// Every identifier the issue exposes for a field. A clause value matches if
+/// it equals any of them (e.g. a project matches by key, name, or id).
+fn field_values(field: JqlField, fields: &Value) -> Vec<String> {
+    let mut out = Vec::new();
+    match field {
+        JqlField::Project => push_keys(&mut out, &fields["project"], &["key", "name", "id"]),
+        JqlField::IssueType => push_keys(&mut out, &fields["issuetype"], &["name", "id"]),
+        JqlField::Status => push_keys(&mut out, &fields["status"], &["name", "id"]),
+        JqlField::Priority => push_keys(&mut out, &fields["priority"], &["name", "id"]),
+        JqlField::Assignee => push_keys(
+            &mut out,
+            &fields["assignee"],
+            &["accountId", "emailAddress", "name", "key", "displayName"],
+        ),
+        JqlField::Labels => {
+            if let Some(labels) = fields["labels"].as_array() {
+                out.extend(labels.iter().filter_map(|l| l.as_str().map(str::to_string)));
+            }
+        }
+        JqlField::Component => {
+            if let Some(components) = fields["components"].as_array() {
+                for c in components {
+                    push_keys(&mut out, c, &["name", "id"]);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn push_keys(out: &mut Vec<String>, obj: &Value, keys: &[&str]) {
+    for key in keys {
+        match &obj[*key] {
+            Value::String(s) if !s.is_empty() => out.push(s.clone()),
+            Value::Number(n) => out.push(n.to_string()),
+            _ => {}
+        }
+    }
+}
+
+// ── Tokenizer ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Unquoted word (field name, keyword, or bare value)
+    Word(String),
+    /// Quoted string value
+    Quoted(String),
+    LParen,
+    RParen,
+    Comma,
+    /// Any operator-like run of symbols (`=`, `!=`, `~`, `>=`, ...)
+    Op(String),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(w) => write!(f, "{}", w),
+            Token::Quoted(s) => write!(f, "\"{}\"", s),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+            Token::Op(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, JqlError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            }
+            '"' | '\'' => {
+                let quote = c;
+                chars.next();
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('\\') => match chars.next() {
+                            Some(escaped) => s.push(escaped),
+                            None => return Err(JqlError::UnterminatedString),
+                        },
+                        Some(ch) if ch == quote => break,
+                        Some(ch) => s.push(ch),
+                        None => return Err(JqlError::UnterminatedString),
+                    }
+                }
+                tokens.push(Token::Quoted(s));
+            }
+            '=' | '!' | '~' | '<' | '>' => {
+                let mut op = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if matches!(ch, '=' | '!' | '~' | '<' | '>') {
+                        op.push(ch);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Op(op));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_whitespace() || "(),\"'=!~<>".contains(ch) {
+                        break;
+                    }
+                    word.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Drop a trailing `ORDER BY ...` clause; ordering is meaningless for a
+/// single-issue match.
+fn strip_order_by(mut tokens: Vec<Token>) -> Vec<Token> {
+    let pos = tokens.windows(2).position(|w| {
+        matches!(&w[0], Token::Word(a) if a.eq_ignore_ascii_case("order"))
+            && matches!(&w[1], Token::Word(b) if b.eq_ignore_ascii_case("by"))
+    });
+    if let Some(pos) = pos {
+        tokens.truncate(pos);
+    }
+    tokens
+}
+
+// ── Parser ──────────────────────────────────────────────────────────────
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), JqlError> {
+        match self.next() {
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword) => Ok(()),
+            Some(tok) => Err(JqlError::UnexpectedToken(tok.to_string())),
+            None => Err(JqlError::UnexpectedEnd),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<JqlExpr, JqlError> {
+        let mut left = self.parse_and()?;
+        while self.peek_keyword("or") {
+            self.next();
+            let right = self.parse_and()?;
+            left = JqlExpr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<JqlExpr, JqlError> {
+        let mut left = self.parse_unary()?;
+        while self.peek_keyword("and") {
+            self.next();
+            let right = self.parse_unary()?;
+            left = JqlExpr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<JqlExpr, JqlError> {
+        if self.peek_keyword("not") {
+            self.next();
+            return Ok(JqlExpr::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.peek() == Some(&Token::LParen) {
+            self.next();
+            let expr = self.parse_or()?;
+            return match self.next() {
+                Some(Token::RParen) => Ok(expr),
+                Some(tok) => Err(JqlError::UnexpectedToken(tok.to_string())),
+                None => Err(JqlError::UnexpectedEnd),
+            };
+        }
+        self.parse_clause()
+    }
+
+    fn parse_clause(&mut self) -> Result<JqlExpr, JqlError> {
+        let field = match self.next() {
+            Some(Token::Word(w)) | Some(Token::Quoted(w)) => parse_field(&w)?,
+            Some(tok) => return Err(JqlError::UnexpectedToken(tok.to_string())),
+            None => return Err(JqlError::UnexpectedEnd),
+        };
+
+        let (negated, values) = match self.next() {
+            Some(Token::Op(op)) if op == "=" => (false, vec![self.parse_value()?]),
+            Some(Token::Op(op)) if op == "!=" => (true, vec![self.parse_value()?]),
+            Some(Token::Op(op)) => return Err(JqlError::UnsupportedOperator(op)),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("in") => (false, self.parse_list()?),
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("not") => {
+                self.expect_keyword("in")?;
+                (true, self.parse_list()?)
+            }
+            Some(Token::Word(w)) if w.eq_ignore_ascii_case("is") => {
+                let negated = self.peek_keyword("not");
+                if negated {
+                    self.next();
+                }
+                match self.parse_value()? {
+                    JqlValue::Empty => (negated, vec![JqlValue::Empty]),
+                    JqlValue::Literal(v) => return Err(JqlError::UnexpectedToken(v)),
+                }
+            }
+            Some(Token::Word(w)) => return Err(JqlError::UnsupportedOperator(w)),
+            Some(tok) => return Err(JqlError::UnexpectedToken(tok.to_string())),
+            None => return Err(JqlError::UnexpectedEnd),
+        };
+
+        Ok(JqlExpr::Clause {
+            field,
+            negated,
+            values,
+        })
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<JqlValue>, JqlError> {
+        match self.next() {
+            Some(Token::LParen) => {}
+            Some(tok) => return Err(JqlError::UnexpectedToken(tok.to_string())),
+            None => return Err(JqlError::UnexpectedEnd),
+        }
+
+        let mut values = vec![self.parse_value()?];
+        loop {
+            match self.next() {
+                Some(Token::Comma) => values.push(self.parse_value()?),
+                Some(Token::RParen) => return Ok(values),
+                Some(tok) => return Err(JqlError::UnexpectedToken(tok.to_string())),
+                None => return Err(JqlError::UnexpectedEnd),
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JqlValue, JqlError> {
+        match self.next() {
+            Some(Token::Quoted(s)) => Ok(JqlValue::Literal(s)),
+            Some(Token::Word(w)) => {
+                if self.peek() == Some(&Token::LParen) {
+                    return Err(JqlError::UnsupportedFunction(w));
+                }
+                if w.eq_ignore_ascii_case("empty") || w.eq_ignore_ascii_case("null") {
+                    Ok(JqlValue::Empty)
+                } else {
+                    Ok(JqlValue::Literal(w))
+                }
+            }
+            Some(tok) => Err(JqlError::UnexpectedToken(tok.to_string())),
+            None => Err(JqlError::UnexpectedEnd),
+        }
+    }
+}
+
+fn parse_field(name: &str) -> Result<JqlField, JqlError> {
+    match name.to_ascii_lowercase().as_str() {
+        "project" => Ok(JqlField::Project),
+        "issuetype" | "type" => Ok(JqlField::IssueType),
+        "status" => Ok(JqlField::Status),
+        "labels" | "label" => Ok(JqlField::Labels),
+        "priority" => Ok(JqlField::Priority),
+        "assignee" => Ok(JqlField::Assignee),
+        "component" | "components" => Ok(JqlField::Component),
+        _ => Err(JqlError::UnsupportedField(name.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue() -> Value {
+        json!({
+            "id": "10001",
+            "key": "PROJ-1",
+            "fields": {
+                "project": { "id": "10000", "key": "PROJ", "name": "Project" },
+                "issuetype": { "id": "3", "name": "Task" },
+                "status": { "id": "1", "name": "In Progress" },
+                "priority": { "id": "2", "name": "High" },
+                "labels": ["backend", "urgent"],
+                "assignee": {
+                    "accountId": "abc123",
+                    "emailAddress": "dev@example.com",
+                    "displayName": "Dev"
+                },
+                "components": [{ "id": "200", "name": "API" }]
+            }
+        })
+    }
+
+    fn eval_jql(jql: &str) -> bool {
+        JqlFilter::parse(jql).unwrap().matches(&issue())
+    }
+
+    #[test]
+    fn test_equals_matches_key_name_or_id() {
+        assert!(eval_jql("project = PROJ"));
+        assert!(eval_jql("project = \"Project\""));
+        assert!(eval_jql("project = 10000"));
+        assert!(!eval_jql("project = OTHER"));
+    }
+
+    #[test]
+    fn test_comparison_is_case_insensitive() {
+        assert!(eval_jql("PROJECT = proj"));
+        assert!(eval_jql("status = 'in progress'"));
+    }
+
+    #[test]
+    fn test_not_equals() {
+        assert!(eval_jql("issuetype != Bug"));
+        assert!(!eval_jql("issuetype != Task"));
+    }
+
+    #[test]
+    fn test_in_and_not_in() {
+        assert!(eval_jql("priority IN (Highest, High)"));
+        assert!(!eval_jql("priority in (Low, Lowest)"));
+        assert!(eval_jql("status NOT IN (Done, \"Won't Do\")"));
+        assert!(!eval_jql("status not in (\"In Progress\")"));
+    }
+
+    #[test]
+    fn test_multi_valued_fields() {
+        assert!(eval_jql("labels = urgent"));
+        assert!(eval_jql("labels in (frontend, backend)"));
+        assert!(!eval_jql("labels = frontend"));
+        assert!(eval_jql("component = API"));
+        assert!(eval_jql("components = 200"));
+    }
+
+    #[test]
+    fn test_assignee_identifiers() {
+        assert!(eval_jql("assignee = abc123"));
+        assert!(eval_jql("assignee = \"dev@example.com\""));
+        assert!(!eval_jql("assignee = someone-else"));
+    }
+
+    #[test]
+    fn test_and_or_precedence() {
+        // AND binds tighter than OR
+        assert!(eval_jql(
+            "project = OTHER AND status = Done OR labels = urgent"
+        ));
+        assert!(!eval_jql(
+            "project = OTHER AND (status = Done OR labels = urgent)"
+        ));
+        assert!(eval_jql("project = PROJ and issuetype = Task"));
+    }
+
+    #[test]
+    fn test_not_prefix() {
+        assert!(eval_jql("NOT project = OTHER"));
+        assert!(!eval_jql("not (project = PROJ)"));
+    }
+
+    #[test]
+    fn test_empty_values() {
+        let unassigned = json!({ "fields": { "assignee": null, "labels": [] } });
+        let f = |jql: &str| JqlFilter::parse(jql).unwrap().matches(&unassigned);
+
+        assert!(f("assignee IS EMPTY"));
+        assert!(f("assignee = null"));
+        assert!(f("labels is empty"));
+        assert!(!f("assignee is not empty"));
+        assert!(eval_jql("assignee IS NOT EMPTY"));
+    }
+
+    #[test]
+    fn test_negation_does_not_match_empty_field() {
+        let unassigned = json!({ "fields": { "assignee": null } });
+        let filter = JqlFilter::parse("assignee != abc123").unwrap();
+        assert!(!filter.matches(&unassigned));
+
+        let filter = JqlFilter::parse("assignee not in (abc123, EMPTY)").unwrap();
+        assert!(!filter.matches(&unassigned));
+    }
+
+    #[test]
+    fn test_order_by_is_ignored() {
+        assert!(eval_jql("project = PROJ ORDER BY created DESC"));
+    }
+
+    #[test]
+    fn test_missing_fields_object() {
+        let filter = JqlFilter::parse("project = PROJ").unwrap();
+        assert!(!filter.matches(&json!({ "key": "PROJ-1" })));
+    }
+
+    #[test]
+    fn test_unsupported_field() {
+        assert_eq!(
+            JqlFilter::parse("reporter = abc"),
+            Err(JqlError::UnsupportedField("reporter".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_operator() {
+        assert_eq!(
+            JqlFilter::parse("status ~ Done"),
+            Err(JqlError::UnsupportedOperator("~".to_string()))
+        );
+        assert_eq!(
+            JqlFilter::parse("status WAS Done"),
+            Err(JqlError::UnsupportedOperator("WAS".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unsupported_function() {
+        assert_eq!(
+            JqlFilter::parse("assignee = currentUser()"),
+            Err(JqlError::UnsupportedFunction("currentUser".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_malformed_jql() {
+        assert_eq!(JqlFilter::parse(""), Err(JqlError::Empty));
+        assert_eq!(JqlFilter::parse("project ="), Err(JqlError::UnexpectedEnd));
+        assert_eq!(
+            JqlFilter::parse("project = 'PROJ"),
+            Err(JqlError::UnterminatedString)
+        );
+        assert!(JqlFilter::parse("project = PROJ AND").is_err());
+        assert!(JqlFilter::parse("(project = PROJ").is_err());
+        assert!(JqlFilter::parse("project = PROJ status = Done").is_err());
+    }
+
+    #[test]
+    fn test_project_scope_equals_and_in() {
+        assert_eq!(jql_project_scope("project = PROJ"), vec!["PROJ"]);
+        assert_eq!(
+            jql_project_scope("issuetype = Bug AND project IN (PROJ, \"Ops Team\")"),
+            vec!["PROJ", "Ops Team"]
+        );
+    }
+
+    #[test]
+    fn test_project_scope_survives_unsupported_clauses() {
+        assert_eq!(
+            jql_project_scope("project = PROJ AND assignee = currentUser() ORDER BY created"),
+            vec!["PROJ"]
+        );
+        assert_eq!(
+            jql_project_scope("(project = PROJ AND status IS NOT EMPTY)"),
+            vec!["PROJ"]
+        );
+    }
+
+    #[test]
+    fn test_project_scope_none_when_project_is_optional() {
+        assert!(jql_project_scope("project = PROJ OR labels = urgent").is_empty());
+        assert!(jql_project_scope("NOT project = PROJ").is_empty());
+        assert!(jql_project_scope("project != PROJ").is_empty());
+        assert!(jql_project_scope("project NOT IN (PROJ)").is_empty());
+        assert!(jql_project_scope("labels = project").is_empty());
+        assert!(jql_project_scope("status = Done").is_empty());
+    }
+}
@@ -1,5 +1,9 @@
+pub mod jql;
 pub mod models;
+pub mod sites;
 pub mod triggers;
 
+pub use jql::*;
 pub use models::*;
+pub use sites::*;
 pub use triggers::*;
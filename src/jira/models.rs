@@ -19,6 +19,35 @@ pub struct JiraWebhookPayload {
     pub extra: serde_json::Value,
 }
 
+impl JiraWebhookPayload {
+    /// The `issue` object for issue, comment, and worklog events.
+    pub fn issue(&self) -> Option<&serde_json::Value> {
+        self.extra.get("issue").filter(|v| v.is_object())
+    }
+
+    /// The project an event belongs to: the issue's project for issue events,
+    /// or the top-level `project` for project events.
+    pub fn project(&self) -> Option<&serde_json::Value> {
+        self.issue()
+            .and_then(|issue| issue.pointer("/fields/project"))
+            .or_else(|| self.extra.get("project"))
+            .filter(|v| v.is_object())
+    }
+
+    /// `self` URLs of the top-level objects in the payload (`issue`,
+    /// `comment`, `user`, `sprint`, ...), which point back at the Jira site.
+    pub fn self_urls(&self) -> Vec<&str> {
+        self.extra
+            .as_object()
+            .map(|obj| {
+                obj.values()
+                    .filter_map(|v| v.get("self").and_then(|s| s.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +174,15 @@ mod tests {
         assert_eq!(payload.extra["timestamp"], 1234567890);
         assert_eq!(payload.extra["custom_field"], "value");
     }
+
+    #[test]
+    fn test_issue_accessor() {
+        let json = r#"{"webhookEvent": "jira:issue_created", "issue": {"key": "PROJ-1"}}"#;
+        let payload: JiraWebhookPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.issue().unwrap()["key"], "PROJ-1");
+
+        let json = r#"{"webhookEvent": "board_created", "board": {"id": 1}}"#;
+        let payload: JiraWebhookPayload = serde_json::from_str(json).unwrap();
+        assert!(payload.issue().is_none());
+    }
 }
@@ -0,0 +1,89 @@
+use super::JiraWebhookPayload;
+
+/// Normalize a Jira site name from a URL path segment.
+///
+/// Site names are case-insensitive; `None` or a blank name means the default
+/// site (the un-prefixed `/jira/events` and `/rest/...` paths), stored as `""`.
+pub fn normalize_site(site: Option<&str>) -> String {
+    site.map(|s| s.trim().to_lowercase()).unwrap_or_default()
+}
+
+/// Detect which configured site sent a payload from the `self` URLs Jira
+/// embeds in it (e.g. `issue.self` = `https://acme.atlassian.net/rest/api/2/issue/10001`).
+///
+/// `sites` holds `(name, base_url)` pairs from `JIRA_SITE_URLS`. Returns the
+/// first site whose base URL prefixes a `self` URL in the payload.
+pub fn detect_site<'a>(
+    payload: &JiraWebhookPayload,
+    sites: &'a [(String, String)],
+) -> Option<&'a str> {
+    let self_urls = payload.self_urls();
+    sites
+        .iter()
+        .find(|(_, base)| {
+            let base = base.trim_end_matches('/');
+            self_urls.iter().any(|url| {
+                url.strip_prefix(base)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+        })
+        .map(|(name, _)| name.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sites() -> Vec<(String, String)> {
+        vec![
+            ("acme".to_string(), "https://acme.atlassian.net".to_string()),
+            (
+                "dc".to_string(),
+                "https://jira.corp.example.com/".to_string(),
+            ),
+        ]
+    }
+
+    fn payload(json: serde_json::Value) -> JiraWebhookPayload {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_site() {
+        assert_eq!(normalize_site(None), "");
+        assert_eq!(normalize_site(Some(" Acme ")), "acme");
+    }
+
+    #[test]
+    fn test_detect_site_from_issue_self() {
+        let p = payload(serde_json::json!({
+            "webhookEvent": "jira:issue_created",
+            "issue": { "self": "https://acme.atlassian.net/rest/api/2/issue/10001" }
+        }));
+        assert_eq!(detect_site(&p, &sites()), Some("acme"));
+    }
+
+    #[test]
+    fn test_detect_site_from_other_objects() {
+        let p = payload(serde_json::json!({
+            "webhookEvent": "sprint_started",
+            "sprint": { "self": "https://jira.corp.example.com/rest/agile/1.0/sprint/1" }
+        }));
+        assert_eq!(detect_site(&p, &sites()), Some("dc"));
+    }
+
+    #[test]
+    fn test_detect_site_requires_host_boundary() {
+        let p = payload(serde_json::json!({
+            "webhookEvent": "jira:issue_created",
+            "issue": { "self": "https://acme.atlassian.net.evil.example/rest/api/2/issue/1" }
+        }));
+        assert_eq!(detect_site(&p, &sites()), None);
+    }
+
+    #[test]
+    fn test_detect_site_no_self_urls() {
+        let p = payload(serde_json::json!({ "webhookEvent": "jira:issue_created" }));
+        assert_eq!(detect_site(&p, &sites()), None);
+    }
+}
@@ -1,4 +1,7 @@
 use crate::n8n::{Workflow, WorkflowNode};
+use tracing::warn;
+
+use super::{JqlFilter, jql_project_scope};
 
 /// Extracted configuration from a Jira Trigger node
 #[derive(Debug, Clone)]
@@ -35,6 +38,24 @@ pub struct JiraTriggerConfig {
     ///   jira:version_released, jira:version_unreleased, jira:version_updated,
     ///   worklog_created, worklog_deleted, worklog_updated
     pub events: Vec<String>,
+
+    /// JQL from the node's "Filter" additional field. Jira would apply it
+    /// server-side; Unihook evaluates it locally against issue events.
+    pub jql_filter: Option<String>,
+
+    /// "Exclude Body" additional field: deliver the event with an empty body.
+    pub exclude_body: bool,
+
+    /// "Include Fields" additional field: Jira URL variables (e.g. `issue.key`,
+    /// `project.id`) appended to the webhook URL as `field=<value>` query
+    /// parameters, which n8n merges into the trigger output. They never drop
+    /// the body on their own; only `exclude_body` does.
+    pub include_fields: Vec<String>,
+
+    /// Projects (key, name, or id) the trigger is scoped to: the
+    /// `unihook.projects` annotation, or else the JQL filter's `project`
+    /// clause. Empty means every project.
+    pub projects: Vec<String>,
 }
 
 /// Parse Jira Trigger configuration from a workflow node
@@ -61,12 +82,62 @@ pub fn parse_jira_trigger(workflow: &Workflow, node: &WorkflowNode) -> Option<Ji
     // Require a webhook ID — it's the correlation key for the database
     let webhook_id = node.webhook_id.as_ref()?;
 
+    let additional_fields = params.get("additionalFields");
+
+    // Extract the JQL filter from "additionalFields.filter"
+    let jql_filter = additional_fields
+        .and_then(|f| f.get("filter"))
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string);
+
+    if let Some(ref jql) = jql_filter
+        && let Err(e) = JqlFilter::parse(jql)
+    {
+        warn!(
+            workflow_id = %workflow.id,
+            workflow_name = %workflow.name,
+            jql = %jql,
+            error = %e,
+            "Jira trigger JQL filter is not supported by Unihook; JIRA_JQL_FAIL_OPEN decides routing"
+        );
+    }
+
+    // Extract "additionalFields.excludeBody" and "additionalFields.includeFields"
+    let exclude_body = additional_fields
+        .and_then(|f| f.get("excludeBody"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let include_fields: Vec<String> = additional_fields
+        .and_then(|f| f.get("includeFields"))
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Project scope: an explicit `unihook.projects` annotation wins over JQL
+    let annotations = node.annotations();
+    let mut projects = annotations.get_list("projects");
+    if projects.is_empty()
+        && let Some(ref jql) = jql_filter
+    {
+        projects = jql_project_scope(jql);
+    }
+
     Some(JiraTriggerConfig {
         webhook_id: webhook_id.clone(),
         workflow_id: workflow.id.clone(),
         workflow_name: workflow.name.clone(),
         workflow_active: workflow.active,
         events,
+        jql_filter,
+        exclude_body,
+        include_fields,
+        projects,
     })
 }
 
@@ -97,6 +168,7 @@ mod tests {
             name: "Jira Trigger".to_string(),
             parameters: params,
             webhook_id: webhook_id.map(|s| s.to_string()),
+            notes: None,
         }
     }
 
@@ -178,6 +250,130 @@ mod tests {
         assert!(config.events.is_empty());
     }
 
+    #[test]
+    fn test_parse_jira_trigger_jql_filter() {
+        let node = create_jira_trigger_node(
+            Some("webhook-j6"),
+            json!({
+                "events": ["jira:issue_created"],
+                "additionalFields": {
+                    "filter": "  project = PROJ AND issuetype = Bug  "
+                }
+            }),
+        );
+        let workflow = create_workflow("wf6", "Filtered Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(
+            config.jql_filter.as_deref(),
+            Some("project = PROJ AND issuetype = Bug")
+        );
+    }
+
+    #[test]
+    fn test_parse_jira_trigger_blank_jql_filter_is_none() {
+        let node = create_jira_trigger_node(
+            Some("webhook-j7"),
+            json!({
+                "events": ["jira:issue_created"],
+                "additionalFields": { "filter": "  " }
+            }),
+        );
+        let workflow = create_workflow("wf7", "Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        assert!(config.jql_filter.is_none());
+    }
+
+    #[test]
+    fn test_parse_jira_trigger_keeps_unsupported_jql() {
+        let node = create_jira_trigger_node(
+            Some("webhook-j8"),
+            json!({
+                "events": ["jira:issue_created"],
+                "additionalFields": { "filter": "assignee = currentUser()" }
+            }),
+        );
+        let workflow = create_workflow("wf8", "Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        // Stored as-is; the router applies JIRA_JQL_FAIL_OPEN at routing time
+        assert_eq!(
+            config.jql_filter.as_deref(),
+            Some("assignee = currentUser()")
+        );
+    }
+
+    #[test]
+    fn test_parse_jira_trigger_body_options() {
+        let node = create_jira_trigger_node(
+            Some("webhook-j9"),
+            json!({
+                "events": ["jira:issue_updated"],
+                "additionalFields": {
+                    "excludeBody": true,
+                    "includeFields": ["issue.key", "project.id"]
+                }
+            }),
+        );
+        let workflow = create_workflow("wf9", "Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        assert!(config.exclude_body);
+        assert_eq!(config.include_fields, vec!["issue.key", "project.id"]);
+    }
+
+    #[test]
+    fn test_parse_jira_trigger_body_options_default() {
+        let node = create_jira_trigger_node(
+            Some("webhook-j10"),
+            json!({ "events": ["jira:issue_updated"] }),
+        );
+        let workflow = create_workflow("wf10", "Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        assert!(!config.exclude_body);
+        assert!(config.include_fields.is_empty());
+    }
+
+    #[test]
+    fn test_parse_jira_trigger_project_scope_from_jql() {
+        let node = create_jira_trigger_node(
+            Some("webhook-j11"),
+            json!({
+                "events": ["jira:issue_updated"],
+                "additionalFields": { "filter": "project IN (PROJ, OPS) AND status = Done" }
+            }),
+        );
+        let workflow = create_workflow("wf11", "Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.projects, vec!["PROJ", "OPS"]);
+    }
+
+    #[test]
+    fn test_parse_jira_trigger_project_annotation_wins() {
+        let mut node = create_jira_trigger_node(
+            Some("webhook-j12"),
+            json!({
+                "events": ["jira:issue_updated"],
+                "additionalFields": { "filter": "project = PROJ" }
+            }),
+        );
+        node.notes = Some("unihook.projects: SUP, ops".to_string());
+        let workflow = create_workflow("wf12", "Workflow", vec![node.clone()]);
+
+        let config = parse_jira_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.projects, vec!["SUP", "ops"]);
+    }
+
     #[test]
     fn test_parse_non_jira_node_returns_none() {
         let node = WorkflowNode {
@@ -185,6 +381,7 @@ mod tests {
             name: "HTTP Request".to_string(),
             parameters: json!({}),
             webhook_id: Some("webhook-123".to_string()),
+            notes: None,
         };
         let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
 
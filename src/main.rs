@@ -6,22 +6,35 @@ mod jira;
 mod n8n;
 mod router;
 mod routes;
+mod secret_gc;
 mod slack;
 mod zoom;
 
-use axum::{Router as AxumRouter, routing::get, routing::post};
+use axum::{
+    Router as AxumRouter,
+    middleware::{from_fn_with_state, map_response},
+    routing::get,
+    routing::post,
+};
+use std::future::IntoFuture;
 use std::sync::Arc;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use crate::config::Config;
 use crate::db::Database;
+use crate::github::GHES_API_PREFIX;
 use crate::n8n::N8nClient;
 use crate::router::{GitHubRouter, JiraRouter, SlackRouter, ZoomRouter};
 use crate::routes::{
     AppState, handle_github_event, handle_jira_event, handle_slack_event, handle_zoom_event,
     health_check, provider_github, provider_jira,
 };
+use crate::zoom::ZoomReplayGuard;
+
+/// Subcommand that re-encrypts all stored webhook secrets under the current
+/// `WEBHOOK_SECRET_KEY` and exits.
+const ROTATE_SECRET_KEY_COMMAND: &str = "rotate-secret-key";
 
 #[tokio::main]
 async fn main() {
@@ -34,6 +47,18 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    let command = std::env::args().nth(1);
+    if let Some(command) = command.as_deref()
+        && command != ROTATE_SECRET_KEY_COMMAND
+    {
+        eprintln!("Error: Unknown command: {command}");
+        eprintln!("\nUsage: n8n-unihook [{ROTATE_SECRET_KEY_COMMAND}]");
+        eprintln!(
+            "  {ROTATE_SECRET_KEY_COMMAND}  Re-encrypt stored webhook secrets with WEBHOOK_SECRET_KEY and exit"
+        );
+        std::process::exit(2);
+    }
+
     // Load configuration
     let config = match Config::from_env() {
         Ok(c) => Arc::new(c),
@@ -47,17 +72,53 @@ async fn main() {
                 "  N8N_API_URL              - n8n instance URL (default: http://localhost:5678)"
             );
             eprintln!("  LISTEN_ADDR              - Address to bind (default: 0.0.0.0:3000)");
+            eprintln!(
+                "  INTERNAL_LISTEN_ADDR     - Separate address for the provider API mocks and /health"
+            );
             eprintln!("  REFRESH_INTERVAL_SECS    - Trigger refresh interval (default: 60)");
             eprintln!("  N8N_ENDPOINT_WEBHOOK     - Production webhook path (default: webhook)");
             eprintln!("  N8N_ENDPOINT_WEBHOOK_TEST - Test webhook path (default: webhook-test)");
             eprintln!(
-                "  GITHUB_WEBHOOK_SECRET    - Shared secret for GitHub inbound HMAC verification"
+                "  GITHUB_WEBHOOK_SECRET    - Comma-separated secrets for GitHub inbound HMAC verification"
+            );
+            eprintln!(
+                "  GITHUB_WEBHOOK_SCOPED_SECRETS - Comma-separated owner=secret or owner/repo=secret pairs"
+            );
+            eprintln!(
+                "  JIRA_WEBHOOK_SECRET      - Comma-separated secrets for Jira inbound HMAC verification"
+            );
+            eprintln!(
+                "  JIRA_SITE_SECRETS        - Comma-separated site=secret pairs for per-site Jira verification"
+            );
+            eprintln!(
+                "  JIRA_SITE_URLS           - Comma-separated name=base_url pairs for Jira site detection"
+            );
+            eprintln!(
+                "  GITHUB_MOCK_TOKEN        - Comma-separated access tokens the GitHub API mock accepts"
+            );
+            eprintln!(
+                "  GITHUB_ENTERPRISE_HOST   - GHES hostname for hooks registered through /api/v3"
+            );
+            eprintln!(
+                "  JIRA_MOCK_USER           - Basic-auth user the Jira API mock expects (with JIRA_MOCK_TOKEN)"
+            );
+            eprintln!(
+                "  JIRA_MOCK_TOKEN          - API token, password or PAT the Jira API mock accepts"
+            );
+            eprintln!(
+                "  ZOOM_WEBHOOK_SECRET      - Comma-separated Zoom app Secret Tokens for signature verification"
             );
             eprintln!(
-                "  ZOOM_WEBHOOK_SECRET      - Zoom app Secret Token for signature verification"
+                "  ZOOM_ACCOUNT_SECRETS     - Optional `account_id=secret` pairs for Zoom accounts with their own Secret Token"
             );
             eprintln!(
-                "  ZOOM_ALLOWED_EVENTS      - Comma-separated Zoom events Unihook may forward"
+                "  ZOOM_REPLAY_WINDOW_SECS  - Max Zoom request timestamp age, 0 disables replay checks (default: 300)"
+            );
+            eprintln!(
+                "  ZOOM_ALLOWED_EVENTS      - Comma-separated Zoom event patterns Unihook may forward (`*` globs, `!` denies)"
+            );
+            eprintln!(
+                "  ZOOM_EVENT_TIERS         - Optional `pattern=tier` pairs; tier is `host` or `privileged` (default: host)"
             );
             eprintln!(
                 "  ZOOM_PRIVILEGED_USERS    - Optional comma-separated emails bypassing Zoom host routing"
@@ -65,6 +126,39 @@ async fn main() {
             eprintln!(
                 "  ZOOM_PRIVILEGED_WORKFLOW_IDS - Optional comma-separated workflow IDs bypassing Zoom host routing"
             );
+            eprintln!(
+                "  ZOOM_ROUTING_MODES       - Optional `pattern=mode` pairs: participant, alternative_host or host_id"
+            );
+            eprintln!(
+                "  ZOOM_USER_EMAILS         - Optional `user_id=email` pairs resolving Zoom host IDs"
+            );
+            eprintln!(
+                "  ZOOM_REDACT_FIELDS       - Optional `pattern=/json/pointer` pairs redacted for non-privileged Zoom triggers"
+            );
+            eprintln!(
+                "  ZOOM_REDACTION_MODE      - How ZOOM_REDACT_FIELDS are redacted: mask or remove (default: mask)"
+            );
+            eprintln!(
+                "  JIRA_JQL_FAIL_OPEN       - Route Jira events when a trigger's JQL is unsupported (default: true)"
+            );
+            eprintln!(
+                "  N8N_TRIGGER_RECONCILE    - Reconcile GitHub/Jira triggers with the n8n API (default: true)"
+            );
+            eprintln!(
+                "  WEBHOOK_SECRET_GC_INTERVAL_SECS - Orphaned webhook secret cleanup interval, 0 disables (default: 3600)"
+            );
+            eprintln!(
+                "  WEBHOOK_SECRET_GC_GRACE_SECS - How long orphaned secrets are quarantined (default: 86400)"
+            );
+            eprintln!(
+                "  WEBHOOK_SECRET_KEY       - Hex-encoded 256-bit key encrypting stored webhook secrets"
+            );
+            eprintln!(
+                "  WEBHOOK_SECRET_KEY_FILE  - File containing WEBHOOK_SECRET_KEY (e.g. a mounted secret)"
+            );
+            eprintln!(
+                "  WEBHOOK_SECRET_PREVIOUS_KEYS - Comma-separated retired keys, still accepted for decryption"
+            );
             eprintln!("  DATABASE_PATH            - Path to SQLite database (default: unihook.db)");
             std::process::exit(1);
         }
@@ -78,9 +172,23 @@ async fn main() {
         "Starting Unihook router"
     );
 
+    // Load the key encrypting stored webhook secrets
+    let secret_cipher = match config.secret_cipher() {
+        Ok(cipher) => cipher,
+        Err(e) => {
+            error!(error = %e, "Failed to load webhook secret encryption key");
+            eprintln!("Error: Failed to load webhook secret encryption key: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let encryption_enabled = secret_cipher.is_some();
+    if let Some(cipher) = &secret_cipher {
+        info!(key_id = %cipher.key_id(), "Webhook secret encryption enabled");
+    }
+
     // Open the SQLite database
     let db = match Database::open(&config.database_path) {
-        Ok(db) => Arc::new(db),
+        Ok(db) => Arc::new(db.with_secret_cipher(secret_cipher)),
         Err(e) => {
             error!(error = %e, path = %config.database_path, "Failed to open database");
             eprintln!(
@@ -91,6 +199,48 @@ async fn main() {
         }
     };
 
+    // Encrypt secrets stored before encryption was enabled, or re-encrypt
+    // everything under the current key when rotating
+    let rotate = command.is_some();
+    if rotate && !encryption_enabled {
+        eprintln!("Error: {ROTATE_SECRET_KEY_COMMAND} requires WEBHOOK_SECRET_KEY to be set");
+        std::process::exit(1);
+    }
+    match db.encrypt_webhook_secrets(rotate) {
+        Ok(report) => {
+            if report.encrypted > 0 || report.rotated > 0 {
+                info!(
+                    encrypted = report.encrypted,
+                    rotated = report.rotated,
+                    "Encrypted stored webhook secrets"
+                );
+            }
+            if !report.unreadable.is_empty() {
+                warn!(
+                    webhook_ids = ?report.unreadable,
+                    "Stored webhook secrets could not be decrypted; set WEBHOOK_SECRET_KEY \
+                     or add the key they were encrypted with to WEBHOOK_SECRET_PREVIOUS_KEYS"
+                );
+            }
+            if rotate {
+                println!(
+                    "Encrypted {} and re-encrypted {} webhook secrets, {} unreadable",
+                    report.encrypted,
+                    report.rotated,
+                    report.unreadable.len()
+                );
+                std::process::exit(if report.unreadable.is_empty() { 0 } else { 1 });
+            }
+        }
+        Err(e) => {
+            error!(error = %e, "Failed to encrypt stored webhook secrets");
+            if rotate {
+                eprintln!("Error: Failed to re-encrypt webhook secrets: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Create shared n8n API client
     let n8n_client = Arc::new(N8nClient::new(config.clone()));
 
@@ -123,9 +273,20 @@ async fn main() {
 
     // Start background tasks that refresh trigger configurations
     slack_router.clone().start_refresh_task();
-    jira_router.clone().start_refresh_task();
-    github_router.clone().start_refresh_task();
     zoom_router.clone().start_refresh_task();
+    // GitHub and Jira triggers are created from provider-mock registrations;
+    // polling n8n only reconciles them
+    if config.n8n_trigger_reconcile {
+        jira_router.clone().start_refresh_task();
+        github_router.clone().start_refresh_task();
+    } else {
+        info!(
+            "N8N_TRIGGER_RECONCILE=false: GitHub and Jira triggers come from provider-mock registrations only"
+        );
+    }
+
+    // Garbage-collect webhook secrets left behind by deleted workflows
+    secret_gc::start_webhook_secret_gc_task(db.clone(), config.clone());
 
     // Create application state
     let app_state = Arc::new(AppState {
@@ -135,40 +296,63 @@ async fn main() {
         zoom_router,
         config: config.clone(),
         db: db.clone(),
+        zoom_replay_guard: ZoomReplayGuard::new(config.zoom_replay_window_secs),
     });
 
-    // Build the HTTP router
-    let app = AxumRouter::new()
-        // ── Inbound event routes (from external providers to n8n) ────────
+    // Public routes: inbound events from external providers to n8n
+    let public_routes = AxumRouter::new()
         .route("/slack/events", post(handle_slack_event))
         .route("/jira/events", post(handle_jira_event))
+        .route("/jira/{site}/events", post(handle_jira_event))
         .route("/github/events", post(handle_github_event))
         .route("/zoom/events", post(handle_zoom_event))
-        // ── Provider API mock routes (intercepting n8n → provider calls) ─
-        // GitHub API mock
-        .route(
-            "/repos/{owner}/{repo}/hooks",
-            get(provider_github::list_hooks).post(provider_github::create_hook),
-        )
-        .route(
-            "/repos/{owner}/{repo}/hooks/{hook_id}",
-            axum::routing::delete(provider_github::delete_hook),
+        .route("/zoom/{account_id}/events", post(handle_zoom_event))
+        .with_state(app_state.clone());
+
+    // Internal routes: provider API mocks (intercepting n8n → provider
+    // calls) and the health check
+    let internal_routes = AxumRouter::new()
+        // GitHub API mock (github.com at the root, GitHub Enterprise Server
+        // under /api/v3)
+        .merge(github_mock_routes(""))
+        .merge(
+            github_mock_routes(GHES_API_PREFIX)
+                .route(
+                    &format!("{GHES_API_PREFIX}/meta"),
+                    get(provider_github::get_enterprise_meta),
+                )
+                .layer(map_response(provider_github::add_enterprise_headers)),
         )
-        .route("/user", get(provider_github::get_user))
+        .layer(from_fn_with_state(
+            app_state.clone(),
+            provider_github::authenticate,
+        ))
         // Jira API mock
-        .route(
-            "/rest/webhooks/1.0/webhook",
-            get(provider_jira::list_webhooks).post(provider_jira::create_webhook),
-        )
-        .route(
-            "/rest/webhooks/1.0/webhook/{id}",
-            axum::routing::delete(provider_jira::delete_webhook),
-        )
-        .route("/rest/api/2/myself", get(provider_jira::get_myself))
-        // ── Health check ─────────────────────────────────────────────────
+        .merge(jira_mock_routes().layer(from_fn_with_state(
+            app_state.clone(),
+            provider_jira::authenticate,
+        )))
         .route("/health", get(health_check))
         .with_state(app_state);
 
+    // With INTERNAL_LISTEN_ADDR, LISTEN_ADDR is the public listener and only
+    // serves inbound events; otherwise it serves everything
+    let (app, internal) = match &config.internal_listen_addr {
+        Some(addr) => {
+            let listener = tokio::net::TcpListener::bind(addr)
+                .await
+                .expect("Failed to bind internal address");
+            info!(address = %addr, "Internal listener (provider API mocks, health) listening");
+            (public_routes, Some((listener, internal_routes)))
+        }
+        None => (public_routes.merge(internal_routes), None),
+    };
+    let internal_host = if internal.is_some() {
+        "<internal-host>"
+    } else {
+        "<your-host>"
+    };
+
     // Start the server
     let listener = tokio::net::TcpListener::bind(&config.listen_addr)
         .await
@@ -179,10 +363,89 @@ async fn main() {
     info!("Jira webhook URL: http://<your-host>/jira/events");
     info!("GitHub webhook URL: http://<your-host>/github/events");
     info!("Zoom webhook URL: http://<your-host>/zoom/events");
-    info!("Provider API mock: http://<your-host>/repos/:owner/:repo/hooks (GitHub)");
-    info!("Provider API mock: http://<your-host>/rest/webhooks/1.0/webhook (Jira)");
+    info!("Provider API mock: http://{internal_host}/repos/:owner/:repo/hooks (GitHub)");
+    info!(
+        "Provider API mock: http://{internal_host}/api/v3/repos/:owner/:repo/hooks (GitHub Enterprise Server)"
+    );
+    info!("Provider API mock: http://{internal_host}/rest/webhooks/1.0/webhook (Jira)");
+    info!("Health check: http://{internal_host}/health");
+    if config.github_mock_token.is_empty() || config.jira_mock_token.is_none() {
+        warn!(
+            "Provider API mocks accept unauthenticated callers; set GITHUB_MOCK_TOKEN and \
+             JIRA_MOCK_TOKEN to the credentials configured in n8n"
+        );
+    }
 
-    axum::serve(listener, app)
-        .await
-        .expect("Server failed to start");
+    let public = axum::serve(listener, app).into_future();
+    match internal {
+        Some((listener, internal_routes)) => {
+            tokio::try_join!(public, axum::serve(listener, internal_routes).into_future())
+                .expect("Server failed to start");
+        }
+        None => public.await.expect("Server failed to start"),
+    }
+}
+
+/// The GitHub API mock routes, served under `prefix` (`""` for github.com,
+/// `/api/v3` for GitHub Enterprise Server).
+fn github_mock_routes(prefix: &str) -> AxumRouter<Arc<AppState>> {
+    AxumRouter::new()
+        .route(
+            &format!("{prefix}/repos/{{owner}}/{{repo}}/hooks"),
+            get(provider_github::list_hooks).post(provider_github::create_hook),
+        )
+        .route(
+            &format!("{prefix}/repos/{{owner}}/{{repo}}/hooks/{{hook_id}}"),
+            get(provider_github::get_hook)
+                .patch(provider_github::update_hook)
+                .delete(provider_github::delete_hook),
+        )
+        .route(
+            &format!("{prefix}/repos/{{owner}}/{{repo}}/hooks/{{hook_id}}/pings"),
+            post(provider_github::ping_hook),
+        )
+        .route(
+            &format!("{prefix}/repos/{{owner}}/{{repo}}/hooks/{{hook_id}}/tests"),
+            post(provider_github::test_hook),
+        )
+        .route(&format!("{prefix}/user"), get(provider_github::get_user))
+}
+
+/// The Jira API mock routes, at the root and under the per-site
+/// `/jira/{site}` prefix (credential domain `http://unihook/jira/{site}`).
+fn jira_mock_routes() -> AxumRouter<Arc<AppState>> {
+    let mut routes = AxumRouter::new();
+    for prefix in ["", "/jira/{site}"] {
+        routes = routes
+            .route(
+                &format!("{prefix}/rest/webhooks/1.0/webhook"),
+                get(provider_jira::list_webhooks).post(provider_jira::create_webhook),
+            )
+            .route(
+                &format!("{prefix}/rest/webhooks/1.0/webhook/{{id}}"),
+                get(provider_jira::get_webhook).delete(provider_jira::delete_webhook),
+            )
+            // Jira Server/Data Center webhook API (same registrations as above)
+            .route(
+                &format!("{prefix}/rest/jira-webhook/1.0/webhooks"),
+                get(provider_jira::list_webhooks).post(provider_jira::create_webhook),
+            )
+            .route(
+                &format!("{prefix}/rest/jira-webhook/1.0/webhooks/{{id}}"),
+                get(provider_jira::get_webhook).delete(provider_jira::delete_webhook),
+            )
+            .route(
+                &format!("{prefix}/rest/api/2/myself"),
+                get(provider_jira::get_myself),
+            )
+            .route(
+                &format!("{prefix}/rest/api/3/myself"),
+                get(provider_jira::get_myself),
+            )
+            .route(
+                &format!("{prefix}/rest/api/2/serverInfo"),
+                get(provider_jira::get_server_info),
+            );
+    }
+    routes
 }
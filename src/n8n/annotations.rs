@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+/// Prefix that marks a line in a node's notes as a Unihook annotation.
+pub const ANNOTATION_PREFIX: &str = "unihook.";
+
+/// Unihook routing annotations parsed from an n8n node's notes.
+///
+/// n8n lets authors attach free-form notes to any node. Unihook reads lines of
+/// the form `unihook.<key>: <value>` (or `unihook.<key> = <value>`) from the
+/// trigger node's notes so routing options that n8n's trigger UI doesn't
+/// expose can live alongside the workflow. All other lines are ignored.
+///
+/// Example notes on a Slack Trigger node:
+/// ```text
+/// Replies to the support bot only.
+/// unihook.channel_types: im, mpim
+/// unihook.thread: replies
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NodeAnnotations {
+    values: HashMap<String, String>,
+}
+
+impl NodeAnnotations {
+    /// Parse annotations from a node's notes. Keys are case-insensitive; when
+    /// a key appears more than once the last value wins.
+    pub fn parse(notes: Option<&str>) -> Self {
+        let mut values = HashMap::new();
+
+        for line in notes.unwrap_or_default().lines() {
+            let line = line.trim();
+            let Some(rest) = line.strip_prefix(ANNOTATION_PREFIX) else {
+                continue;
+            };
+            let Some(idx) = rest.find([':', '=']) else {
+                continue;
+            };
+            let key = rest[..idx].trim().to_lowercase();
+            let value = rest[idx + 1..].trim().to_string();
+            if !key.is_empty() {
+                values.insert(key, value);
+            }
+        }
+
+        Self { values }
+    }
+
+    /// Raw value for `key`, if the annotation is present and non-empty.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .get(key)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// Comma-separated value for `key` split into trimmed, non-empty items.
+    pub fn get_list(&self, key: &str) -> Vec<String> {
+        self.get(key)
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_colon_and_equals_forms() {
+        let notes = "unihook.thread: replies\nunihook.channel_types = im, mpim";
+        let annotations = NodeAnnotations::parse(Some(notes));
+
+        assert_eq!(annotations.get("thread"), Some("replies"));
+        assert_eq!(annotations.get_list("channel_types"), vec!["im", "mpim"]);
+    }
+
+    #[test]
+    fn test_parse_ignores_non_annotation_lines() {
+        let notes = "Handles support DMs.\n  unihook.thread: top_level  \nthread: replies";
+        let annotations = NodeAnnotations::parse(Some(notes));
+
+        assert_eq!(annotations.get("thread"), Some("top_level"));
+    }
+
+    #[test]
+    fn test_parse_keys_are_case_insensitive() {
+        let annotations = NodeAnnotations::parse(Some("unihook.Channel_Types: im"));
+
+        assert_eq!(annotations.get_list("channel_types"), vec!["im"]);
+    }
+
+    #[test]
+    fn test_parse_empty_value_is_absent() {
+        let annotations = NodeAnnotations::parse(Some("unihook.thread:"));
+
+        assert!(annotations.get("thread").is_none());
+        assert!(annotations.get_list("thread").is_empty());
+    }
+
+    #[test]
+    fn test_parse_no_notes() {
+        let annotations = NodeAnnotations::parse(None);
+
+        assert!(annotations.get("thread").is_none());
+    }
+}
@@ -87,6 +87,8 @@ impl N8nClient {
                             workflow_name = %trigger.workflow_name,
                             workflow_active = trigger.workflow_active,
                             events = ?trigger.events,
+                            jql_filter = ?trigger.jql_filter,
+                            projects = ?trigger.projects,
                             "Found Jira trigger"
                         );
                         triggers.push(trigger);
@@ -377,6 +379,7 @@ impl N8nClient {
                             events = ?trigger.events,
                             owner = %trigger.owner,
                             repository = %trigger.repository,
+                            actions = ?trigger.actions,
                             has_webhook_secret = trigger.webhook_secret.is_some(),
                             "Found GitHub trigger"
                         );
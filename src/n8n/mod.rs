@@ -1,3 +1,4 @@
+pub mod annotations;
 pub mod client;
 pub mod models;
 
@@ -1,5 +1,7 @@
 use serde::Deserialize;
 
+use super::annotations::NodeAnnotations;
+
 /// Response from n8n GET /api/v1/workflows endpoint
 #[derive(Debug, Deserialize)]
 pub struct WorkflowsResponse {
@@ -132,4 +134,15 @@ pub struct WorkflowNode {
     /// Webhook ID (for trigger nodes)
     #[serde(rename = "webhookId")]
     pub webhook_id: Option<String>,
+
+    /// Free-form node notes; may carry `unihook.*` routing annotations
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl WorkflowNode {
+    /// Unihook routing annotations parsed from this node's notes.
+    pub fn annotations(&self) -> NodeAnnotations {
+        NodeAnnotations::parse(self.notes.as_deref())
+    }
 }
@@ -1,6 +1,10 @@
 use crate::config::Config;
-use crate::crypto::compute_hmac_sha256;
-use crate::db::{Database, GitHubTriggerRow};
+use crate::crypto::{compute_hmac_sha1, compute_hmac_sha256};
+use crate::db::{Database, GitHubHookRow, GitHubTriggerRow};
+use crate::github::{
+    GHES_HOST_HEADER, GHES_VERSION, GHES_VERSION_HEADER, GitHubRepositoryRef, GitHubWebhookPayload,
+    enterprise_host,
+};
 use crate::n8n::N8nClient;
 use axum::http::{HeaderMap, HeaderName, HeaderValue};
 use std::collections::HashSet;
@@ -11,6 +15,38 @@ use tracing::{debug, error, info, warn};
 
 use super::forward_to_webhook;
 
+/// Where a GitHub event came from, as read from its payload.
+#[derive(Debug, Default)]
+pub struct GitHubEventSource {
+    /// Repository owner, or the organization / App installation account for
+    /// repository-less org-level events
+    pub owner: Option<String>,
+    /// Repository name
+    pub repository: Option<String>,
+    /// GitHub App installation the event was delivered for
+    pub installation_id: Option<u64>,
+}
+
+impl GitHubEventSource {
+    /// Read the routing fields from a parsed payload.
+    pub fn from_payload(payload: &GitHubWebhookPayload) -> Self {
+        Self {
+            owner: payload.owner_login().map(str::to_string),
+            repository: payload.repository.as_ref().map(|repo| repo.name.clone()),
+            installation_id: payload.installation_id(),
+        }
+    }
+
+    /// `owner/name` of the event's repository, if it has one.
+    fn full_name(&self) -> Option<String> {
+        Some(format!(
+            "{}/{}",
+            self.owner.as_ref()?,
+            self.repository.as_ref()?
+        ))
+    }
+}
+
 /// The GitHub routing engine that manages trigger configurations and forwards events.
 ///
 /// Trigger metadata and webhook secrets are stored in SQLite. The periodic
@@ -112,32 +148,149 @@ impl GitHubRouter {
         )
     }
 
+    /// Update the local GitHub App installation map from an event.
+    ///
+    /// `installation` events record (`created`) or forget (`deleted`) an
+    /// installation and its repositories, and `installation_repositories`
+    /// events add and remove repositories. Any other event delivered for an
+    /// installation records its repository, so the map fills in even when
+    /// Unihook missed the lifecycle events. `github_app_authorization` is
+    /// user-level and leaves the map unchanged.
+    pub fn apply_installation_event(&self, event_type: &str, payload: &GitHubWebhookPayload) {
+        let Some(installation_id) = payload.installation_id() else {
+            if event_type == "github_app_authorization" {
+                info!(
+                    action = ?payload.action,
+                    sender = ?payload.sender.as_ref().map(|s| s.login.as_str()),
+                    "GitHub App authorization changed"
+                );
+            }
+            return;
+        };
+        let account = payload
+            .installation
+            .as_ref()
+            .and_then(|i| i.account.as_ref())
+            .map(|a| a.login.as_str())
+            .unwrap_or_default();
+        let action = payload.action.as_deref();
+        let full_names = |repos: &Option<Vec<GitHubRepositoryRef>>| -> Vec<String> {
+            repos
+                .iter()
+                .flatten()
+                .map(|r| r.full_name.clone())
+                .collect()
+        };
+
+        let result = match (event_type, action) {
+            ("installation", Some("created")) => {
+                let repositories = full_names(&payload.repositories);
+                info!(
+                    installation_id,
+                    account = %account,
+                    repository_count = repositories.len(),
+                    "GitHub App installed"
+                );
+                self.db
+                    .replace_github_installation(installation_id, account, &repositories)
+            }
+            ("installation", Some("deleted")) => {
+                info!(installation_id, account = %account, "GitHub App uninstalled");
+                self.db
+                    .delete_github_installation(installation_id)
+                    .map(|_| ())
+            }
+            ("installation_repositories", _) => {
+                let added = full_names(&payload.repositories_added);
+                let removed = full_names(&payload.repositories_removed);
+                info!(
+                    installation_id,
+                    added = ?added,
+                    removed = ?removed,
+                    "GitHub App installation repositories changed"
+                );
+                self.db
+                    .add_github_installation_repositories(installation_id, account, &added)
+                    .and_then(|()| {
+                        self.db
+                            .remove_github_installation_repositories(installation_id, &removed)
+                    })
+            }
+            ("installation", _) => Ok(()),
+            _ => match &payload.repository {
+                Some(repo) => self.db.add_github_installation_repositories(
+                    installation_id,
+                    account,
+                    std::slice::from_ref(&repo.full_name),
+                ),
+                None => Ok(()),
+            },
+        };
+        if let Err(e) = result {
+            warn!(
+                error = %e,
+                installation_id,
+                event_type = %event_type,
+                "Failed to update GitHub App installation map"
+            );
+        }
+    }
+
+    /// Query the triggers for an event's source: repository and owner
+    /// triggers, plus installation-scoped triggers.
+    fn query_triggers(
+        &self,
+        source: &GitHubEventSource,
+    ) -> Result<Vec<GitHubTriggerRow>, rusqlite::Error> {
+        let mut rows = self
+            .db
+            .query_github_triggers(source.owner.as_deref(), source.repository.as_deref())?;
+        rows.extend(self.db.query_github_installation_triggers(
+            source.installation_id,
+            source.full_name().as_deref(),
+        )?);
+        Ok(rows)
+    }
+
     /// Route a GitHub event to all matching triggers.
     ///
+    /// Triggers are matched on event type and, when the trigger has
+    /// `unihook.actions` filters for that event, on the payload's `action`.
+    /// Deliveries from GitHub Enterprise Server (`X-GitHub-Enterprise-Host`)
+    /// only match triggers registered through the GHES API, and github.com
+    /// deliveries only those registered through github.com's.
+    /// Installation-scoped triggers match events delivered for their
+    /// installation or for any repository in it.
+    ///
     /// Reads matching triggers from the database (which JOINs webhook_secrets
     /// so the HMAC secret is included). For each matching trigger, re-signs
     /// the payload and forwards to both production and test webhook URLs.
     ///
     /// If any forward returns a 401 or if a trigger's webhook secret was
     /// missing, the router will immediately refresh its trigger cache from
-    /// the n8n API and retry those specific deliveries.
+    /// the n8n API and retry those specific deliveries (unless
+    /// `N8N_TRIGGER_RECONCILE` is off).
     pub async fn route_event(
         &self,
         event_type: &str,
-        owner: Option<&str>,
-        repository: Option<&str>,
+        action: Option<&str>,
+        source: &GitHubEventSource,
         raw_body: String,
         headers: HeaderMap,
     ) {
+        let owner = source.owner.as_deref();
+        let repository = source.repository.as_deref();
         debug!(
             event_type = %event_type,
+            action = ?action,
             owner = ?owner,
             repository = ?repository,
+            installation_id = ?source.installation_id,
             "Routing GitHub event"
         );
 
         // Get matching triggers from the database
-        let all_rows = match self.db.query_github_triggers(owner, repository) {
+        let all_rows = match self.query_triggers(source) {
             Ok(rows) => rows,
             Err(e) => {
                 error!(error = %e, "Failed to query GitHub triggers from database");
@@ -145,10 +298,13 @@ impl GitHubRouter {
             }
         };
 
-        // Filter by event type (the DB doesn't filter events for us)
+        // Filter by GitHub instance, event type and action (the DB doesn't
+        // filter events for us)
+        let enterprise = enterprise_host(&headers);
         let matching_triggers: Vec<&GitHubTriggerRow> = all_rows
             .iter()
-            .filter(|t| t.events.iter().any(|e| e == "*" || e == event_type))
+            .filter(|t| instance_matches(t, enterprise))
+            .filter(|t| trigger_matches_event(t, event_type, action))
             .collect();
 
         if matching_triggers.is_empty() {
@@ -187,6 +343,15 @@ impl GitHubRouter {
             return;
         }
 
+        // Without n8n reconciliation there is nothing fresher to retry with
+        if !self.config.n8n_trigger_reconcile {
+            warn!(
+                failed_count = retry_urls.len(),
+                "Got 401 or missing webhook secret; not retrying (N8N_TRIGGER_RECONCILE=false)"
+            );
+            return;
+        }
+
         info!(
             retry_count = retry_urls.len(),
             "Got 401 or missing webhook secret; refreshing triggers from n8n API and retrying"
@@ -199,7 +364,7 @@ impl GitHubRouter {
         }
 
         // Re-query the database for matching triggers (now with fresh data)
-        let fresh_rows = match self.db.query_github_triggers(owner, repository) {
+        let fresh_rows = match self.query_triggers(source) {
             Ok(rows) => rows,
             Err(e) => {
                 error!(error = %e, "Failed to re-query GitHub triggers after refresh");
@@ -209,7 +374,8 @@ impl GitHubRouter {
 
         let fresh_matching: Vec<&GitHubTriggerRow> = fresh_rows
             .iter()
-            .filter(|t| t.events.iter().any(|e| e == "*" || e == event_type))
+            .filter(|t| instance_matches(t, enterprise))
+            .filter(|t| trigger_matches_event(t, event_type, action))
             .collect();
 
         // Phase 3: Retry only the specific webhook URLs that failed.
@@ -332,28 +498,110 @@ impl GitHubRouter {
         results
     }
 
-    /// Build forwarded headers with a re-computed `X-Hub-Signature-256`.
+    /// Deliver a mock-generated event (`ping`, or the `push` sent by a hook
+    /// test) to the n8n webhook behind a registered hook, signed with the
+    /// hook's secret the way GitHub would.
+    ///
+    /// Hooks registered through n8n's test URL are delivered to the test
+    /// webhook, all others to the production webhook.
+    pub async fn deliver_hook_event(&self, hook: &GitHubHookRow, event_type: &str, body: String) {
+        let (url, webhook_type) = if self.config.is_test_webhook_url(&hook.config_url) {
+            (self.build_test_webhook_url(&hook.webhook_id), "test")
+        } else {
+            (self.build_webhook_url(&hook.webhook_id), "production")
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("content-type"),
+            HeaderValue::from_static("application/json"),
+        );
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let delivery = format!("unihook-{}-{}", hook.id, nanos);
+        for (name, value) in [
+            ("x-github-event", event_type.to_string()),
+            ("x-github-hook-id", hook.id.to_string()),
+            ("x-github-delivery", delivery),
+        ] {
+            if let Ok(value) = HeaderValue::from_str(&value) {
+                headers.insert(HeaderName::from_static(name), value);
+            }
+        }
+        if hook.enterprise {
+            headers.insert(
+                HeaderName::from_static(GHES_VERSION_HEADER),
+                HeaderValue::from_static(GHES_VERSION),
+            );
+            if let Ok(host) = HeaderValue::from_str(&hook.enterprise_host)
+                && !hook.enterprise_host.is_empty()
+            {
+                headers.insert(HeaderName::from_static(GHES_HOST_HEADER), host);
+            }
+        }
+        let secret = hook.secret.as_deref().filter(|s| !s.is_empty());
+        let headers = Self::build_signed_headers(&headers, &body, secret);
+
+        info!(
+            hook_id = hook.id,
+            webhook_id = %hook.webhook_id,
+            event_type = %event_type,
+            "Delivering GitHub mock hook event"
+        );
+        forward_to_webhook(
+            &self.n8n_client,
+            &url,
+            &hook.name,
+            webhook_type,
+            &body,
+            &headers,
+        )
+        .await;
+    }
+
+    /// Build forwarded headers with re-computed `X-Hub-Signature-256` and
+    /// legacy SHA-1 `X-Hub-Signature` headers.
+    ///
+    /// The inbound signatures were computed with the secret of the webhook
+    /// pointing at Unihook, not n8n's, so they are always removed. Without a
+    /// secret to re-sign with, the payload is forwarded unsigned rather than
+    /// with a stale signature.
     fn build_signed_headers(
         original_headers: &HeaderMap,
         body: &str,
         webhook_secret: Option<&str>,
     ) -> HeaderMap {
         let mut headers = original_headers.clone();
+        headers.remove("x-hub-signature-256");
+        headers.remove("x-hub-signature");
 
         if let Some(secret) = webhook_secret {
-            let signature = compute_hmac_sha256(secret, body.as_bytes());
-
-            headers.insert(
-                HeaderName::from_static("x-hub-signature-256"),
-                HeaderValue::from_str(&signature).expect("signature is valid ASCII"),
-            );
+            for (name, signature) in [
+                (
+                    "x-hub-signature-256",
+                    compute_hmac_sha256(secret, body.as_bytes()),
+                ),
+                (
+                    "x-hub-signature",
+                    compute_hmac_sha1(secret, body.as_bytes()),
+                ),
+            ] {
+                headers.insert(
+                    HeaderName::from_static(name),
+                    HeaderValue::from_str(&signature).expect("signature is valid ASCII"),
+                );
+            }
 
             debug!(
                 has_secret = true,
                 "Re-signed GitHub webhook payload with n8n's webhook secret"
             );
         } else {
-            warn!("No webhook secret available for GitHub trigger; forwarding without re-signing");
+            warn!(
+                "No webhook secret available for GitHub trigger; forwarding without signature headers"
+            );
         }
 
         headers
@@ -373,6 +621,51 @@ struct ForwardResult {
     status: Option<u16>,
 }
 
+/// Returns true if a trigger belongs to the GitHub instance a delivery came
+/// from: github.com triggers for deliveries without `X-GitHub-Enterprise-Host`,
+/// and GHES triggers whose registered host equals that header
+/// (case-insensitively).
+fn instance_matches(t: &GitHubTriggerRow, enterprise_host: Option<&str>) -> bool {
+    match enterprise_host {
+        Some(host) => t.enterprise && t.enterprise_host.eq_ignore_ascii_case(host),
+        None => !t.enterprise,
+    }
+}
+
+/// Returns true if a trigger wants an event of this type and action.
+///
+/// The event type must be in the trigger's event list (or `*`). If the
+/// trigger also has action filters for that event type (e.g.
+/// `pull_request.opened`), the payload's `action` must be one of them; event
+/// types without action filters receive every action.
+fn trigger_matches_event(t: &GitHubTriggerRow, event_type: &str, action: Option<&str>) -> bool {
+    if !t.events.iter().any(|e| e == "*" || e == event_type) {
+        return false;
+    }
+
+    let allowed: Vec<&str> = t
+        .actions
+        .iter()
+        .filter_map(|a| a.split_once('.'))
+        .filter(|(event, _)| *event == event_type)
+        .map(|(_, action)| action)
+        .collect();
+    if allowed.is_empty() {
+        return true;
+    }
+
+    let matched = action.is_some_and(|a| allowed.iter().any(|f| f.eq_ignore_ascii_case(a)));
+    if !matched {
+        debug!(
+            workflow_name = %t.workflow_name,
+            event_type = %event_type,
+            action = ?action,
+            "Skipping GitHub trigger: action not in its action filters"
+        );
+    }
+    matched
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,6 +694,8 @@ mod tests {
             owner: "test-owner".to_string(),
             repository: "test-repo".to_string(),
             webhook_secret: None,
+            actions: vec![],
+            installation_id: None,
         }];
         db.sync_github_triggers(&triggers).unwrap();
 
@@ -409,6 +704,71 @@ mod tests {
         }
     }
 
+    fn repo_source() -> GitHubEventSource {
+        GitHubEventSource {
+            owner: Some("test-owner".to_string()),
+            repository: Some("test-repo".to_string()),
+            installation_id: None,
+        }
+    }
+
+    fn action_row(events: &[&str], actions: &[&str]) -> GitHubTriggerRow {
+        GitHubTriggerRow {
+            webhook_id: "wh1".to_string(),
+            workflow_name: "Test".to_string(),
+            workflow_active: true,
+            events: events.iter().map(|s| s.to_string()).collect(),
+            secret: None,
+            actions: actions.iter().map(|s| s.to_string()).collect(),
+            enterprise: false,
+            enterprise_host: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_trigger_without_action_filters_gets_every_action() {
+        let row = action_row(&["pull_request"], &[]);
+        assert!(trigger_matches_event(&row, "pull_request", Some("opened")));
+        assert!(trigger_matches_event(&row, "pull_request", Some("labeled")));
+        assert!(!trigger_matches_event(&row, "issues", Some("opened")));
+    }
+
+    #[test]
+    fn test_action_filters_restrict_their_event_type() {
+        let row = action_row(
+            &["pull_request", "issues", "push"],
+            &[
+                "pull_request.opened",
+                "pull_request.reopened",
+                "issues.closed",
+            ],
+        );
+        assert!(trigger_matches_event(&row, "pull_request", Some("opened")));
+        assert!(trigger_matches_event(
+            &row,
+            "pull_request",
+            Some("Reopened")
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            "pull_request",
+            Some("synchronize")
+        ));
+        assert!(trigger_matches_event(&row, "issues", Some("closed")));
+        assert!(!trigger_matches_event(&row, "issues", Some("opened")));
+        // No filters for push, so it is unaffected
+        assert!(trigger_matches_event(&row, "push", None));
+    }
+
+    #[test]
+    fn test_action_filters_with_wildcard_events() {
+        let row = action_row(&["*"], &["pull_request.closed"]);
+        assert!(trigger_matches_event(&row, "pull_request", Some("closed")));
+        assert!(!trigger_matches_event(&row, "pull_request", Some("opened")));
+        assert!(!trigger_matches_event(&row, "pull_request", None));
+        assert!(trigger_matches_event(&row, "star", Some("created")));
+    }
+
     /// Build a mock n8n API response for `GET /api/v1/workflows` that returns
     /// a single GitHub Trigger workflow with the given webhook secret.
     fn workflows_api_response(secret: &str) -> serde_json::Value {
@@ -469,8 +829,291 @@ mod tests {
         router
             .route_event(
                 "push",
-                Some("test-owner"),
-                Some("test-repo"),
+                None,
+                &repo_source(),
+                r#"{"ref":"refs/heads/main"}"#.to_string(),
+                HeaderMap::new(),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_enterprise_deliveries_route_to_enterprise_triggers_only() {
+        let mock_server = MockServer::start().await;
+        let config = test_config(&mock_server.uri());
+        let n8n_client = Arc::new(N8nClient::new(config.clone()));
+        let db = Arc::new(Database::open(":memory:").unwrap());
+        let router = GitHubRouter::new(config, n8n_client, db.clone());
+
+        // Same owner/repo on github.com (wh1) and on GHES (wh-ghe)
+        seed_trigger(&db, Some("good-secret"));
+        db.upsert_github_hook(&crate::db::GitHubHookRegistration {
+            owner: "test-owner".to_string(),
+            repository: "test-repo".to_string(),
+            webhook_id: "wh-ghe".to_string(),
+            name: "web".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            config_url: "http://n8n/webhook/wh-ghe/webhook".to_string(),
+            content_type: "json".to_string(),
+            secret: Some("ghe-secret".to_string()),
+            production: true,
+            enterprise: true,
+            enterprise_host: "ghe.example.com".to_string(),
+        })
+        .unwrap();
+        // Same owner/repo on a second GHES instance
+        db.upsert_github_hook(&crate::db::GitHubHookRegistration {
+            owner: "test-owner".to_string(),
+            repository: "test-repo".to_string(),
+            webhook_id: "wh-ghe2".to_string(),
+            name: "web".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            config_url: "http://n8n/webhook/wh-ghe2/webhook".to_string(),
+            content_type: "json".to_string(),
+            secret: Some("ghe2-secret".to_string()),
+            production: true,
+            enterprise: true,
+            enterprise_host: "ghe2.example.com".to_string(),
+        })
+        .unwrap();
+
+        Mock::given(method("POST"))
+            .and(path_regex("/wh-ghe/webhook"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex("/wh-ghe2/webhook"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path_regex("/wh1/webhook"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-github-enterprise-host",
+            "GHE.example.com".parse().unwrap(),
+        );
+        router
+            .route_event(
+                "push",
+                None,
+                &repo_source(),
+                r#"{"ref":"refs/heads/main"}"#.to_string(),
+                headers,
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_registered_trigger_stops_routing_once_n8n_drops_workflow() {
+        let mock_server = MockServer::start().await;
+        let config = test_config(&mock_server.uri());
+        let n8n_client = Arc::new(N8nClient::new(config.clone()));
+        let db = Arc::new(Database::open(":memory:").unwrap());
+        let router = GitHubRouter::new(config, n8n_client, db.clone());
+
+        // Registered through the provider mock, then the workflow is deleted
+        db.upsert_github_hook(&crate::db::GitHubHookRegistration {
+            owner: "test-owner".to_string(),
+            repository: "test-repo".to_string(),
+            webhook_id: "wh1".to_string(),
+            name: "web".to_string(),
+            events: vec!["push".to_string()],
+            active: true,
+            config_url: "http://n8n/webhook/wh1/webhook".to_string(),
+            content_type: "json".to_string(),
+            secret: Some("good-secret".to_string()),
+            production: true,
+            enterprise: false,
+            enterprise_host: String::new(),
+        })
+        .unwrap();
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workflows"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": [], "nextCursor": null})),
+            )
+            .mount(&mock_server)
+            .await;
+        router.refresh_triggers().await.unwrap();
+
+        Mock::given(method("POST"))
+            .and(path_regex("/wh1/webhook"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+        router
+            .route_event(
+                "push",
+                None,
+                &repo_source(),
+                r#"{"ref":"refs/heads/main"}"#.to_string(),
+                HeaderMap::new(),
+            )
+            .await;
+        assert_eq!(router.trigger_count(), 0);
+    }
+
+    fn inbound_signed_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-github-event", "push".parse().unwrap());
+        headers.insert("x-hub-signature-256", "sha256=inbound".parse().unwrap());
+        headers.insert("x-hub-signature", "sha1=inbound".parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_build_signed_headers_re_signs_sha256_and_sha1() {
+        let body = r#"{"ref":"refs/heads/main"}"#;
+        let headers =
+            GitHubRouter::build_signed_headers(&inbound_signed_headers(), body, Some("n8n-secret"));
+
+        assert_eq!(
+            headers.get("x-hub-signature-256").unwrap(),
+            compute_hmac_sha256("n8n-secret", body.as_bytes()).as_str()
+        );
+        assert_eq!(
+            headers.get("x-hub-signature").unwrap(),
+            compute_hmac_sha1("n8n-secret", body.as_bytes()).as_str()
+        );
+        assert_eq!(headers.get("x-github-event").unwrap(), "push");
+    }
+
+    #[test]
+    fn test_build_signed_headers_strips_signatures_without_secret() {
+        let headers = GitHubRouter::build_signed_headers(&inbound_signed_headers(), "{}", None);
+
+        assert!(headers.get("x-hub-signature-256").is_none());
+        assert!(headers.get("x-hub-signature").is_none());
+        assert_eq!(headers.get("x-github-event").unwrap(), "push");
+    }
+
+    fn payload(json: &str) -> GitHubWebhookPayload {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_installation_lifecycle_events_maintain_map() {
+        let config = test_config("http://localhost");
+        let n8n_client = Arc::new(N8nClient::new(config.clone()));
+        let db = Arc::new(Database::open(":memory:").unwrap());
+        let router = GitHubRouter::new(config, n8n_client, db.clone());
+
+        router.apply_installation_event(
+            "installation",
+            &payload(
+                r#"{"action": "created", "installation": {"id": 42, "account": {"login": "acme"}},
+                    "repositories": [{"full_name": "acme/api"}, {"full_name": "acme/web"}]}"#,
+            ),
+        );
+        router.apply_installation_event(
+            "installation_repositories",
+            &payload(
+                r#"{"action": "added", "installation": {"id": 42, "account": {"login": "acme"}},
+                    "repositories_added": [{"full_name": "acme/docs"}],
+                    "repositories_removed": [{"full_name": "acme/web"}]}"#,
+            ),
+        );
+        // Events for a repository the map missed still record it
+        router.apply_installation_event(
+            "push",
+            &payload(
+                r#"{"installation": {"id": 42},
+                    "repository": {"name": "cli", "full_name": "acme/cli", "owner": {"login": "acme"}}}"#,
+            ),
+        );
+        assert_eq!(
+            db.list_github_installation_repositories(42).unwrap(),
+            vec!["acme/api", "acme/cli", "acme/docs"]
+        );
+
+        router.apply_installation_event(
+            "github_app_authorization",
+            &payload(r#"{"action": "revoked", "sender": {"login": "octocat"}}"#),
+        );
+        assert_eq!(
+            db.list_github_installation_repositories(42).unwrap().len(),
+            3
+        );
+
+        router.apply_installation_event(
+            "installation",
+            &payload(r#"{"action": "deleted", "installation": {"id": 42, "account": {"login": "acme"}}}"#),
+        );
+        assert!(
+            db.list_github_installation_repositories(42)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_installation_scoped_trigger_receives_installation_events() {
+        let mock_server = MockServer::start().await;
+        let config = test_config(&mock_server.uri());
+        let n8n_client = Arc::new(N8nClient::new(config.clone()));
+        let db = Arc::new(Database::open(":memory:").unwrap());
+        let router = GitHubRouter::new(config, n8n_client, db.clone());
+
+        // Scoped to installation 42; its owner/repository are not matched
+        db.sync_github_triggers(&[GitHubTriggerConfig {
+            webhook_id: "wh-app".to_string(),
+            workflow_id: "wf-app".to_string(),
+            workflow_name: "App Workflow".to_string(),
+            workflow_active: true,
+            events: vec!["push".to_string()],
+            owner: "test-owner".to_string(),
+            repository: "test-repo".to_string(),
+            webhook_secret: None,
+            actions: vec![],
+            installation_id: Some(42),
+        }])
+        .unwrap();
+        db.upsert_webhook_secret("wh-app", "github", "app-secret")
+            .unwrap();
+
+        Mock::given(method("POST"))
+            .and(path_regex("/wh-app/webhook"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        router
+            .route_event(
+                "push",
+                None,
+                &GitHubEventSource {
+                    owner: Some("acme".to_string()),
+                    repository: Some("api".to_string()),
+                    installation_id: Some(42),
+                },
+                r#"{"ref":"refs/heads/main"}"#.to_string(),
+                HeaderMap::new(),
+            )
+            .await;
+        // Another installation's event does not match
+        router
+            .route_event(
+                "push",
+                None,
+                &GitHubEventSource {
+                    owner: Some("other".to_string()),
+                    repository: Some("api".to_string()),
+                    installation_id: Some(7),
+                },
                 r#"{"ref":"refs/heads/main"}"#.to_string(),
                 HeaderMap::new(),
             )
@@ -510,8 +1153,8 @@ mod tests {
         router
             .route_event(
                 "push",
-                Some("test-owner"),
-                Some("test-repo"),
+                None,
+                &repo_source(),
                 r#"{"ref":"refs/heads/main"}"#.to_string(),
                 HeaderMap::new(),
             )
@@ -552,8 +1195,8 @@ mod tests {
         router
             .route_event(
                 "push",
-                Some("test-owner"),
-                Some("test-repo"),
+                None,
+                &repo_source(),
                 r#"{"ref":"refs/heads/main"}"#.to_string(),
                 HeaderMap::new(),
             )
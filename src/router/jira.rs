@@ -1,7 +1,11 @@
 use crate::config::Config;
-use crate::db::Database;
+use crate::db::{Database, JiraTriggerRow};
+use crate::jira::{JiraWebhookPayload, JqlFilter};
 use crate::n8n::N8nClient;
 use axum::http::HeaderMap;
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::interval;
@@ -22,6 +26,47 @@ pub struct JiraRouter {
 
     /// Configuration
     config: Arc<Config>,
+
+    /// Trigger JQL filters parsed since the last refresh
+    jql_cache: JqlCache,
+}
+
+/// Parsed trigger JQL filters, keyed by JQL text.
+///
+/// Each filter is parsed the first time an issue event needs it and reused
+/// until the next trigger refresh clears the cache, so unsupported JQL is
+/// logged once per refresh rather than on every event.
+#[derive(Default)]
+struct JqlCache {
+    /// `None` records JQL outside the supported subset
+    filters: Mutex<HashMap<String, Option<Arc<JqlFilter>>>>,
+}
+
+impl JqlCache {
+    fn get(&self, jql: &str, workflow_name: &str) -> Option<Arc<JqlFilter>> {
+        let mut filters = self.filters.lock();
+        if let Some(filter) = filters.get(jql) {
+            return filter.clone();
+        }
+        let filter = match JqlFilter::parse(jql) {
+            Ok(filter) => Some(Arc::new(filter)),
+            Err(e) => {
+                warn!(
+                    workflow_name = %workflow_name,
+                    jql = %jql,
+                    error = %e,
+                    "Cannot evaluate Jira trigger JQL filter; applying JIRA_JQL_FAIL_OPEN"
+                );
+                None
+            }
+        };
+        filters.insert(jql.to_string(), filter.clone());
+        filter
+    }
+
+    fn clear(&self) {
+        self.filters.lock().clear();
+    }
 }
 
 impl JiraRouter {
@@ -31,6 +76,7 @@ impl JiraRouter {
             db,
             n8n_client,
             config,
+            jql_cache: JqlCache::default(),
         }
     }
 
@@ -69,6 +115,7 @@ impl JiraRouter {
         if let Err(e) = self.db.sync_jira_triggers(&new_triggers) {
             warn!(error = %e, "Failed to sync Jira triggers to database");
         }
+        self.jql_cache.clear();
 
         Ok(())
     }
@@ -93,17 +140,22 @@ impl JiraRouter {
 
     /// Route a Jira event to all matching triggers.
     ///
-    /// Reads triggers from the database, filters by event type, reconstructs
-    /// webhook URLs, and forwards.
+    /// Reads triggers from the database, filters by site, event type, project
+    /// scope, and JQL filter, reconstructs webhook URLs, and forwards. `site` is `""` for the
+    /// default site.
     pub async fn route_event(
         &self,
-        webhook_event: &str,
+        payload: &JiraWebhookPayload,
+        site: &str,
         raw_body: String,
         headers: HeaderMap,
         query_string: Option<String>,
     ) {
+        let webhook_event = payload.webhook_event.as_str();
+
         debug!(
             webhook_event = %webhook_event,
+            site = %site,
             "Routing Jira event"
         );
 
@@ -116,15 +168,22 @@ impl JiraRouter {
             }
         };
 
-        // Filter by event type
+        // Filter by site, event type, and project scope, then by the trigger's
+        // JQL filter (issue events only)
         let matching_triggers: Vec<_> = all_rows
             .iter()
+            .filter(|t| t.site == site)
             .filter(|t| t.events.iter().any(|e| e == "*" || e == webhook_event))
+            .filter(|t| project_scope_matches(t, payload))
+            .filter(|t| {
+                jql_filter_matches(t, payload, self.config.jira_jql_fail_open, &self.jql_cache)
+            })
             .collect();
 
         if matching_triggers.is_empty() {
             debug!(
                 webhook_event = %webhook_event,
+                site = %site,
                 "No matching Jira triggers found for event"
             );
             return;
@@ -136,6 +195,13 @@ impl JiraRouter {
             "Forwarding Jira event to matching triggers"
         );
 
+        // Only parse the body as a Value when some trigger includes fields from it
+        let payload_value: Option<Value> = matching_triggers
+            .iter()
+            .any(|t| !t.include_fields.is_empty())
+            .then(|| serde_json::from_str(&raw_body).ok())
+            .flatten();
+
         // Wrap in Arc for sharing across async tasks
         let headers = Arc::new(headers);
         let raw_body = Arc::new(raw_body);
@@ -146,19 +212,15 @@ impl JiraRouter {
         for trigger in &matching_triggers {
             let client = self.n8n_client.clone();
             let workflow_name = trigger.workflow_name.clone();
-
-            let prod_url =
-                append_query_string(&self.build_webhook_url(&trigger.webhook_id), &query_string);
-            let test_url = append_query_string(
-                &self.build_test_webhook_url(&trigger.webhook_id),
-                &query_string,
-            );
+            let body = trigger_body(trigger, &raw_body);
+            let (prod_url, test_url) =
+                self.trigger_urls(trigger, payload_value.as_ref(), &query_string);
 
             // Production webhook - only for active workflows
             if trigger.workflow_active {
                 let prod_client = client.clone();
                 let prod_name = workflow_name.clone();
-                let prod_body = raw_body.clone();
+                let prod_body = body.clone();
                 let prod_headers = headers.clone();
                 forwards.push(tokio::spawn(async move {
                     forward_to_webhook(
@@ -181,7 +243,7 @@ impl JiraRouter {
             // Test webhook - always forward
             let test_client = client.clone();
             let test_name = workflow_name.clone();
-            let test_body = raw_body.clone();
+            let test_body = body.clone();
             let test_headers = headers.clone();
             forwards.push(tokio::spawn(async move {
                 forward_to_webhook(
@@ -201,12 +263,169 @@ impl JiraRouter {
         }
     }
 
+    /// Production and test webhook URLs for a trigger, carrying the inbound
+    /// query string and the trigger's resolved Include Fields.
+    fn trigger_urls(
+        &self,
+        t: &JiraTriggerRow,
+        payload: Option<&Value>,
+        query_string: &Option<String>,
+    ) -> (String, String) {
+        let fields = include_field_params(t, payload);
+        let build =
+            |url: String| append_query_params(append_query_string(&url, query_string), &fields);
+        (
+            build(self.build_webhook_url(&t.webhook_id)),
+            build(self.build_test_webhook_url(&t.webhook_id)),
+        )
+    }
+
     /// Get the current number of loaded Jira triggers (for health checks)
     pub fn trigger_count(&self) -> usize {
         self.db.count_jira_triggers().unwrap_or(0)
     }
 }
 
+/// Returns true if the event's project is one the trigger is scoped to.
+///
+/// Scopes match the project's key, name, or id case-insensitively. Events
+/// that carry no project (user, sprint, board events) are not scoped.
+fn project_scope_matches(t: &JiraTriggerRow, payload: &JiraWebhookPayload) -> bool {
+    if t.projects.is_empty() {
+        return true;
+    }
+    let Some(project) = payload.project() else {
+        return true;
+    };
+
+    let identifiers: Vec<String> = ["key", "name", "id"]
+        .iter()
+        .filter_map(|k| match &project[*k] {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .collect();
+    let matched = t
+        .projects
+        .iter()
+        .any(|p| identifiers.iter().any(|id| id.eq_ignore_ascii_case(p)));
+    if !matched {
+        debug!(
+            workflow_name = %t.workflow_name,
+            projects = ?t.projects,
+            "Skipping Jira trigger: event is outside its project scope"
+        );
+    }
+    matched
+}
+
+/// Returns true if the trigger's JQL filter (if any) accepts the event.
+///
+/// Jira only applies webhook JQL to events that carry an issue, so events
+/// without one always pass. JQL outside the supported subset passes or fails
+/// according to `fail_open`. Filters are parsed through `cache`.
+fn jql_filter_matches(
+    t: &JiraTriggerRow,
+    payload: &JiraWebhookPayload,
+    fail_open: bool,
+    cache: &JqlCache,
+) -> bool {
+    let (Some(jql), Some(issue)) = (t.jql_filter.as_deref(), payload.issue()) else {
+        return true;
+    };
+
+    match cache.get(jql, &t.workflow_name) {
+        Some(filter) => {
+            let matched = filter.matches(issue);
+            if !matched {
+                debug!(
+                    workflow_name = %t.workflow_name,
+                    jql = %jql,
+                    "Skipping Jira trigger: issue does not match JQL filter"
+                );
+            }
+            matched
+        }
+        None => {
+            debug!(
+                workflow_name = %t.workflow_name,
+                fail_open,
+                "Jira trigger JQL filter is unsupported"
+            );
+            fail_open
+        }
+    }
+}
+
+/// Build the body forwarded to a trigger.
+///
+/// Jira drops the body itself when a webhook is registered with "Exclude
+/// Body"; since Unihook receives a single event, it applies that per trigger.
+/// Other triggers share the raw body.
+fn trigger_body(t: &JiraTriggerRow, raw_body: &Arc<String>) -> Arc<String> {
+    if t.exclude_body {
+        return Arc::new(String::new());
+    }
+    raw_body.clone()
+}
+
+/// Resolve a trigger's Include Fields against the payload as `(field, value)`
+/// query parameters, as Jira substitutes `field=${field}` in the webhook URL.
+/// Fields missing from the payload are left out.
+fn include_field_params(t: &JiraTriggerRow, payload: Option<&Value>) -> Vec<(String, String)> {
+    let Some(payload) = payload else {
+        return Vec::new();
+    };
+    t.include_fields
+        .iter()
+        .filter_map(|field| {
+            let value = match resolve_field(payload, field)? {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            Some((field.clone(), value))
+        })
+        .collect()
+}
+
+/// Resolve a Jira URL variable path (e.g. `issue.key`, `project.id`,
+/// `modifiedUser.accountId`) against a webhook payload.
+///
+/// `project` falls back to the issue's project and `modifiedUser` maps to the
+/// event's `user`, mirroring the variables Jira substitutes.
+fn resolve_field<'a>(payload: &'a Value, path: &str) -> Option<&'a Value> {
+    let (root, rest) = path.split_once('.').unwrap_or((path, ""));
+    let mut current = match root {
+        "project" => payload
+            .get("project")
+            .or_else(|| payload.pointer("/issue/fields/project"))?,
+        "modifiedUser" => payload.get("user")?,
+        other => payload.get(other)?,
+    };
+    for segment in rest.split('.').filter(|s| !s.is_empty()) {
+        current = current.get(segment)?;
+    }
+    (!current.is_null()).then_some(current)
+}
+
+/// Append URL-encoded query parameters to a URL.
+fn append_query_params(url: String, params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return url;
+    }
+    match reqwest::Url::parse(&url) {
+        Ok(mut parsed) => {
+            parsed.query_pairs_mut().extend_pairs(params);
+            parsed.to_string()
+        }
+        Err(e) => {
+            warn!(url = %url, error = %e, "Cannot append Jira Include Fields to webhook URL");
+            url
+        }
+    }
+}
+
 /// Append an optional query string to a URL.
 fn append_query_string(url: &str, query_string: &Option<String>) -> String {
     match query_string {
@@ -225,6 +444,254 @@ fn append_query_string(url: &str, query_string: &Option<String>) -> String {
 mod tests {
     use super::*;
 
+    fn sample_row(jql_filter: Option<&str>) -> JiraTriggerRow {
+        JiraTriggerRow {
+            webhook_id: "jh1".to_string(),
+            workflow_name: "Test".to_string(),
+            workflow_active: true,
+            events: vec!["*".to_string()],
+            jql_filter: jql_filter.map(str::to_string),
+            site: String::new(),
+            exclude_body: false,
+            include_fields: vec![],
+            projects: vec![],
+        }
+    }
+
+    fn issue_payload(project_key: &str) -> JiraWebhookPayload {
+        serde_json::from_value(serde_json::json!({
+            "webhookEvent": "jira:issue_created",
+            "issue": {
+                "key": format!("{}-1", project_key),
+                "fields": {
+                    "project": { "key": project_key },
+                    "issuetype": { "name": "Bug" }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_jql_no_filter_matches() {
+        assert!(jql_filter_matches(
+            &sample_row(None),
+            &issue_payload("PROJ"),
+            false,
+            &JqlCache::default()
+        ));
+    }
+
+    #[test]
+    fn test_jql_filter_applied_to_issue_events() {
+        let row = sample_row(Some("project = PROJ AND issuetype = Bug"));
+        assert!(jql_filter_matches(
+            &row,
+            &issue_payload("PROJ"),
+            true,
+            &JqlCache::default()
+        ));
+        assert!(!jql_filter_matches(
+            &row,
+            &issue_payload("OTHER"),
+            true,
+            &JqlCache::default()
+        ));
+    }
+
+    #[test]
+    fn test_jql_filter_ignored_for_events_without_issue() {
+        let row = sample_row(Some("project = PROJ"));
+        let payload: JiraWebhookPayload = serde_json::from_value(serde_json::json!({
+            "webhookEvent": "sprint_started",
+            "sprint": { "id": 1 }
+        }))
+        .unwrap();
+        assert!(jql_filter_matches(
+            &row,
+            &payload,
+            false,
+            &JqlCache::default()
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_jql_fails_open_or_closed() {
+        let row = sample_row(Some("assignee = currentUser()"));
+        assert!(jql_filter_matches(
+            &row,
+            &issue_payload("PROJ"),
+            true,
+            &JqlCache::default()
+        ));
+        assert!(!jql_filter_matches(
+            &row,
+            &issue_payload("PROJ"),
+            false,
+            &JqlCache::default()
+        ));
+    }
+
+    #[test]
+    fn test_jql_cache_parses_once_until_cleared() {
+        let cache = JqlCache::default();
+        let first = cache.get("project = PROJ", "Test").unwrap();
+        let second = cache.get("project = PROJ", "Test").unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // Unsupported JQL is remembered too, so it is only logged once
+        assert!(cache.get("assignee = currentUser()", "Test").is_none());
+        assert_eq!(cache.filters.lock().len(), 2);
+
+        cache.clear();
+        assert!(cache.filters.lock().is_empty());
+        let reparsed = cache.get("project = PROJ", "Test").unwrap();
+        assert!(!Arc::ptr_eq(&first, &reparsed));
+    }
+
+    #[test]
+    fn test_project_scope_unscoped_matches() {
+        assert!(project_scope_matches(
+            &sample_row(None),
+            &issue_payload("PROJ")
+        ));
+    }
+
+    #[test]
+    fn test_project_scope_filters_issue_events() {
+        let mut row = sample_row(None);
+        row.projects = vec!["proj".to_string(), "OPS".to_string()];
+        assert!(project_scope_matches(&row, &issue_payload("PROJ")));
+        assert!(!project_scope_matches(&row, &issue_payload("OTHER")));
+    }
+
+    #[test]
+    fn test_project_scope_uses_top_level_project() {
+        let mut row = sample_row(None);
+        row.projects = vec!["PROJ".to_string()];
+        let payload: JiraWebhookPayload = serde_json::from_value(serde_json::json!({
+            "webhookEvent": "project_updated",
+            "project": { "id": 10000, "key": "PROJ" }
+        }))
+        .unwrap();
+        assert!(project_scope_matches(&row, &payload));
+    }
+
+    #[test]
+    fn test_project_scope_ignored_for_events_without_project() {
+        let mut row = sample_row(None);
+        row.projects = vec!["PROJ".to_string()];
+        let payload: JiraWebhookPayload = serde_json::from_value(serde_json::json!({
+            "webhookEvent": "sprint_started",
+            "sprint": { "id": 1 }
+        }))
+        .unwrap();
+        assert!(project_scope_matches(&row, &payload));
+    }
+
+    #[test]
+    fn test_trigger_body_untrimmed_shares_raw_body() {
+        let raw = Arc::new("{\"webhookEvent\":\"jira:issue_created\"}".to_string());
+        let body = trigger_body(&sample_row(None), &raw);
+        assert!(Arc::ptr_eq(&body, &raw));
+    }
+
+    #[test]
+    fn test_trigger_body_exclude_body() {
+        let mut row = sample_row(None);
+        row.exclude_body = true;
+        row.include_fields = vec!["issue.key".to_string()];
+        let raw = Arc::new("{\"webhookEvent\":\"jira:issue_created\"}".to_string());
+        assert_eq!(trigger_body(&row, &raw).as_str(), "");
+    }
+
+    #[test]
+    fn test_trigger_body_include_fields_keep_raw_body() {
+        let mut row = sample_row(None);
+        row.include_fields = vec!["issue.key".to_string()];
+        let raw = Arc::new(include_fields_payload().to_string());
+        assert!(Arc::ptr_eq(&trigger_body(&row, &raw), &raw));
+    }
+
+    fn include_fields_payload() -> Value {
+        serde_json::json!({
+            "webhookEvent": "jira:issue_updated",
+            "user": { "accountId": "acc-1" },
+            "issue": {
+                "key": "PROJ-1",
+                "fields": { "project": { "id": "10000", "key": "PROJ" } }
+            }
+        })
+    }
+
+    fn test_router() -> JiraRouter {
+        let config = Arc::new(Config {
+            n8n_api_url: "http://n8n:5678".to_string(),
+            ..Config::test_default()
+        });
+        let n8n_client = Arc::new(N8nClient::new(config.clone()));
+        JiraRouter::new(
+            config,
+            n8n_client,
+            Arc::new(Database::open(":memory:").unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_include_fields_appended_to_prod_and_test_urls() {
+        let mut row = sample_row(None);
+        row.include_fields = vec![
+            "issue.key".to_string(),
+            "project.id".to_string(),
+            "modifiedUser.accountId".to_string(),
+        ];
+        let payload = include_fields_payload();
+
+        let (prod_url, test_url) =
+            test_router().trigger_urls(&row, Some(&payload), &Some("secret=abc".to_string()));
+
+        let query = "?secret=abc&issue.key=PROJ-1&project.id=10000&modifiedUser.accountId=acc-1";
+        assert_eq!(
+            prod_url,
+            format!("http://n8n:5678/webhook/jh1/webhook{query}")
+        );
+        assert_eq!(
+            test_url,
+            format!("http://n8n:5678/webhook-test/jh1/webhook{query}")
+        );
+    }
+
+    #[test]
+    fn test_include_fields_missing_from_payload_are_left_out() {
+        let mut row = sample_row(None);
+        row.include_fields = vec!["sprint.id".to_string(), "issue.key".to_string()];
+        let payload = include_fields_payload();
+
+        let (prod_url, _) = test_router().trigger_urls(&row, Some(&payload), &None);
+
+        assert_eq!(
+            prod_url,
+            "http://n8n:5678/webhook/jh1/webhook?issue.key=PROJ-1"
+        );
+    }
+
+    #[test]
+    fn test_include_field_values_are_url_encoded() {
+        let mut row = sample_row(None);
+        row.include_fields = vec!["issue.fields.summary".to_string()];
+        let payload = serde_json::json!({
+            "issue": { "fields": { "summary": "Fix a&b = c" } }
+        });
+
+        assert_eq!(
+            append_query_params(
+                "http://n8n:5678/webhook/jh1/webhook".to_string(),
+                &include_field_params(&row, Some(&payload)),
+            ),
+            "http://n8n:5678/webhook/jh1/webhook?issue.fields.summary=Fix+a%26b+%3D+c"
+        );
+    }
+
     #[test]
     fn test_append_query_string_none() {
         let url = "http://n8n:5678/webhook/abc/webhook";
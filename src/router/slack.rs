@@ -1,7 +1,7 @@
 use crate::config::Config;
-use crate::db::Database;
+use crate::db::{Database, SlackTriggerRow};
 use crate::n8n::N8nClient;
-use crate::slack::SlackEventCallback;
+use crate::slack::{SlackEvent, SlackEventCallback};
 use axum::http::HeaderMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -89,7 +89,8 @@ impl SlackRouter {
 
     /// Route a Slack event to all matching triggers.
     ///
-    /// Reads triggers from the database, filters by event type and channel,
+    /// Reads triggers from the database, filters by event type, channel,
+    /// conversation type, and thread position,
     /// reconstructs webhook URLs, and forwards.
     pub async fn route_event(
         &self,
@@ -118,29 +119,10 @@ impl SlackRouter {
             }
         };
 
-        // Filter by event type and channel (replicating SlackTriggerConfig::matches_event logic)
+        // Filter by event type, channel, conversation type, and thread position
         let matching_triggers: Vec<_> = all_rows
             .iter()
-            .filter(|t| {
-                // Event type must match (or trigger accepts any event)
-                let type_matches = t.event_type == "any_event" || t.event_type == n8n_event_type;
-                if !type_matches {
-                    return false;
-                }
-
-                // Channel must match (or trigger watches whole workspace)
-                if t.watch_whole_workspace {
-                    return true;
-                }
-
-                match channel {
-                    Some(ch) => t.channels.contains(&ch.to_string()),
-                    None => matches!(
-                        t.event_type.as_str(),
-                        "user_created" | "channel_created" | "any_event"
-                    ),
-                }
-            })
+            .filter(|t| trigger_matches_event(t, event))
             .collect();
 
         if matching_triggers.is_empty() {
@@ -224,3 +206,221 @@ impl SlackRouter {
         self.db.count_slack_triggers().unwrap_or(0)
     }
 }
+
+/// Returns true if the trigger row should receive the event.
+fn trigger_matches_event(t: &SlackTriggerRow, event: &SlackEvent) -> bool {
+    let n8n_event_type = event.to_n8n_event_type();
+
+    // Event type must match (or trigger accepts any event)
+    let type_matches = t.event_type == "any_event" || t.event_type == n8n_event_type;
+    if !type_matches {
+        return false;
+    }
+
+    // Channel must match (or trigger watches whole workspace)
+    let channel_matches = if t.watch_whole_workspace {
+        true
+    } else {
+        match event.channel.as_deref() {
+            Some(ch) => t.channels.iter().any(|c| c == ch),
+            None => matches!(
+                t.event_type.as_str(),
+                "user_created" | "channel_created" | "any_event"
+            ),
+        }
+    };
+    if !channel_matches {
+        return false;
+    }
+
+    // Optional conversation type filter (DMs, private channels, ...)
+    if let Some(channel_types) = &t.channel_types {
+        match event.resolved_channel_type() {
+            Some(ct) if channel_types.iter().any(|c| c == ct) => {}
+            _ => {
+                debug!(
+                    workflow_name = %t.workflow_name,
+                    channel_type = ?event.resolved_channel_type(),
+                    allowed = ?channel_types,
+                    "Skipping Slack trigger: channel type not allowed"
+                );
+                return false;
+            }
+        }
+    }
+
+    // Optional top-level vs thread reply filter
+    if !t.thread_filter.matches(event.is_thread_reply()) {
+        debug!(
+            workflow_name = %t.workflow_name,
+            thread_filter = %t.thread_filter.as_str(),
+            "Skipping Slack trigger: thread position does not match"
+        );
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slack::SlackThreadFilter;
+
+    fn sample_row(channel_types: &[&str], thread_filter: SlackThreadFilter) -> SlackTriggerRow {
+        let channel_types = (!channel_types.is_empty())
+            .then(|| channel_types.iter().map(|s| s.to_string()).collect());
+        SlackTriggerRow {
+            webhook_id: "sh1".to_string(),
+            workflow_name: "Test".to_string(),
+            workflow_active: true,
+            event_type: "message".to_string(),
+            channels: vec![],
+            watch_whole_workspace: true,
+            channel_types,
+            thread_filter,
+        }
+    }
+
+    fn message(channel: &str, channel_type: Option<&str>, thread_ts: Option<&str>) -> SlackEvent {
+        serde_json::from_value(serde_json::json!({
+            "type": "message",
+            "channel": channel,
+            "channel_type": channel_type,
+            "ts": "1700000000.000200",
+            "thread_ts": thread_ts,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_no_filters_matches_everything() {
+        let row = sample_row(&[], SlackThreadFilter::Any);
+        assert!(trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), None)
+        ));
+        assert!(trigger_matches_event(
+            &row,
+            &message("D1", Some("im"), Some("1700000000.000100"))
+        ));
+    }
+
+    #[test]
+    fn test_channel_type_filter_accepts_listed_type() {
+        let row = sample_row(&["im", "mpim"], SlackThreadFilter::Any);
+        assert!(trigger_matches_event(
+            &row,
+            &message("D1", Some("im"), None)
+        ));
+        assert!(trigger_matches_event(
+            &row,
+            &message("G1", Some("mpim"), None)
+        ));
+    }
+
+    #[test]
+    fn test_empty_configured_channel_type_filter_matches_nothing() {
+        let mut row = sample_row(&[], SlackThreadFilter::Any);
+        row.channel_types = Some(vec![]);
+        for (channel, channel_type) in [
+            ("C1", "channel"),
+            ("G1", "group"),
+            ("D1", "im"),
+            ("G2", "mpim"),
+        ] {
+            assert!(!trigger_matches_event(
+                &row,
+                &message(channel, Some(channel_type), None)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_channel_type_filter_rejects_other_types() {
+        let row = sample_row(&["im"], SlackThreadFilter::Any);
+        assert!(!trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), None)
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            &message("G1", Some("group"), None)
+        ));
+    }
+
+    #[test]
+    fn test_channel_type_filter_rejects_unknown_type() {
+        let row = sample_row(&["channel"], SlackThreadFilter::Any);
+        assert!(!trigger_matches_event(&row, &message("C1", None, None)));
+    }
+
+    #[test]
+    fn test_channel_type_filter_infers_dm_without_channel_type() {
+        let row = sample_row(&["im"], SlackThreadFilter::Any);
+        assert!(trigger_matches_event(&row, &message("D1", None, None)));
+    }
+
+    #[test]
+    fn test_thread_filter_replies_only() {
+        let row = sample_row(&[], SlackThreadFilter::Replies);
+        assert!(trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), Some("1700000000.000100"))
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), None)
+        ));
+        // Thread parent (thread_ts == ts) is a top-level message
+        assert!(!trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), Some("1700000000.000200"))
+        ));
+    }
+
+    #[test]
+    fn test_thread_filter_top_level_only() {
+        let row = sample_row(&[], SlackThreadFilter::TopLevel);
+        assert!(trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), None)
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), Some("1700000000.000100"))
+        ));
+    }
+
+    #[test]
+    fn test_filters_combine() {
+        let row = sample_row(&["im"], SlackThreadFilter::Replies);
+        assert!(trigger_matches_event(
+            &row,
+            &message("D1", Some("im"), Some("1700000000.000100"))
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            &message("D1", Some("im"), None)
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), Some("1700000000.000100"))
+        ));
+    }
+
+    #[test]
+    fn test_channel_list_still_applies() {
+        let mut row = sample_row(&["channel"], SlackThreadFilter::Any);
+        row.watch_whole_workspace = false;
+        row.channels = vec!["C1".to_string()];
+        assert!(trigger_matches_event(
+            &row,
+            &message("C1", Some("channel"), None)
+        ));
+        assert!(!trigger_matches_event(
+            &row,
+            &message("C2", Some("channel"), None)
+        ));
+    }
+}
@@ -1,7 +1,12 @@
 use crate::config::Config;
+use crate::crypto::compute_zoom_webhook_signature;
 use crate::db::{Database, ZoomTriggerRow};
 use crate::n8n::N8nClient;
-use axum::http::HeaderMap;
+use crate::zoom::{
+    ZoomEventTier, ZoomRedactionMode, ZoomRoutingMode, extract_alternative_hosts,
+    extract_host_email, extract_host_id, extract_participant_email, redact_json_pointers,
+};
+use axum::http::{HeaderMap, HeaderValue};
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,6 +15,14 @@ use tracing::{debug, error, info, warn};
 
 use super::forward_to_webhook;
 
+/// An email a Zoom event can be routed to, and how it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ZoomRecipient {
+    pub email: String,
+    /// `host`, or the [`ZoomRoutingMode`] that produced it
+    pub via: &'static str,
+}
+
 /// The Zoom routing engine that manages trigger configurations and forwards events.
 pub struct ZoomRouter {
     db: Arc<Database>,
@@ -72,19 +85,89 @@ impl ZoomRouter {
         )
     }
 
+    /// Collect the emails an event may be routed to: the host's, plus those
+    /// found by the routing modes enabled for the event type.
+    ///
+    /// `host_id` is resolved with `ZOOM_USER_EMAILS`, then with mappings
+    /// learned from earlier events; events carrying both `host_id` and
+    /// `host_email` update the learned mappings.
+    fn resolve_recipients(&self, event: &str, payload: &serde_json::Value) -> Vec<ZoomRecipient> {
+        let host_email = extract_host_email(payload);
+        let host_id = extract_host_id(payload);
+        if let (Some(id), Some(email)) = (&host_id, &host_email)
+            && let Err(e) = self.db.upsert_zoom_user(id, email)
+        {
+            warn!(error = %e, "Failed to record Zoom host ID mapping");
+        }
+
+        let mut recipients: Vec<ZoomRecipient> = host_email
+            .into_iter()
+            .map(|email| ZoomRecipient { email, via: "host" })
+            .collect();
+
+        for mode in self.config.zoom_routing_modes(event) {
+            let emails = match mode {
+                ZoomRoutingMode::Participant => {
+                    extract_participant_email(payload).into_iter().collect()
+                }
+                ZoomRoutingMode::AlternativeHost => extract_alternative_hosts(payload),
+                ZoomRoutingMode::HostId => host_id
+                    .as_deref()
+                    .and_then(|id| self.lookup_zoom_user_email(id))
+                    .into_iter()
+                    .collect(),
+            };
+            for email in emails {
+                if !recipients
+                    .iter()
+                    .any(|r| r.email.eq_ignore_ascii_case(&email))
+                {
+                    recipients.push(ZoomRecipient {
+                        email,
+                        via: mode.as_str(),
+                    });
+                }
+            }
+        }
+        recipients
+    }
+
+    fn lookup_zoom_user_email(&self, user_id: &str) -> Option<String> {
+        if let Some(email) = self.config.zoom_user_email(user_id) {
+            return Some(email.to_string());
+        }
+        match self.db.get_zoom_user_email(user_id) {
+            Ok(email) => email,
+            Err(e) => {
+                warn!(error = %e, "Failed to look up Zoom host ID mapping");
+                None
+            }
+        }
+    }
+
+    /// Forward a verified Zoom event to matching triggers.
+    ///
+    /// `account_id` is the Zoom account that sent the event; account-scoped
+    /// triggers only receive their own account's events. `payload` is the
+    /// event's JSON (with its `payload.object`). `secret` is
+    /// the Secret Token that signed the request; payloads redacted for
+    /// non-privileged triggers are re-signed with it.
     pub async fn route_event(
         &self,
         event: &str,
-        host_email: Option<&str>,
+        account_id: Option<&str>,
+        payload: &serde_json::Value,
         raw_body: String,
         headers: HeaderMap,
+        secret: &str,
     ) {
-        debug!(event = %event, host_email = ?host_email, "Routing Zoom event");
+        let recipients = self.resolve_recipients(event, payload);
+        debug!(event = %event, recipients = ?recipients, "Routing Zoom event");
 
-        if host_email.is_none() {
+        if recipients.is_empty() {
             warn!(
                 event = %event,
-                "Zoom event has no host email field; only privileged triggers may receive it"
+                "Zoom event has no host or routing email; only privileged triggers may receive it"
             );
         }
 
@@ -98,26 +181,69 @@ impl ZoomRouter {
 
         let privileged_users = self.config.zoom_privileged_user_emails();
         let privileged_workflow_ids = self.config.zoom_privileged_workflow_ids();
+        let tier = self.config.zoom_event_tier(event);
 
         let matching_triggers: Vec<_> = all_rows
             .iter()
             .filter(|t| zoom_trigger_matches_event(&t.events, event))
+            .filter(|t| zoom_trigger_matches_account(t.account_id.as_deref(), account_id))
             .filter(|t| {
-                trigger_should_receive(t, host_email, &privileged_users, &privileged_workflow_ids)
+                trigger_should_receive(
+                    t,
+                    tier,
+                    &recipients,
+                    &privileged_users,
+                    &privileged_workflow_ids,
+                )
             })
             .collect();
 
         if matching_triggers.is_empty() {
-            debug!(event = %event, "No matching Zoom triggers found for event after host filter");
+            debug!(
+                event = %event,
+                tier = tier.as_str(),
+                "No matching Zoom triggers found for event after host filter"
+            );
             return;
         }
 
         info!(
             event = %event,
+            tier = tier.as_str(),
             matching_count = matching_triggers.len(),
             "Forwarding Zoom event to matching triggers"
         );
 
+        let redact_pointers = self.config.zoom_redact_pointers(event);
+        let needs_redaction = !redact_pointers.is_empty()
+            && matching_triggers
+                .iter()
+                .any(|t| !is_privileged_trigger(t, &privileged_users, &privileged_workflow_ids));
+        let mut redaction_failed = false;
+        let redacted = if needs_redaction {
+            match redact_zoom_event(
+                &raw_body,
+                &headers,
+                &redact_pointers,
+                self.config.zoom_redaction_mode(),
+                secret,
+            ) {
+                Ok(Some((body, headers))) => Some((Arc::new(body), Arc::new(headers))),
+                Ok(None) => None,
+                Err(e) => {
+                    error!(
+                        event = %event,
+                        error = %e,
+                        "Failed to redact Zoom payload; skipping non-privileged triggers"
+                    );
+                    redaction_failed = true;
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         let headers = Arc::new(headers);
         let raw_body = Arc::new(raw_body);
         let mut forwards = Vec::new();
@@ -125,6 +251,16 @@ impl ZoomRouter {
         for trigger in &matching_triggers {
             let client = self.n8n_client.clone();
             let workflow_name = trigger.workflow_name.clone();
+            let privileged =
+                is_privileged_trigger(trigger, &privileged_users, &privileged_workflow_ids);
+            if !privileged && redaction_failed {
+                // Never fall back to the unredacted payload
+                continue;
+            }
+            let (raw_body, headers) = match &redacted {
+                Some((body, headers)) if !privileged => (body.clone(), headers.clone()),
+                _ => (raw_body.clone(), headers.clone()),
+            };
             let prod_url = self.build_webhook_url(&trigger.webhook_id);
             let test_url = self.build_test_webhook_url(&trigger.webhook_id);
 
@@ -178,47 +314,133 @@ impl ZoomRouter {
     }
 }
 
+/// Redact the fields at `pointers` in a Zoom payload and re-sign it.
+///
+/// n8n's Zoom Trigger verifies `x-zm-signature` over the exact body, so the
+/// redacted body gets a fresh signature from the same Secret Token and the
+/// original `x-zm-request-timestamp`. Returns `None` when none of the fields
+/// are present, so the original body can be forwarded unchanged.
+fn redact_zoom_event(
+    raw_body: &str,
+    headers: &HeaderMap,
+    pointers: &[&str],
+    mode: ZoomRedactionMode,
+    secret: &str,
+) -> Result<Option<(String, HeaderMap)>, serde_json::Error> {
+    let mut payload: serde_json::Value = serde_json::from_str(raw_body)?;
+    let redacted_count = redact_json_pointers(&mut payload, pointers.iter().copied(), mode);
+    if redacted_count == 0 {
+        return Ok(None);
+    }
+    let body = serde_json::to_string(&payload)?;
+
+    let timestamp = headers
+        .get("x-zm-request-timestamp")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let signature = compute_zoom_webhook_signature(secret, timestamp, body.as_bytes());
+    let mut headers = headers.clone();
+    headers.insert(
+        "x-zm-signature",
+        HeaderValue::from_str(&signature).expect("signature is valid ASCII"),
+    );
+
+    debug!(
+        redacted_count,
+        "Redacted Zoom payload fields for non-privileged triggers"
+    );
+    Ok(Some((body, headers)))
+}
+
 /// Returns true if any trigger row matches the given Zoom event (including wildcard).
 fn zoom_trigger_matches_event(events: &[String], event: &str) -> bool {
     events.iter().any(|e| e == "*" || e == event)
 }
 
+/// Returns true if a trigger scoped to `trigger_account` (`unihook.account`)
+/// may receive an event from `event_account`. Unscoped triggers receive
+/// events from every account.
+fn zoom_trigger_matches_account(
+    trigger_account: Option<&str>,
+    event_account: Option<&str>,
+) -> bool {
+    match trigger_account {
+        None => true,
+        Some(account) => event_account == Some(account),
+    }
+}
+
+/// Returns true if the trigger is on a privileged allowlist, by workflow ID
+/// or by its owner's email.
+fn is_privileged_trigger(
+    trigger: &ZoomTriggerRow,
+    privileged_users: &HashSet<String>,
+    privileged_workflow_ids: &HashSet<String>,
+) -> bool {
+    privileged_workflow_ids.contains(&trigger.workflow_id)
+        || trigger
+            .owner_email
+            .as_ref()
+            .is_some_and(|owner| privileged_users.contains(&owner.to_lowercase()))
+}
+
 /// Returns true if the trigger should receive the event after host/privileged filtering.
+///
+/// Privileged triggers receive every event. Events of the `privileged` tier
+/// go only to them; `host`-tier events also go to workflows whose owner is
+/// one of the event's recipients (the host, or a participant / alternative
+/// host / resolved `host_id` when that routing mode is enabled).
 pub(crate) fn trigger_should_receive(
     trigger: &ZoomTriggerRow,
-    host_email: Option<&str>,
+    tier: ZoomEventTier,
+    recipients: &[ZoomRecipient],
     privileged_users: &HashSet<String>,
     privileged_workflow_ids: &HashSet<String>,
 ) -> bool {
-    if privileged_workflow_ids.contains(&trigger.workflow_id) {
+    if is_privileged_trigger(trigger, privileged_users, privileged_workflow_ids) {
         return true;
     }
 
-    if let Some(owner) = &trigger.owner_email
-        && privileged_users.contains(&owner.to_lowercase())
-    {
-        return true;
+    if tier == ZoomEventTier::Privileged {
+        debug!(
+            workflow_name = %trigger.workflow_name,
+            "Skipping Zoom trigger: event is privileged-tier and trigger is not privileged"
+        );
+        return false;
     }
 
-    let Some(host) = host_email else {
+    if recipients.is_empty() {
         debug!(
             workflow_name = %trigger.workflow_name,
-            "Skipping Zoom trigger: event has no host email and trigger is not privileged"
+            "Skipping Zoom trigger: event has no recipient email and trigger is not privileged"
         );
         return false;
-    };
+    }
 
     match &trigger.owner_email {
-        Some(owner) if host.eq_ignore_ascii_case(owner) => true,
-        Some(owner) => {
-            debug!(
-                workflow_name = %trigger.workflow_name,
-                host_email = %host,
-                owner_email = %owner,
-                "Skipping Zoom trigger: host email does not match workflow owner"
-            );
-            false
-        }
+        Some(owner) => match recipients
+            .iter()
+            .find(|r| r.email.eq_ignore_ascii_case(owner))
+        {
+            Some(recipient) => {
+                debug!(
+                    workflow_name = %trigger.workflow_name,
+                    owner_email = %owner,
+                    via = recipient.via,
+                    "Zoom trigger owner matches event recipient"
+                );
+                true
+            }
+            None => {
+                debug!(
+                    workflow_name = %trigger.workflow_name,
+                    recipients = ?recipients,
+                    owner_email = %owner,
+                    "Skipping Zoom trigger: no event recipient matches workflow owner"
+                );
+                false
+            }
+        },
         None => {
             debug!(
                 workflow_name = %trigger.workflow_name,
@@ -248,9 +470,58 @@ mod tests {
             owner_email: owner_email.map(str::to_string),
             project_id: "proj1".to_string(),
             project_type: project_type.to_string(),
+            account_id: None,
         }
     }
 
+    fn host(email: &str) -> Vec<ZoomRecipient> {
+        vec![ZoomRecipient {
+            email: email.to_string(),
+            via: "host",
+        }]
+    }
+
+    #[test]
+    fn test_redact_zoom_event_re_signs_body() {
+        let raw_body = r#"{"event":"recording.completed","download_token":"tok","payload":{"object":{"password":"pw","topic":"Standup"}}}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert("x-zm-request-timestamp", "1739923528".parse().unwrap());
+        headers.insert("x-zm-signature", "v0=inbound".parse().unwrap());
+
+        let (body, headers) = redact_zoom_event(
+            raw_body,
+            &headers,
+            &["/download_token", "/payload/object/password"],
+            ZoomRedactionMode::Mask,
+            "zoom-secret",
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(!body.contains("\"tok\"") && !body.contains("\"pw\""));
+        assert!(body.contains("Standup"));
+        assert!(crate::crypto::verify_zoom_webhook_signature(
+            "zoom-secret",
+            body.as_bytes(),
+            "1739923528",
+            headers.get("x-zm-signature").unwrap().to_str().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_redact_zoom_event_without_matching_fields() {
+        let raw_body = r#"{"event":"meeting.started","payload":{"object":{}}}"#;
+        let redacted = redact_zoom_event(
+            raw_body,
+            &HeaderMap::new(),
+            &["/download_token"],
+            ZoomRedactionMode::Remove,
+            "zoom-secret",
+        )
+        .unwrap();
+        assert!(redacted.is_none());
+    }
+
     #[test]
     fn test_zoom_trigger_matches_event_exact() {
         assert!(zoom_trigger_matches_event(
@@ -267,6 +538,15 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_zoom_trigger_matches_account() {
+        assert!(zoom_trigger_matches_account(None, Some("prod")));
+        assert!(zoom_trigger_matches_account(None, None));
+        assert!(zoom_trigger_matches_account(Some("sub1"), Some("sub1")));
+        assert!(!zoom_trigger_matches_account(Some("sub1"), Some("prod")));
+        assert!(!zoom_trigger_matches_account(Some("sub1"), None));
+    }
+
     #[test]
     fn test_zoom_trigger_does_not_match() {
         assert!(!zoom_trigger_matches_event(
@@ -282,7 +562,8 @@ mod tests {
         let workflows = HashSet::new();
         assert!(trigger_should_receive(
             &trigger,
-            Some("host@example.com"),
+            ZoomEventTier::Host,
+            &host("host@example.com"),
             &users,
             &workflows
         ));
@@ -295,7 +576,8 @@ mod tests {
         let workflows = HashSet::new();
         assert!(!trigger_should_receive(
             &trigger,
-            Some("other@example.com"),
+            ZoomEventTier::Host,
+            &host("other@example.com"),
             &users,
             &workflows
         ));
@@ -308,7 +590,8 @@ mod tests {
         let workflows = HashSet::new();
         assert!(trigger_should_receive(
             &trigger,
-            Some("other@example.com"),
+            ZoomEventTier::Host,
+            &host("other@example.com"),
             &users,
             &workflows
         ));
@@ -319,7 +602,13 @@ mod tests {
         let trigger = sample_trigger("wf1", Some("host@example.com"), "personal");
         let users = HashSet::from(["host@example.com".to_string()]);
         let workflows = HashSet::new();
-        assert!(trigger_should_receive(&trigger, None, &users, &workflows));
+        assert!(trigger_should_receive(
+            &trigger,
+            ZoomEventTier::Host,
+            &[],
+            &users,
+            &workflows
+        ));
     }
 
     #[test]
@@ -327,7 +616,13 @@ mod tests {
         let trigger = sample_trigger("wf1", Some("host@example.com"), "personal");
         let users = HashSet::new();
         let workflows = HashSet::new();
-        assert!(!trigger_should_receive(&trigger, None, &users, &workflows));
+        assert!(!trigger_should_receive(
+            &trigger,
+            ZoomEventTier::Host,
+            &[],
+            &users,
+            &workflows
+        ));
     }
 
     #[test]
@@ -337,7 +632,8 @@ mod tests {
         let workflows = HashSet::from(["wf-admin".to_string()]);
         assert!(trigger_should_receive(
             &trigger,
-            Some("other@example.com"),
+            ZoomEventTier::Host,
+            &host("other@example.com"),
             &users,
             &workflows
         ));
@@ -350,7 +646,70 @@ mod tests {
         let workflows = HashSet::new();
         assert!(!trigger_should_receive(
             &trigger,
-            Some("host@example.com"),
+            ZoomEventTier::Host,
+            &host("host@example.com"),
+            &users,
+            &workflows
+        ));
+    }
+
+    #[test]
+    fn test_privileged_tier_skips_host_routing() {
+        let trigger = sample_trigger("wf1", Some("host@example.com"), "personal");
+        let users = HashSet::new();
+        let workflows = HashSet::new();
+        assert!(!trigger_should_receive(
+            &trigger,
+            ZoomEventTier::Privileged,
+            &host("host@example.com"),
+            &users,
+            &workflows
+        ));
+    }
+
+    #[test]
+    fn test_privileged_tier_reaches_privileged_triggers() {
+        let admin = sample_trigger("wf-admin", None, "team");
+        let owner = sample_trigger("wf1", Some("Privileged@example.com"), "personal");
+        let users = HashSet::from(["privileged@example.com".to_string()]);
+        let workflows = HashSet::from(["wf-admin".to_string()]);
+        for trigger in [&admin, &owner] {
+            assert!(trigger_should_receive(
+                trigger,
+                ZoomEventTier::Privileged,
+                &host("host@example.com"),
+                &users,
+                &workflows
+            ));
+        }
+    }
+
+    #[test]
+    fn test_trigger_should_receive_any_recipient() {
+        let trigger = sample_trigger("wf1", Some("Alt@example.com"), "personal");
+        let users = HashSet::new();
+        let workflows = HashSet::new();
+        let recipients = vec![
+            ZoomRecipient {
+                email: "host@example.com".to_string(),
+                via: "host",
+            },
+            ZoomRecipient {
+                email: "alt@example.com".to_string(),
+                via: "alternative_host",
+            },
+        ];
+        assert!(trigger_should_receive(
+            &trigger,
+            ZoomEventTier::Host,
+            &recipients,
+            &users,
+            &workflows
+        ));
+        assert!(!trigger_should_receive(
+            &trigger,
+            ZoomEventTier::Privileged,
+            &recipients,
             &users,
             &workflows
         ));
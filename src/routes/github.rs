@@ -6,8 +6,10 @@ use axum::{
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
+use crate::config::Config;
 use crate::crypto::verify_hmac_sha256;
-use crate::github::GitHubWebhookPayload;
+use crate::github::{GitHubWebhookPayload, enterprise_host};
+use crate::router::github::GitHubEventSource;
 
 use super::{AppState, extract_forwarded_headers};
 
@@ -18,38 +20,26 @@ const GITHUB_FORWARDED_HEADER_PREFIXES: &[&str] = &["x-github-", "x-hub-signatur
 /// Handle incoming GitHub webhook events
 ///
 /// This endpoint:
-/// 1. If `GITHUB_WEBHOOK_SECRET` is configured, verifies the `X-Hub-Signature-256`
-///    header using HMAC-SHA256 (returns 401 if invalid or missing)
+/// 1. If `GITHUB_WEBHOOK_SECRET` or `GITHUB_WEBHOOK_SCOPED_SECRETS` is
+///    configured, verifies the `X-Hub-Signature-256` header using HMAC-SHA256
+///    (returns 401 if invalid or missing)
 /// 2. Extracts the event type from the `X-GitHub-Event` header
-/// 3. Parses the repository owner/name from the payload body
+/// 3. Parses the repository owner/name from the payload body (or the
+///    organization / installation account for repository-less events)
 /// 4. Detects and acknowledges ping events without routing
-/// 5. Routes real events to all matching n8n workflows with GitHub triggers
-/// 6. Forwards the raw body and relevant headers to preserve webhook authentication
+/// 5. Updates the GitHub App installation map from installation events
+/// 6. Routes real events to all matching n8n workflows with GitHub triggers
+/// 7. Forwards the raw body and relevant headers to preserve webhook authentication
 pub async fn handle_github_event(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     body: String,
 ) -> impl IntoResponse {
-    // Verify inbound signature if a shared secret is configured
-    if let Some(ref secret) = state.config.github_webhook_secret {
-        let signature = headers
-            .get("x-hub-signature-256")
-            .and_then(|v| v.to_str().ok());
-
-        match signature {
-            Some(sig) if verify_hmac_sha256(secret, body.as_bytes(), sig) => {
-                debug!("GitHub webhook signature verified successfully");
-            }
-            Some(_) => {
-                warn!("GitHub webhook signature verification failed");
-                return (StatusCode::UNAUTHORIZED, "Invalid signature").into_response();
-            }
-            None => {
-                warn!("Missing X-Hub-Signature-256 header but GITHUB_WEBHOOK_SECRET is set");
-                return (StatusCode::UNAUTHORIZED, "Missing signature").into_response();
-            }
-        }
-    }
+    // Verify inbound signature if shared secrets are configured
+    let signature_key = match verify_github_signature(&state.config, &headers, &body) {
+        Ok(key) => key,
+        Err(response) => return response.into_response(),
+    };
 
     // Extract the event type from the X-GitHub-Event header
     let event_type = match headers.get("x-github-event").and_then(|v| v.to_str().ok()) {
@@ -81,19 +71,26 @@ pub async fn handle_github_event(
         return StatusCode::OK.into_response();
     }
 
-    // Extract owner/repo from the payload for routing
-    let (owner, repository) = match &payload.repository {
-        Some(repo) => (Some(repo.owner.login.as_str()), Some(repo.name.as_str())),
-        None => (None, None),
-    };
+    // Extract owner/repo from the payload for routing; repository-less
+    // org-level events route by organization or installation account
+    let source = GitHubEventSource::from_payload(&payload);
 
     info!(
         event_type = %event_type,
-        owner = ?owner,
-        repository = ?repository,
+        owner = ?source.owner,
+        repository = ?source.repository,
+        installation_id = ?source.installation_id,
+        enterprise_host = ?enterprise_host(&headers),
+        signature_key = ?signature_key,
         "Received GitHub event"
     );
 
+    // Keep the GitHub App installation map current before routing, so
+    // installation-scoped triggers see newly added repositories
+    state
+        .github_router
+        .apply_installation_event(&event_type, &payload);
+
     // Extract headers to forward to n8n
     let forwarded_headers = extract_forwarded_headers(&headers, GITHUB_FORWARDED_HEADER_PREFIXES);
     debug!(
@@ -104,14 +101,13 @@ pub async fn handle_github_event(
     // Route the event asynchronously but respond immediately
     let github_router = state.github_router.clone();
     let event_type_owned = event_type.clone();
-    let owner_owned = owner.map(|s| s.to_string());
-    let repo_owned = repository.map(|s| s.to_string());
+    let action_owned = payload.action.clone();
     tokio::spawn(async move {
         github_router
             .route_event(
                 &event_type_owned,
-                owner_owned.as_deref(),
-                repo_owned.as_deref(),
+                action_owned.as_deref(),
+                &source,
                 body,
                 forwarded_headers,
             )
@@ -122,6 +118,57 @@ pub async fn handle_github_event(
     StatusCode::OK.into_response()
 }
 
+/// Verify the `X-Hub-Signature-256` header against the configured GitHub
+/// secrets.
+///
+/// The payload's owner and repository select which scoped secrets apply, in
+/// addition to the global ones; the payload is only read for that and may be
+/// invalid here. Returns the key of the secret that matched (`acme/api`,
+/// `acme`, `global[1]`), or `None` when verification is disabled.
+fn verify_github_signature(
+    config: &Config,
+    headers: &HeaderMap,
+    body: &str,
+) -> Result<Option<String>, (StatusCode, &'static str)> {
+    if !config.github_signature_required() {
+        return Ok(None);
+    }
+
+    let Some(signature) = headers
+        .get("x-hub-signature-256")
+        .and_then(|v| v.to_str().ok())
+    else {
+        warn!("Missing X-Hub-Signature-256 header but GitHub webhook secrets are set");
+        return Err((StatusCode::UNAUTHORIZED, "Missing signature"));
+    };
+
+    let payload = serde_json::from_str::<GitHubWebhookPayload>(body).ok();
+    let owner = payload.as_ref().and_then(|p| p.owner_login());
+    let repository = payload
+        .as_ref()
+        .and_then(|p| p.repository.as_ref())
+        .map(|repo| repo.name.as_str());
+
+    let matched = config
+        .github_inbound_secrets(owner, repository)
+        .into_iter()
+        .find(|(_, secret)| verify_hmac_sha256(secret, body.as_bytes(), signature));
+    match matched {
+        Some((key, _)) => {
+            debug!(signature_key = %key, "GitHub webhook signature verified successfully");
+            Ok(Some(key))
+        }
+        None => {
+            warn!(
+                owner = ?owner,
+                repository = ?repository,
+                "GitHub webhook signature verification failed"
+            );
+            Err((StatusCode::UNAUTHORIZED, "Invalid signature"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,4 +337,83 @@ mod tests {
 
         assert_eq!(forwarded.len(), 0);
     }
+
+    // ── verify_github_signature tests ───────────────────────────────────
+
+    const ACME_API_PUSH: &str =
+        r#"{"repository": {"name": "api", "full_name": "acme/api", "owner": {"login": "acme"}}}"#;
+
+    fn signed_headers(secret: &str, body: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-hub-signature-256"),
+            HeaderValue::from_str(&crate::crypto::compute_hmac_sha256(secret, body.as_bytes()))
+                .unwrap(),
+        );
+        headers
+    }
+
+    fn secrets_config(global: &[&str], scoped: &[&str]) -> Config {
+        Config {
+            github_webhook_secret: global.iter().map(|s| s.to_string()).collect(),
+            github_webhook_scoped_secrets: scoped.iter().map(|s| s.to_string()).collect(),
+            ..Config::test_default()
+        }
+    }
+
+    #[test]
+    fn test_verify_skipped_without_secrets() {
+        let config = Config::test_default();
+        assert_eq!(
+            verify_github_signature(&config, &HeaderMap::new(), "{}"),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_verify_accepts_old_and_new_global_secrets() {
+        let config = secrets_config(&["old", "new"], &[]);
+        let body = ACME_API_PUSH;
+
+        assert_eq!(
+            verify_github_signature(&config, &signed_headers("old", body), body),
+            Ok(Some("global[0]".to_string()))
+        );
+        assert_eq!(
+            verify_github_signature(&config, &signed_headers("new", body), body),
+            Ok(Some("global[1]".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_verify_scoped_secrets_apply_to_their_owner_or_repository() {
+        let config = secrets_config(&[], &["acme=org-secret", "acme/web=web-secret"]);
+        let body = ACME_API_PUSH;
+
+        assert_eq!(
+            verify_github_signature(&config, &signed_headers("org-secret", body), body),
+            Ok(Some("acme".to_string()))
+        );
+        // acme/web's secret does not verify acme/api deliveries
+        let err = verify_github_signature(&config, &signed_headers("web-secret", body), body)
+            .unwrap_err();
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[test]
+    fn test_verify_rejects_missing_signature() {
+        let config = secrets_config(&[], &["acme=org-secret"]);
+
+        let err = verify_github_signature(&config, &HeaderMap::new(), ACME_API_PUSH).unwrap_err();
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Missing signature"));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_body() {
+        let config = secrets_config(&["secret"], &[]);
+        let headers = signed_headers("secret", ACME_API_PUSH);
+
+        let err = verify_github_signature(&config, &headers, "{}").unwrap_err();
+        assert_eq!(err.0, StatusCode::UNAUTHORIZED);
+    }
 }
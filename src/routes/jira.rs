@@ -1,12 +1,14 @@
 use axum::{
-    extract::{OriginalUri, State},
+    extract::{OriginalUri, Path, State},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
 };
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use crate::jira::JiraWebhookPayload;
+use crate::config::Config;
+use crate::crypto::find_hmac_sha256_secret;
+use crate::jira::{JiraWebhookPayload, detect_site, normalize_site};
 
 use super::{AppState, extract_forwarded_headers};
 
@@ -18,8 +20,12 @@ const JIRA_FORWARDED_HEADER_PREFIXES: &[&str] = &["x-atlassian-", "content-type"
 ///
 /// This endpoint:
 /// 1. Parses the `webhookEvent` field from the Jira payload to determine the event type
-/// 2. Routes the event to all matching n8n workflows with Jira triggers
-/// 3. Forwards the raw body, relevant headers, and any query parameters to n8n
+/// 2. Determines the Jira site from the path (`/jira/{site}/events`) or, on
+///    `/jira/events`, from the payload's `self` URLs via `JIRA_SITE_URLS`
+/// 3. If Jira secrets are configured, verifies the `X-Hub-Signature` header
+///    against that site's secrets (returns 401 if none match or missing)
+/// 4. Routes the event to all matching n8n workflows with Jira triggers for that site
+/// 5. Forwards the raw body, relevant headers, and any query parameters to n8n
 ///
 /// Query parameters on the inbound URL (e.g. `/jira/events?secret=abc`) are
 /// forwarded to the n8n webhook URL so that n8n's `authenticateWebhook` /
@@ -27,6 +33,7 @@ const JIRA_FORWARDED_HEADER_PREFIXES: &[&str] = &["x-atlassian-", "content-type"
 pub async fn handle_jira_event(
     State(state): State<Arc<AppState>>,
     OriginalUri(uri): OriginalUri,
+    site: Option<Path<String>>,
     headers: HeaderMap,
     body: String,
 ) -> impl IntoResponse {
@@ -40,8 +47,25 @@ pub async fn handle_jira_event(
         }
     };
 
+    // An explicit site in the path wins over detection from the payload
+    let site_in_path = site.is_some();
+    let site = match site {
+        Some(Path(site)) => normalize_site(Some(&site)),
+        None => detect_site(&payload, &state.config.jira_sites())
+            .unwrap_or_default()
+            .to_string(),
+    };
+
+    // Verify inbound signature against the resolved site's secrets
+    if let Err(response) =
+        verify_jira_signature(&state.config, &site, site_in_path, &headers, &body)
+    {
+        return response.into_response();
+    }
+
     info!(
         webhook_event = %payload.webhook_event,
+        site = %site,
         "Received Jira event"
     );
 
@@ -63,10 +87,9 @@ pub async fn handle_jira_event(
 
     // Route the event asynchronously but respond immediately
     let jira_router = state.jira_router.clone();
-    let webhook_event = payload.webhook_event.clone();
     tokio::spawn(async move {
         jira_router
-            .route_event(&webhook_event, body, forwarded_headers, query_string)
+            .route_event(&payload, &site, body, forwarded_headers, query_string)
             .await;
     });
 
@@ -74,6 +97,48 @@ pub async fn handle_jira_event(
     StatusCode::OK.into_response()
 }
 
+/// Verify the `X-Hub-Signature` header against the secrets for `site`.
+///
+/// Jira Cloud signs deliveries to webhooks registered with a secret using
+/// HMAC-SHA256 in `sha256=<hex>` format. Only the site's own secrets are
+/// tried (see [`Config::jira_secrets_for_site`]), so one site's secret cannot
+/// sign events for another. Verification is skipped when no Jira secrets are
+/// configured.
+fn verify_jira_signature(
+    config: &Config,
+    site: &str,
+    site_in_path: bool,
+    headers: &HeaderMap,
+    body: &str,
+) -> Result<(), (StatusCode, &'static str)> {
+    if !config.jira_signature_required() {
+        return Ok(());
+    }
+
+    let signature = headers.get("x-hub-signature").and_then(|v| v.to_str().ok());
+    let secrets = config.jira_secrets_for_site(site, site_in_path);
+
+    match signature {
+        Some(sig) => match find_hmac_sha256_secret(&secrets, body.as_bytes(), sig) {
+            Some(index) => {
+                debug!(
+                    secret_index = index,
+                    "Jira webhook signature verified successfully"
+                );
+                Ok(())
+            }
+            None => {
+                warn!(site = %site, "Jira webhook signature verification failed");
+                Err((StatusCode::UNAUTHORIZED, "Invalid signature"))
+            }
+        },
+        None => {
+            warn!("Missing X-Hub-Signature header but Jira webhook secrets are set");
+            Err((StatusCode::UNAUTHORIZED, "Missing signature"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,4 +242,116 @@ mod tests {
 
         assert_eq!(forwarded.len(), 0);
     }
+
+    // ── verify_jira_signature tests ─────────────────────────────────────
+
+    fn signed_headers(secret: &str, body: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-hub-signature"),
+            HeaderValue::from_str(&crate::crypto::compute_hmac_sha256(secret, body.as_bytes()))
+                .unwrap(),
+        );
+        headers
+    }
+
+    fn config_with_secrets(unscoped: &[&str], site_secrets: &[&str]) -> Config {
+        let mut config = Config::test_default();
+        config.jira_webhook_secret = unscoped.iter().map(|s| s.to_string()).collect();
+        config.jira_site_secrets = site_secrets.iter().map(|s| s.to_string()).collect();
+        config
+    }
+
+    const BODY: &str = r#"{"webhookEvent":"jira:issue_created"}"#;
+
+    #[test]
+    fn test_verify_skipped_without_secrets() {
+        let config = Config::test_default();
+        assert!(verify_jira_signature(&config, "acme", true, &HeaderMap::new(), "{}").is_ok());
+    }
+
+    #[test]
+    fn test_verify_accepts_any_unscoped_secret_on_unsited_route() {
+        let config = config_with_secrets(&["shared-a", "shared-b"], &[]);
+
+        for secret in ["shared-a", "shared-b"] {
+            assert!(
+                verify_jira_signature(&config, "", false, &signed_headers(secret, BODY), BODY)
+                    .is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_accepts_site_secret() {
+        let config = config_with_secrets(&[], &["acme=acme-secret"]);
+
+        let headers = signed_headers("acme-secret", BODY);
+        assert!(verify_jira_signature(&config, "acme", true, &headers, BODY).is_ok());
+        assert!(verify_jira_signature(&config, "acme", false, &headers, BODY).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_other_sites_secret() {
+        let config = config_with_secrets(&[], &["acme=acme-secret", "dc=dc-secret"]);
+        let headers = signed_headers("acme-secret", BODY);
+
+        // Via the site path and via payload detection alike
+        for site_in_path in [true, false] {
+            let err =
+                verify_jira_signature(&config, "dc", site_in_path, &headers, BODY).unwrap_err();
+            assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_unscoped_secret_on_site_path() {
+        let config = config_with_secrets(&["shared"], &[]);
+        let headers = signed_headers("shared", BODY);
+
+        let err = verify_jira_signature(&config, "acme", true, &headers, BODY).unwrap_err();
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[test]
+    fn test_verify_rejects_unscoped_secret_for_site_with_own_secret() {
+        let config = config_with_secrets(&["shared"], &["acme=acme-secret"]);
+        let headers = signed_headers("shared", BODY);
+
+        assert!(verify_jira_signature(&config, "acme", false, &headers, BODY).is_err());
+        assert!(verify_jira_signature(&config, "", false, &headers, BODY).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_unknown_secret() {
+        let config = config_with_secrets(&["site-a"], &[]);
+
+        let err = verify_jira_signature(&config, "", false, &signed_headers("other", BODY), BODY)
+            .unwrap_err();
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_body() {
+        let config = config_with_secrets(&["site-a"], &[]);
+        let headers = signed_headers("site-a", BODY);
+
+        let err = verify_jira_signature(
+            &config,
+            "",
+            false,
+            &headers,
+            r#"{"webhookEvent":"jira:issue_deleted"}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.0, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_verify_rejects_missing_signature() {
+        let config = config_with_secrets(&["site-a"], &[]);
+
+        let err = verify_jira_signature(&config, "", false, &HeaderMap::new(), "{}").unwrap_err();
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Missing signature"));
+    }
 }
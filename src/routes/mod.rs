@@ -13,6 +13,7 @@ pub use zoom::handle_zoom_event;
 use crate::config::Config;
 use crate::db::Database;
 use crate::router::{GitHubRouter, JiraRouter, SlackRouter, ZoomRouter};
+use crate::zoom::ZoomReplayGuard;
 use axum::{
     extract::State,
     http::HeaderMap,
@@ -28,6 +29,8 @@ pub struct AppState {
     pub zoom_router: Arc<ZoomRouter>,
     pub config: Arc<Config>,
     pub db: Arc<Database>,
+    /// Recently seen Zoom signatures, for rejecting replayed deliveries
+    pub zoom_replay_guard: ZoomReplayGuard,
 }
 
 /// Extract headers that should be forwarded to n8n, filtering by allowed prefixes.
@@ -49,6 +52,48 @@ pub fn extract_forwarded_headers(headers: &HeaderMap, allowed_prefixes: &[&str])
     forwarded
 }
 
+/// Extract the n8n webhook ID from a webhook URL registered through a
+/// provider mock (GitHub `config.url`, Jira `url`).
+///
+/// n8n webhook URLs follow the pattern:
+///   `http://<host>/<endpoint>/<webhookId>/webhook`
+///
+/// For example:
+///   `http://n8n:5678/webhook/abc123-def/webhook`  →  `"abc123-def"`
+///   `http://n8n:5678/webhook-test/abc123-def/webhook`  →  `"abc123-def"`
+///
+/// The webhook ID is always the second-to-last path segment.
+pub fn extract_webhook_id_from_url(url: &str) -> Option<String> {
+    // Strip query string if present
+    let path_part = url.split('?').next().unwrap_or(url);
+    let segments: Vec<&str> = path_part.trim_end_matches('/').rsplit('/').collect();
+
+    // We need at least: ["webhook", "<webhookId>", "<endpoint>", ...]
+    if segments.len() >= 2 {
+        let id = segments[1]; // second-to-last segment
+        if !id.is_empty() {
+            return Some(id.to_string());
+        }
+    }
+    None
+}
+
+/// Build the base URL (`scheme://host`) callers used to reach us, so the
+/// links provider mocks hand back point at Unihook rather than a placeholder.
+pub fn request_base_url(headers: &HeaderMap) -> String {
+    let host = headers
+        .get("host")
+        .and_then(|v| v.to_str().ok())
+        .filter(|h| !h.is_empty())
+        .unwrap_or("localhost");
+    let scheme = headers
+        .get("x-forwarded-proto")
+        .and_then(|v| v.to_str().ok())
+        .filter(|p| *p == "http" || *p == "https")
+        .unwrap_or("http");
+    format!("{scheme}://{host}")
+}
+
 /// Health check endpoint
 pub async fn health_check(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let slack_trigger_count = state.slack_router.trigger_count();
@@ -143,4 +188,42 @@ mod tests {
 
         assert_eq!(forwarded.len(), 0);
     }
+
+    // ── extract_webhook_id_from_url tests ───────────────────────────────
+
+    #[test]
+    fn test_extract_webhook_id_standard_url() {
+        let id = extract_webhook_id_from_url("http://n8n:5678/webhook/abc123-def456/webhook");
+        assert_eq!(id.as_deref(), Some("abc123-def456"));
+    }
+
+    #[test]
+    fn test_extract_webhook_id_test_endpoint() {
+        let id = extract_webhook_id_from_url("http://n8n:5678/webhook-test/abc123-def456/webhook");
+        assert_eq!(id.as_deref(), Some("abc123-def456"));
+    }
+
+    #[test]
+    fn test_extract_webhook_id_with_trailing_slash() {
+        let id = extract_webhook_id_from_url("http://n8n:5678/webhook/abc123/webhook/");
+        assert_eq!(id.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn test_extract_webhook_id_with_query_string() {
+        let id = extract_webhook_id_from_url("http://n8n:5678/webhook/abc123/webhook?token=xyz");
+        assert_eq!(id.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn test_extract_webhook_id_empty_url() {
+        let id = extract_webhook_id_from_url("");
+        assert!(id.is_none());
+    }
+
+    #[test]
+    fn test_extract_webhook_id_no_path() {
+        let id = extract_webhook_id_from_url("http://n8n:5678");
+        assert!(id.is_none());
+    }
 }
@@ -1,47 +1,221 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::{IntoResponse, Json},
+    extract::{FromRequestParts, Path, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header::AUTHORIZATION, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
 };
+use std::convert::Infallible;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use super::AppState;
+use super::{AppState, extract_webhook_id_from_url, request_base_url};
+use crate::crypto::credentials_match;
+use crate::db::{GitHubHookRegistration, GitHubHookRow};
+use crate::github::{GHES_API_PREFIX, GHES_VERSION, GHES_VERSION_HEADER};
 
-/// Extract the n8n webhook ID from a `config.url` value.
+/// Which GitHub API a mock request came in through: github.com's (served at
+/// the root) or GitHub Enterprise Server's (served under `/api/v3`, where
+/// n8n sends requests when the credential's server URL ends in `/api/v3`).
 ///
-/// n8n webhook URLs follow the pattern:
-///   `http://<host>/<endpoint>/<webhookId>/webhook`
+/// Hooks are scoped by API, so a GHES repository and a github.com
+/// repository with the same owner/name are kept apart.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GitHubApi {
+    pub enterprise: bool,
+}
+
+impl GitHubApi {
+    /// Base URL for API links in responses, including `/api/v3` for GHES.
+    fn base_url(&self, headers: &HeaderMap) -> String {
+        let base = request_base_url(headers);
+        if self.enterprise {
+            format!("{base}{GHES_API_PREFIX}")
+        } else {
+            base
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for GitHubApi {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let path = parts.uri.path();
+        Ok(Self {
+            enterprise: path
+                .strip_prefix(GHES_API_PREFIX)
+                .is_some_and(|rest| rest.starts_with('/')),
+        })
+    }
+}
+
+/// Render a stored hook in GitHub's repository webhook response shape.
 ///
-/// For example:
-///   `http://n8n:5678/webhook/abc123-def/webhook`  →  `"abc123-def"`
-///   `http://n8n:5678/webhook-test/abc123-def/webhook`  →  `"abc123-def"`
+/// Like GitHub, the secret itself is never echoed back, only masked.
+fn hook_json(hook: &GitHubHookRow, base_url: &str) -> serde_json::Value {
+    let hook_url = format!(
+        "{}/repos/{}/{}/hooks/{}",
+        base_url, hook.owner, hook.repository, hook.id
+    );
+    let mut config = serde_json::json!({
+        "url": hook.config_url,
+        "content_type": hook.content_type,
+        "insecure_ssl": "0"
+    });
+    if hook.secret.as_deref().is_some_and(|s| !s.is_empty()) {
+        config["secret"] = serde_json::json!("********");
+    }
+    serde_json::json!({
+        "type": "Repository",
+        "id": hook.id,
+        "name": hook.name,
+        "active": hook.active,
+        "events": hook.events,
+        "config": config,
+        "updated_at": github_timestamp(&hook.updated_at),
+        "created_at": github_timestamp(&hook.created_at),
+        "url": hook_url,
+        "test_url": format!("{hook_url}/tests"),
+        "ping_url": format!("{hook_url}/pings"),
+        "deliveries_url": format!("{hook_url}/deliveries"),
+        "last_response": { "code": null, "status": "unused", "message": null }
+    })
+}
+
+/// Convert SQLite's `YYYY-MM-DD HH:MM:SS` (UTC) to GitHub's ISO 8601 form.
+fn github_timestamp(sqlite_datetime: &str) -> String {
+    format!("{}Z", sqlite_datetime.replacen(' ', "T", 1))
+}
+
+/// GitHub's `404 Not Found` error body.
+fn not_found() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(serde_json::json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })),
+    )
+        .into_response()
+}
+
+/// Check a mock request's `Authorization` header against the configured
+/// tokens, returning GitHub's `401` message if it doesn't carry one.
 ///
-/// The webhook ID is always the second-to-last path segment.
-fn extract_webhook_id_from_url(url: &str) -> Option<String> {
-    // Strip query string if present
-    let path_part = url.split('?').next().unwrap_or(url);
-    let segments: Vec<&str> = path_part.trim_end_matches('/').rsplit('/').collect();
-
-    // We need at least: ["webhook", "<webhookId>", "<endpoint>", ...]
-    if segments.len() >= 2 {
-        let id = segments[1]; // second-to-last segment
-        if !id.is_empty() {
-            return Some(id.to_string());
-        }
+/// n8n's GitHub API credential sends `token <access token>`; OAuth2
+/// credentials send `Bearer <token>`.
+fn check_token(tokens: &[String], headers: &HeaderMap) -> Result<(), &'static str> {
+    let presented = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split_once(' '))
+        .filter(|(scheme, _)| {
+            scheme.eq_ignore_ascii_case("token") || scheme.eq_ignore_ascii_case("bearer")
+        })
+        .map(|(_, token)| token.trim());
+    match presented {
+        None => Err("Requires authentication"),
+        Some(token) if tokens.iter().any(|t| credentials_match(token, t)) => Ok(()),
+        Some(_) => Err("Bad credentials"),
     }
-    None
+}
+
+/// Look up a hook for a repository-scoped request, mapping DB errors to `None`.
+fn find_hook(
+    state: &AppState,
+    api: &GitHubApi,
+    owner: &str,
+    repo: &str,
+    hook_id: i64,
+) -> Option<GitHubHookRow> {
+    state
+        .db
+        .get_github_hook(api.enterprise, owner, repo, hook_id)
+        .unwrap_or_else(|e| {
+            warn!(error = %e, hook_id = hook_id, "GitHub mock: failed to load hook");
+            None
+        })
+}
+
+/// Reconcile GitHub triggers with n8n in the background after a hook change,
+/// so the registration's trigger row picks up the workflow name and
+/// `unihook.*` annotations without waiting for the periodic sync. Skipped
+/// when `N8N_TRIGGER_RECONCILE` is off.
+fn spawn_trigger_refresh(state: &AppState) {
+    if !state.config.n8n_trigger_reconcile {
+        return;
+    }
+    let github_router = state.github_router.clone();
+    tokio::spawn(async move {
+        if let Err(e) = github_router.refresh_triggers().await {
+            warn!(error = %e, "GitHub mock: failed to refresh triggers after hook change");
+        }
+    });
+}
+
+/// Read a JSON array of strings (e.g. `events`), if present.
+fn string_list(value: Option<&serde_json::Value>) -> Option<Vec<String>> {
+    value.and_then(|v| v.as_array()).map(|arr| {
+        arr.iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect()
+    })
+}
+
+/// Minimal `repository` object for mock-generated payloads.
+fn repository_json(hook: &GitHubHookRow) -> serde_json::Value {
+    serde_json::json!({
+        "name": hook.repository,
+        "full_name": format!("{}/{}", hook.owner, hook.repository),
+        "owner": { "login": hook.owner }
+    })
 }
 
 // ── GitHub API mock endpoints ───────────────────────────────────────────
 
-/// `GET /repos/:owner/:repo/hooks` — n8n calls this to check for existing hooks.
+/// `GET /repos/:owner/:repo/hooks` — list the hooks registered on a repository.
 ///
-/// We always return an empty array, telling n8n there are no existing webhooks
-/// so it proceeds to create a new one via POST.
-pub async fn list_hooks(Path((_owner, _repo)): Path<(String, String)>) -> impl IntoResponse {
-    debug!("GitHub mock: GET /repos/{_owner}/{_repo}/hooks -> []");
-    Json(serde_json::json!([]))
+/// n8n's `checkExists` and its "hook already exists" recovery both look here,
+/// so registrations persist across calls.
+pub async fn list_hooks(
+    State(state): State<Arc<AppState>>,
+    api: GitHubApi,
+    Path((owner, repo)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let base_url = api.base_url(&headers);
+    let hooks = state
+        .db
+        .list_github_hooks(api.enterprise, &owner, &repo)
+        .unwrap_or_else(|e| {
+            warn!(error = %e, "GitHub mock: failed to list hooks");
+            Vec::new()
+        });
+    debug!(
+        owner = %owner,
+        repo = %repo,
+        count = hooks.len(),
+        "GitHub mock: GET /repos/{owner}/{repo}/hooks"
+    );
+    Json(serde_json::Value::Array(
+        hooks.iter().map(|h| hook_json(h, &base_url)).collect(),
+    ))
+}
+
+/// `GET /repos/:owner/:repo/hooks/:hook_id` — fetch a single hook.
+///
+/// n8n's GitHub Trigger checks whether its stored hook ID still exists here
+/// before deciding to register a new one.
+pub async fn get_hook(
+    State(state): State<Arc<AppState>>,
+    api: GitHubApi,
+    Path((owner, repo, hook_id)): Path<(String, String, i64)>,
+    headers: HeaderMap,
+) -> Response {
+    match find_hook(&state, &api, &owner, &repo, hook_id) {
+        Some(hook) => Json(hook_json(&hook, &api.base_url(&headers))).into_response(),
+        None => not_found(),
+    }
 }
 
 /// `POST /repos/:owner/:repo/hooks` — n8n calls this to register a webhook.
@@ -60,18 +234,19 @@ pub async fn list_hooks(Path((_owner, _repo)): Path<(String, String)>) -> impl I
 /// }
 /// ```
 ///
-/// We extract the `webhook_id` from `config.url` and the `secret` from
-/// `config.secret`, store them in the database, and return a valid GitHub
-/// hook object so n8n considers the registration successful.
+/// We extract the `webhook_id` from `config.url`, store the hook and its
+/// `config.secret` in the database, and return the hook in GitHub's shape so
+/// n8n considers the registration successful.
 pub async fn create_hook(
     State(state): State<Arc<AppState>>,
+    api: GitHubApi,
     Path((owner, repo)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(body): Json<serde_json::Value>,
-) -> impl IntoResponse {
+) -> Response {
     let config = body.get("config").cloned().unwrap_or(serde_json::json!({}));
     let url = config.get("url").and_then(|v| v.as_str()).unwrap_or("");
     let secret = config.get("secret").and_then(|v| v.as_str()).unwrap_or("");
-    let events = body.get("events").cloned().unwrap_or(serde_json::json!([]));
 
     let webhook_id = match extract_webhook_id_from_url(url) {
         Some(id) => id,
@@ -85,65 +260,239 @@ pub async fn create_hook(
         }
     };
 
-    // Store the secret in the database
-    let hook_id = match state
-        .db
-        .upsert_webhook_secret(&webhook_id, "github", secret)
-    {
-        Ok(id) => id,
+    // GHES deliveries only route to hooks registered for their host
+    let enterprise_host = if api.enterprise {
+        let host = state.config.github_enterprise_host();
+        if host.is_none() {
+            warn!(
+                owner = %owner,
+                repo = %repo,
+                "GitHub mock: GHES hook registered without GITHUB_ENTERPRISE_HOST; \
+                 it will receive no deliveries"
+            );
+        }
+        host.unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    let registration = GitHubHookRegistration {
+        owner: owner.clone(),
+        repository: repo.clone(),
+        webhook_id: webhook_id.clone(),
+        name: body
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("web")
+            .to_string(),
+        // GitHub subscribes new hooks to `push` when no events are given
+        events: string_list(body.get("events")).unwrap_or_else(|| vec!["push".to_string()]),
+        active: body.get("active").and_then(|v| v.as_bool()).unwrap_or(true),
+        config_url: url.to_string(),
+        content_type: config
+            .get("content_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("json")
+            .to_string(),
+        secret: Some(secret.to_string()),
+        production: !state.config.is_test_webhook_url(url),
+        enterprise: api.enterprise,
+        enterprise_host,
+    };
+
+    let hook = match state.db.upsert_github_hook(&registration) {
+        Ok(hook) => hook,
         Err(e) => {
-            warn!(error = %e, "GitHub mock: failed to store webhook secret");
-            // Return a fake ID so n8n doesn't break
-            1
+            warn!(error = %e, "GitHub mock: failed to store hook registration");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "message": "Failed to store webhook" })),
+            )
+                .into_response();
         }
     };
 
     info!(
         webhook_id = %webhook_id,
-        hook_id = hook_id,
+        hook_id = hook.id,
         owner = %owner,
         repo = %repo,
+        enterprise = api.enterprise,
         has_secret = !secret.is_empty(),
         "GitHub mock: captured webhook registration"
     );
 
-    // Trigger an immediate sync so the github_triggers table is populated
-    // right away — otherwise events arriving before the next periodic refresh
-    // would find no matching trigger rows.
-    let github_router = state.github_router.clone();
-    tokio::spawn(async move {
-        if let Err(e) = github_router.refresh_triggers().await {
-            warn!(error = %e, "GitHub mock: failed to refresh triggers after webhook registration");
-        }
-    });
+    // The registration already created the trigger row; reconcile it with
+    // n8n right away to pick up annotations such as `unihook.actions`
+    spawn_trigger_refresh(&state);
 
     (
         StatusCode::CREATED,
-        Json(serde_json::json!({
-            "id": hook_id,
-            "name": "web",
-            "active": true,
-            "events": events,
-            "config": {
-                "url": url,
-                "content_type": "json"
-            },
-            "updated_at": "2024-01-01T00:00:00Z",
-            "created_at": "2024-01-01T00:00:00Z"
-        })),
+        Json(hook_json(&hook, &api.base_url(&headers))),
     )
+        .into_response()
+}
+
+/// `PATCH /repos/:owner/:repo/hooks/:hook_id` — update a hook.
+///
+/// Supports GitHub's fields: `config` (merged into the existing config),
+/// `events`, `add_events`, `remove_events`, and `active`.
+pub async fn update_hook(
+    State(state): State<Arc<AppState>>,
+    api: GitHubApi,
+    Path((owner, repo, hook_id)): Path<(String, String, i64)>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
+) -> Response {
+    let Some(existing) = find_hook(&state, &api, &owner, &repo, hook_id) else {
+        return not_found();
+    };
+
+    let config = body.get("config");
+    let config_str = |key: &str| config.and_then(|c| c.get(key)).and_then(|v| v.as_str());
+    let config_url = config_str("url")
+        .unwrap_or(&existing.config_url)
+        .to_string();
+    let webhook_id = if config_url == existing.config_url {
+        existing.webhook_id.clone()
+    } else {
+        extract_webhook_id_from_url(&config_url).unwrap_or(existing.webhook_id.clone())
+    };
+
+    let mut events = string_list(body.get("events")).unwrap_or(existing.events.clone());
+    for event in string_list(body.get("add_events")).unwrap_or_default() {
+        if !events.contains(&event) {
+            events.push(event);
+        }
+    }
+    let removed = string_list(body.get("remove_events")).unwrap_or_default();
+    events.retain(|e| !removed.contains(e));
+
+    let registration = GitHubHookRegistration {
+        owner: existing.owner.clone(),
+        repository: existing.repository.clone(),
+        webhook_id,
+        name: existing.name.clone(),
+        events,
+        active: body
+            .get("active")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(existing.active),
+        content_type: config_str("content_type")
+            .unwrap_or(&existing.content_type)
+            .to_string(),
+        secret: config_str("secret").map(str::to_string),
+        production: !state.config.is_test_webhook_url(&config_url),
+        config_url,
+        enterprise: existing.enterprise,
+        enterprise_host: existing.enterprise_host.clone(),
+    };
+
+    match state.db.update_github_hook(hook_id, &registration) {
+        Ok(Some(hook)) => {
+            info!(hook_id = hook_id, "GitHub mock: updated hook");
+            spawn_trigger_refresh(&state);
+            Json(hook_json(&hook, &api.base_url(&headers))).into_response()
+        }
+        Ok(None) => not_found(),
+        Err(e) => {
+            warn!(error = %e, hook_id = hook_id, "GitHub mock: failed to update hook");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({ "message": "Failed to update webhook" })),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// `POST /repos/:owner/:repo/hooks/:hook_id/pings` — send a `ping` event to
+/// the hook, as GitHub does on request. Returns 204.
+pub async fn ping_hook(
+    State(state): State<Arc<AppState>>,
+    api: GitHubApi,
+    Path((owner, repo, hook_id)): Path<(String, String, i64)>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(hook) = find_hook(&state, &api, &owner, &repo, hook_id) else {
+        return not_found();
+    };
+
+    let payload = serde_json::json!({
+        "zen": "Keep it logically awesome.",
+        "hook_id": hook.id,
+        "hook": hook_json(&hook, &api.base_url(&headers)),
+        "repository": repository_json(&hook),
+        "sender": { "login": "unihook-mock" }
+    });
+    let github_router = state.github_router.clone();
+    tokio::spawn(async move {
+        github_router
+            .deliver_hook_event(&hook, "ping", payload.to_string())
+            .await;
+    });
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+/// `POST /repos/:owner/:repo/hooks/:hook_id/tests` — trigger a test `push`.
+///
+/// GitHub re-sends the latest push to the hook if it subscribes to `push`,
+/// and otherwise does nothing; both return 204. The mock has no push history,
+/// so it sends a synthetic push to the default branch.
+pub async fn test_hook(
+    State(state): State<Arc<AppState>>,
+    api: GitHubApi,
+    Path((owner, repo, hook_id)): Path<(String, String, i64)>,
+) -> Response {
+    let Some(hook) = find_hook(&state, &api, &owner, &repo, hook_id) else {
+        return not_found();
+    };
+
+    if hook.active && hook.events.iter().any(|e| e == "push" || e == "*") {
+        let payload = serde_json::json!({
+            "ref": "refs/heads/main",
+            "before": "0000000000000000000000000000000000000000",
+            "after": "0000000000000000000000000000000000000000",
+            "created": false,
+            "deleted": false,
+            "forced": false,
+            "commits": [],
+            "head_commit": null,
+            "repository": repository_json(&hook),
+            "pusher": { "name": "unihook-mock" },
+            "sender": { "login": "unihook-mock" }
+        });
+        let github_router = state.github_router.clone();
+        tokio::spawn(async move {
+            github_router
+                .deliver_hook_event(&hook, "push", payload.to_string())
+                .await;
+        });
+    } else {
+        debug!(
+            hook_id = hook_id,
+            "GitHub mock: hook test skipped (hook inactive or not subscribed to push)"
+        );
+    }
+
+    StatusCode::NO_CONTENT.into_response()
 }
 
 /// `DELETE /repos/:owner/:repo/hooks/:hook_id` — n8n calls this to deregister.
 ///
-/// We remove the corresponding secret from the database and return 204.
+/// We remove the hook and its secret from the database and return 204.
 pub async fn delete_hook(
     State(state): State<Arc<AppState>>,
     Path((_owner, _repo, hook_id)): Path<(String, String, i64)>,
 ) -> impl IntoResponse {
-    match state.db.delete_webhook_secret_by_id(hook_id) {
+    match state.db.delete_github_hook(hook_id) {
         Ok(true) => {
-            info!(hook_id = hook_id, "GitHub mock: deleted webhook secret");
+            info!(
+                hook_id = hook_id,
+                "GitHub mock: deleted hook and webhook secret"
+            );
+            spawn_trigger_refresh(&state);
         }
         Ok(false) => {
             debug!(
@@ -152,7 +501,7 @@ pub async fn delete_hook(
             );
         }
         Err(e) => {
-            warn!(error = %e, hook_id = hook_id, "GitHub mock: failed to delete webhook secret");
+            warn!(error = %e, hook_id = hook_id, "GitHub mock: failed to delete hook");
         }
     }
     StatusCode::NO_CONTENT
@@ -171,6 +520,57 @@ pub async fn get_user() -> impl IntoResponse {
     }))
 }
 
+/// `GET /api/v3/meta` — GitHub Enterprise Server instance metadata.
+pub async fn get_enterprise_meta() -> impl IntoResponse {
+    debug!("GitHub mock: GET /api/v3/meta");
+    Json(serde_json::json!({
+        "verifiable_password_authentication": false,
+        "installed_version": GHES_VERSION
+    }))
+}
+
+/// Response middleware for the `/api/v3` routes: GitHub Enterprise Server
+/// reports its version on every API response.
+pub async fn add_enterprise_headers(mut response: Response) -> Response {
+    response
+        .headers_mut()
+        .insert(GHES_VERSION_HEADER, HeaderValue::from_static(GHES_VERSION));
+    response
+}
+
+/// Request middleware for the GitHub mock routes: when `GITHUB_MOCK_TOKEN`
+/// is set, only callers presenting one of its tokens (i.e. n8n's GitHub
+/// credential) reach the mock. Others get GitHub's `401` response, so they
+/// can't register hooks that overwrite a workflow's re-signing secret.
+pub async fn authenticate(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let tokens = &state.config.github_mock_token;
+    if tokens.is_empty() {
+        return next.run(request).await;
+    }
+    match check_token(tokens, request.headers()) {
+        Ok(()) => next.run(request).await,
+        Err(message) => {
+            warn!(
+                method = %request.method(),
+                path = %request.uri().path(),
+                reason = message,
+                "GitHub mock: rejected unauthenticated request"
+            );
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({
+                    "message": message,
+                    "documentation_url": "https://docs.github.com/rest"
+                })),
+            )
+                .into_response()
+        }
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,6 +578,7 @@ mod tests {
     use crate::db::Database;
     use crate::n8n::N8nClient;
     use crate::router::{GitHubRouter, JiraRouter, SlackRouter, ZoomRouter};
+    use crate::zoom::ZoomReplayGuard;
     use axum::response::IntoResponse;
 
     /// Build a test `AppState` backed by an in-memory SQLite database.
@@ -185,8 +586,12 @@ mod tests {
     /// Returns the shared state and a handle to the database so tests can
     /// verify that handlers wrote the expected data.
     fn test_state() -> (Arc<AppState>, Arc<Database>) {
+        test_state_with_config(Config::test_default())
+    }
+
+    fn test_state_with_config(config: Config) -> (Arc<AppState>, Arc<Database>) {
         let db = Arc::new(Database::open(":memory:").unwrap());
-        let config = Arc::new(Config::test_default());
+        let config = Arc::new(config);
         let n8n_client = Arc::new(N8nClient::new(config.clone()));
         let slack_router = Arc::new(SlackRouter::new(
             config.clone(),
@@ -214,6 +619,7 @@ mod tests {
             jira_router,
             github_router,
             zoom_router,
+            zoom_replay_guard: ZoomReplayGuard::new(config.zoom_replay_window_secs),
             config,
             db: db.clone(),
         });
@@ -221,44 +627,6 @@ mod tests {
         (state, db)
     }
 
-    // ── extract_webhook_id_from_url tests ───────────────────────────────
-
-    #[test]
-    fn test_extract_webhook_id_standard_url() {
-        let id = extract_webhook_id_from_url("http://n8n:5678/webhook/abc123-def456/webhook");
-        assert_eq!(id.as_deref(), Some("abc123-def456"));
-    }
-
-    #[test]
-    fn test_extract_webhook_id_test_endpoint() {
-        let id = extract_webhook_id_from_url("http://n8n:5678/webhook-test/abc123-def456/webhook");
-        assert_eq!(id.as_deref(), Some("abc123-def456"));
-    }
-
-    #[test]
-    fn test_extract_webhook_id_with_trailing_slash() {
-        let id = extract_webhook_id_from_url("http://n8n:5678/webhook/abc123/webhook/");
-        assert_eq!(id.as_deref(), Some("abc123"));
-    }
-
-    #[test]
-    fn test_extract_webhook_id_with_query_string() {
-        let id = extract_webhook_id_from_url("http://n8n:5678/webhook/abc123/webhook?token=xyz");
-        assert_eq!(id.as_deref(), Some("abc123"));
-    }
-
-    #[test]
-    fn test_extract_webhook_id_empty_url() {
-        let id = extract_webhook_id_from_url("");
-        assert!(id.is_none());
-    }
-
-    #[test]
-    fn test_extract_webhook_id_no_path() {
-        let id = extract_webhook_id_from_url("http://n8n:5678");
-        assert!(id.is_none());
-    }
-
     // ── create_hook handler tests ───────────────────────────────────────
 
     #[tokio::test]
@@ -278,7 +646,9 @@ mod tests {
 
         let response = create_hook(
             State(state),
+            GitHubApi::default(),
             Path(("test-owner".into(), "test-repo".into())),
+            HeaderMap::new(),
             Json(body),
         )
         .await
@@ -304,7 +674,9 @@ mod tests {
 
         create_hook(
             State(state.clone()),
+            GitHubApi::default(),
             Path(("o".into(), "r".into())),
+            HeaderMap::new(),
             Json(body1),
         )
         .await;
@@ -316,7 +688,14 @@ mod tests {
             }
         });
 
-        create_hook(State(state), Path(("o".into(), "r".into())), Json(body2)).await;
+        create_hook(
+            State(state),
+            GitHubApi::default(),
+            Path(("o".into(), "r".into())),
+            HeaderMap::new(),
+            Json(body2),
+        )
+        .await;
 
         // The secret should have been updated
         let secret = db.get_webhook_secret("wh-upsert").unwrap();
@@ -336,7 +715,9 @@ mod tests {
 
         let response = create_hook(
             State(state),
+            GitHubApi::default(),
             Path(("the-owner".into(), "the-repo".into())),
+            HeaderMap::new(),
             Json(body),
         )
         .await
@@ -359,9 +740,15 @@ mod tests {
             }
         });
 
-        let response = create_hook(State(state), Path(("o".into(), "r".into())), Json(body))
-            .await
-            .into_response();
+        let response = create_hook(
+            State(state),
+            GitHubApi::default(),
+            Path(("o".into(), "r".into())),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await
+        .into_response();
 
         assert_eq!(response.status(), StatusCode::CREATED);
 
@@ -405,11 +792,311 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_hooks_returns_200() {
-        let response = list_hooks(Path(("owner".into(), "repo".into())))
+        let (state, _db) = test_state();
+        let response = list_hooks(
+            State(state),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into())),
+            HeaderMap::new(),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    // ── hook registry tests ─────────────────────────────────────────────
+
+    async fn response_json(response: Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
-            .into_response();
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    fn host_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("host", "unihook:3000".parse().unwrap());
+        headers
+    }
+
+    /// Register a hook through the handler and return its JSON.
+    async fn register(state: &Arc<AppState>, webhook_id: &str) -> serde_json::Value {
+        let body = serde_json::json!({
+            "name": "web",
+            "config": {
+                "url": format!("http://n8n:5678/webhook/{webhook_id}/webhook"),
+                "content_type": "json",
+                "secret": "s3cret"
+            },
+            "events": ["pull_request"],
+            "active": true
+        });
+        let response = create_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into())),
+            host_headers(),
+            Json(body),
+        )
+        .await;
+        response_json(response).await
+    }
+
+    #[tokio::test]
+    async fn test_create_hook_returns_github_shape() {
+        let (state, _db) = test_state();
+
+        let hook = register(&state, "wh-shape").await;
+
+        assert_eq!(hook["type"], "Repository");
+        assert_eq!(hook["events"], serde_json::json!(["pull_request"]));
+        assert_eq!(hook["config"]["secret"], "********");
+        let id = hook["id"].as_i64().unwrap();
+        assert_eq!(
+            hook["url"],
+            format!("http://unihook:3000/repos/owner/repo/hooks/{id}")
+        );
+        assert!(hook["created_at"].as_str().unwrap().ends_with('Z'));
+    }
+
+    #[tokio::test]
+    async fn test_create_hook_registers_trigger() {
+        let (state, db) = test_state();
+        register(&state, "wh-trigger").await;
+
+        let rows = db
+            .query_github_triggers(Some("owner"), Some("repo"))
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "wh-trigger");
+        assert!(rows[0].workflow_active);
+        assert_eq!(rows[0].events, vec!["pull_request"]);
+        assert_eq!(rows[0].secret.as_deref(), Some("s3cret"));
+    }
+
+    #[tokio::test]
+    async fn test_github_api_detects_enterprise_prefix() {
+        for (uri, enterprise) in [
+            ("/repos/o/r/hooks", false),
+            ("/api/v3/repos/o/r/hooks", true),
+            ("/api/v3/user", true),
+            ("/api/v30/user", false),
+        ] {
+            let (mut parts, _) = axum::http::Request::builder()
+                .uri(uri)
+                .body(())
+                .unwrap()
+                .into_parts();
+            let api = GitHubApi::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+            assert_eq!(api.enterprise, enterprise, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enterprise_hooks_are_separate_from_github_com() {
+        let (state, db) = test_state_with_config(Config {
+            github_enterprise_host: Some(" GHE.example.com ".to_string()),
+            ..Config::test_default()
+        });
+        let body = serde_json::json!({
+            "config": { "url": "http://n8n:5678/webhook/wh-ghe/webhook", "secret": "s" },
+            "events": ["push"]
+        });
+        let ghes = GitHubApi { enterprise: true };
+        let response = create_hook(
+            State(state.clone()),
+            ghes,
+            Path(("owner".into(), "repo".into())),
+            host_headers(),
+            Json(body),
+        )
+        .await;
+        let hook = response_json(response).await;
+        let id = hook["id"].as_i64().unwrap();
+        assert_eq!(
+            hook["url"],
+            format!("http://unihook:3000/api/v3/repos/owner/repo/hooks/{id}")
+        );
+
+        // Not visible through the github.com API
+        let dotcom = get_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), id)),
+            HeaderMap::new(),
+        )
+        .await;
+        assert_eq!(dotcom.status(), StatusCode::NOT_FOUND);
+        let found = get_hook(
+            State(state.clone()),
+            ghes,
+            Path(("owner".into(), "repo".into(), id)),
+            HeaderMap::new(),
+        )
+        .await;
+        assert_eq!(found.status(), StatusCode::OK);
+
+        let rows = db
+            .query_github_triggers(Some("owner"), Some("repo"))
+            .unwrap();
+        assert!(rows[0].enterprise);
+        assert_eq!(rows[0].enterprise_host, "ghe.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_enterprise_responses_report_version() {
+        let response = add_enterprise_headers(StatusCode::NO_CONTENT.into_response()).await;
+        assert_eq!(response.headers()[GHES_VERSION_HEADER], GHES_VERSION);
+    }
 
+    #[tokio::test]
+    async fn test_list_hooks_returns_registrations() {
+        let (state, _db) = test_state();
+        let hook = register(&state, "wh-list").await;
+
+        let response = list_hooks(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("Owner".into(), "repo".into())),
+            HeaderMap::new(),
+        )
+        .await
+        .into_response();
+        let hooks = response_json(response).await;
+
+        assert_eq!(hooks.as_array().unwrap().len(), 1);
+        assert_eq!(hooks[0]["id"], hook["id"]);
+        assert_eq!(
+            hooks[0]["config"]["url"],
+            "http://n8n:5678/webhook/wh-list/webhook"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_hook_by_id() {
+        let (state, _db) = test_state();
+        let id = register(&state, "wh-get").await["id"].as_i64().unwrap();
+
+        let response = get_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), id)),
+            HeaderMap::new(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let missing = get_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "other-repo".into(), id)),
+            HeaderMap::new(),
+        )
+        .await;
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response_json(missing).await["message"], "Not Found");
+    }
+
+    #[tokio::test]
+    async fn test_update_hook_events_and_active() {
+        let (state, db) = test_state();
+        let id = register(&state, "wh-patch").await["id"].as_i64().unwrap();
+
+        let response = update_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), id)),
+            HeaderMap::new(),
+            Json(serde_json::json!({
+                "add_events": ["issues", "push"],
+                "remove_events": ["pull_request"],
+                "active": false,
+                "config": { "secret": "rotated" }
+            })),
+        )
+        .await;
         assert_eq!(response.status(), StatusCode::OK);
+
+        let hook = response_json(response).await;
+        assert_eq!(hook["events"], serde_json::json!(["issues", "push"]));
+        assert_eq!(hook["active"], false);
+        assert_eq!(
+            hook["config"]["url"],
+            "http://n8n:5678/webhook/wh-patch/webhook"
+        );
+        assert_eq!(
+            db.get_webhook_secret("wh-patch").unwrap().as_deref(),
+            Some("rotated")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_hook_returns_404() {
+        let (state, _db) = test_state();
+
+        let response = update_hook(
+            State(state),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), 404)),
+            HeaderMap::new(),
+            Json(serde_json::json!({ "active": false })),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_ping_and_test_hook() {
+        let (state, _db) = test_state();
+        let id = register(&state, "wh-ping").await["id"].as_i64().unwrap();
+
+        let ping = ping_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), id)),
+            HeaderMap::new(),
+        )
+        .await;
+        assert_eq!(ping.status(), StatusCode::NO_CONTENT);
+
+        let test = test_hook(
+            State(state.clone()),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), id)),
+        )
+        .await;
+        assert_eq!(test.status(), StatusCode::NO_CONTENT);
+
+        let missing = ping_hook(
+            State(state),
+            GitHubApi::default(),
+            Path(("owner".into(), "repo".into(), id + 1)),
+            HeaderMap::new(),
+        )
+        .await;
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_delete_hook_removes_registration() {
+        let (state, db) = test_state();
+        let id = register(&state, "wh-gone").await["id"].as_i64().unwrap();
+
+        let response = delete_hook(State(state), Path(("owner".into(), "repo".into(), id)))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(
+            db.list_github_hooks(false, "owner", "repo")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(db.get_webhook_secret("wh-gone").unwrap().is_none());
     }
 
     #[tokio::test]
@@ -418,4 +1105,44 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    // ── authentication tests ────────────────────────────────────────────
+
+    fn auth_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_check_token_accepts_configured_tokens() {
+        let tokens = vec!["old-dud".to_string(), "dud-token".to_string()];
+
+        assert_eq!(
+            check_token(&tokens, &auth_headers("token dud-token")),
+            Ok(())
+        );
+        assert_eq!(
+            check_token(&tokens, &auth_headers("Bearer old-dud")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_token_rejects_with_github_messages() {
+        let tokens = vec!["dud-token".to_string()];
+
+        assert_eq!(
+            check_token(&tokens, &HeaderMap::new()),
+            Err("Requires authentication")
+        );
+        assert_eq!(
+            check_token(&tokens, &auth_headers("Basic ZHVkLXRva2Vu")),
+            Err("Requires authentication")
+        );
+        assert_eq!(
+            check_token(&tokens, &auth_headers("token wrong")),
+            Err("Bad credentials")
+        );
+    }
 }
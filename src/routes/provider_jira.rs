@@ -1,22 +1,190 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::{IntoResponse, Json},
+    extract::{Path, Request, State},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
 };
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use serde::Deserialize;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use super::AppState;
+use super::{AppState, extract_webhook_id_from_url, request_base_url};
+use crate::crypto::credentials_match;
+use crate::db::{JiraWebhookRegistration, JiraWebhookRow};
+use crate::jira::normalize_site;
+
+/// Path parameters for `/rest/webhooks/1.0/webhook/{id}`, optionally nested
+/// under a per-site prefix (`/jira/{site}/...`).
+#[derive(Debug, Deserialize)]
+pub struct WebhookPath {
+    site: Option<String>,
+    id: String,
+}
+
+/// Base URL for `self` links, including the per-site prefix when the
+/// request came in under `/jira/{site}`.
+fn site_base_url(headers: &HeaderMap, site: &str) -> String {
+    let base = request_base_url(headers);
+    if site.is_empty() {
+        base
+    } else {
+        format!("{base}/jira/{site}")
+    }
+}
+
+/// Render a stored registration in Jira's webhook response shape.
+fn webhook_json(row: &JiraWebhookRow, base_url: &str) -> serde_json::Value {
+    serde_json::json!({
+        "name": row.name,
+        "url": row.url,
+        "events": row.events,
+        "filters": row.filters,
+        "excludeBody": row.exclude_body,
+        "enabled": true,
+        "self": format!("{}/rest/webhooks/1.0/webhook/{}", base_url, row.id)
+    })
+}
+
+/// Reconcile Jira triggers with n8n in the background after a registration
+/// change, so the registration's trigger row picks up the workflow name and
+/// Include Fields without waiting for the periodic sync. Skipped when
+/// `N8N_TRIGGER_RECONCILE` is off.
+fn spawn_trigger_refresh(state: &AppState) {
+    if !state.config.n8n_trigger_reconcile {
+        return;
+    }
+    let jira_router = state.jira_router.clone();
+    tokio::spawn(async move {
+        if let Err(e) = jira_router.refresh_triggers().await {
+            warn!(error = %e, "Jira mock: failed to refresh triggers after webhook change");
+        }
+    });
+}
+
+/// Returns true if a mock request's `Authorization` header carries the
+/// configured credentials: basic auth with `token` as the API token or
+/// password (and `user` as the login, if set), as sent by n8n's Jira Cloud
+/// and Server credentials, or `Bearer <token>` from the Server PAT credential.
+fn check_credentials(user: Option<&str>, token: &str, headers: &HeaderMap) -> bool {
+    let Some((scheme, value)) = headers
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split_once(' '))
+    else {
+        return false;
+    };
+    let value = value.trim();
+    if scheme.eq_ignore_ascii_case("bearer") {
+        return credentials_match(value, token);
+    }
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return false;
+    }
+    let Some(decoded) = BASE64
+        .decode(value)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+    else {
+        return false;
+    };
+    let Some((login, password)) = decoded.split_once(':') else {
+        return false;
+    };
+    user.is_none_or(|user| user.eq_ignore_ascii_case(login)) && credentials_match(password, token)
+}
+
+/// Request middleware for the Jira mock routes: when `JIRA_MOCK_TOKEN` is
+/// set, only callers presenting the configured credentials (i.e. n8n's Jira
+/// credential) reach the mock. Others get Jira's `401` response, so they
+/// can't register webhooks or overwrite existing registrations.
+pub async fn authenticate(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(token) = &state.config.jira_mock_token else {
+        return next.run(request).await;
+    };
+    let user = state.config.jira_mock_user.as_deref();
+    if check_credentials(user, token, request.headers()) {
+        return next.run(request).await;
+    }
+    warn!(
+        method = %request.method(),
+        path = %request.uri().path(),
+        "Jira mock: rejected unauthenticated request"
+    );
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(serde_json::json!({
+            "errorMessages": [
+                "You are not authenticated. Authentication required to perform this operation."
+            ],
+            "errors": {}
+        })),
+    )
+        .into_response()
+}
 
 // ── Jira API mock endpoints ─────────────────────────────────────────────
 
 /// `GET /rest/webhooks/1.0/webhook` — n8n calls this to check for existing hooks.
 ///
-/// We always return an empty array, telling n8n there are no existing Jira
-/// webhooks so it proceeds to create one via POST.
-pub async fn list_webhooks() -> impl IntoResponse {
-    debug!("Jira mock: GET /rest/webhooks/1.0/webhook -> []");
-    Json(serde_json::json!([]))
+/// Returns every registration stored for the site. n8n's `checkExists`
+/// matches on `url` and `events` and reuses the ID from `self`, so an
+/// already-registered trigger isn't registered again on every activation.
+///
+/// Also served under `/jira/{site}/...` for multi-site setups, where each
+/// n8n Jira credential's domain points at its own site prefix.
+pub async fn list_webhooks(
+    State(state): State<Arc<AppState>>,
+    site: Option<Path<String>>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let site = normalize_site(site.as_deref().map(String::as_str));
+    let base_url = site_base_url(&headers, &site);
+    let rows = match state.db.list_jira_webhooks(&site) {
+        Ok(rows) => rows,
+        Err(e) => {
+            warn!(error = %e, "Jira mock: failed to list webhook registrations");
+            Vec::new()
+        }
+    };
+    debug!(
+        site = %site,
+        count = rows.len(),
+        "Jira mock: GET /rest/webhooks/1.0/webhook"
+    );
+    Json(serde_json::Value::Array(
+        rows.iter().map(|r| webhook_json(r, &base_url)).collect(),
+    ))
+}
+
+/// `GET /rest/webhooks/1.0/webhook/:id` — fetch a single registration.
+///
+/// Only registrations made through the same site's mock path are found.
+pub async fn get_webhook(
+    State(state): State<Arc<AppState>>,
+    Path(WebhookPath { site, id }): Path<WebhookPath>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let site = normalize_site(site.as_deref());
+    let row = match id.parse::<i64>() {
+        Ok(id) => state.db.get_jira_webhook(&site, id).unwrap_or_else(|e| {
+            warn!(error = %e, id = id, "Jira mock: failed to read webhook registration");
+            None
+        }),
+        Err(_) => None,
+    };
+
+    match row {
+        Some(row) => Json(webhook_json(&row, &site_base_url(&headers, &site))).into_response(),
+        None => {
+            debug!(site = %site, id = %id, "Jira mock: webhook registration not found");
+            StatusCode::NOT_FOUND.into_response()
+        }
+    }
 }
 
 /// `POST /rest/webhooks/1.0/webhook` — n8n calls this to register a webhook.
@@ -24,75 +192,170 @@ pub async fn list_webhooks() -> impl IntoResponse {
 /// The request body looks like:
 /// ```json
 /// {
-///   "name": "n8n: <webhook-url>",
+///   "name": "n8n-webhook:<webhook-url>",
 ///   "url": "http://n8n:5678/webhook/<webhookId>/webhook",
 ///   "events": ["jira:issue_created"],
-///   "filters": {},
+///   "filters": { "issue-related-events-section": "project = PROJ" },
 ///   "excludeBody": false
 /// }
 /// ```
 ///
 /// Jira doesn't use HMAC secrets — authentication is done via the Jira API
-/// credential itself. We simply return a valid webhook object so n8n considers
-/// the registration successful. The `self` URL in the response is used by n8n
-/// to extract the webhook ID for DELETE operations during deactivation.
+/// credential itself. The registration is persisted (re-registering the same
+/// URL keeps its ID) and returned with a `self` URL built from the request's
+/// Host header; n8n takes the trailing ID from `self` for DELETE operations
+/// during deactivation.
 pub async fn create_webhook(
     State(state): State<Arc<AppState>>,
+    site: Option<Path<String>>,
+    headers: HeaderMap,
     Json(body): Json<serde_json::Value>,
 ) -> impl IntoResponse {
+    let site = normalize_site(site.as_deref().map(String::as_str));
     let url = body.get("url").and_then(|v| v.as_str()).unwrap_or("");
-    let events = body.get("events").cloned().unwrap_or(serde_json::json!([]));
     let name = body
         .get("name")
         .and_then(|v| v.as_str())
         .unwrap_or("n8n-mock");
+    let events: Vec<String> = body
+        .get("events")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let filters = body
+        .get("filters")
+        .filter(|v| v.is_object())
+        .cloned()
+        .unwrap_or_else(|| serde_json::json!({}));
+    let exclude_body = body
+        .get("excludeBody")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let webhook_id = extract_webhook_id_from_url(url).unwrap_or_else(|| {
+        warn!(url = %url, "Jira mock: could not extract webhook_id from url");
+        String::new()
+    });
+
+    let registration = JiraWebhookRegistration {
+        site,
+        webhook_id,
+        name: name.to_string(),
+        url: url.to_string(),
+        events,
+        filters,
+        exclude_body,
+        production: !state.config.is_test_webhook_url(url),
+    };
+
+    let row = match state.db.upsert_jira_webhook(&registration) {
+        Ok(row) => row,
+        Err(e) => {
+            warn!(error = %e, "Jira mock: failed to store webhook registration");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "errorMessages": ["Failed to store webhook registration"]
+                })),
+            )
+                .into_response();
+        }
+    };
 
     info!(
-        name = %name,
-        url = %url,
+        id = row.id,
+        site = %row.site,
+        webhook_id = %row.webhook_id,
+        name = %row.name,
+        url = %row.url,
         "Jira mock: captured webhook registration"
     );
 
-    // Trigger an immediate sync so the jira_triggers table is populated
-    // right away — otherwise events arriving before the next periodic refresh
-    // would find no matching trigger rows.
-    let jira_router = state.jira_router.clone();
-    tokio::spawn(async move {
-        if let Err(e) = jira_router.refresh_triggers().await {
-            warn!(error = %e, "Jira mock: failed to refresh triggers after webhook registration");
-        }
-    });
+    // The registration already created the trigger row; reconcile it with
+    // n8n right away to pick up Include Fields and `unihook.projects`
+    spawn_trigger_refresh(&state);
 
     (
         StatusCode::CREATED,
-        Json(serde_json::json!({
-            "name": name,
-            "url": url,
-            "events": events,
-            "enabled": true,
-            "self": format!("http://localhost/rest/webhooks/1.0/webhook/1")
-        })),
+        Json(webhook_json(&row, &site_base_url(&headers, &row.site))),
     )
+        .into_response()
 }
 
 /// `DELETE /rest/webhooks/1.0/webhook/:id` — n8n calls this to deregister.
 ///
-/// Returns 204 No Content.
-pub async fn delete_webhook(Path(id): Path<String>) -> impl IntoResponse {
-    info!(id = %id, "Jira mock: deleted webhook");
+/// Removes the stored registration and returns 204 No Content. Unknown IDs
+/// also return 204 so deactivating a workflow registered before the registry
+/// existed (or already cleaned up) doesn't fail in n8n.
+pub async fn delete_webhook(
+    State(state): State<Arc<AppState>>,
+    Path(WebhookPath { site, id }): Path<WebhookPath>,
+) -> impl IntoResponse {
+    let site = normalize_site(site.as_deref());
+    let deleted = match id.parse::<i64>() {
+        Ok(numeric_id) => state
+            .db
+            .delete_jira_webhook(&site, numeric_id)
+            .unwrap_or_else(|e| {
+                warn!(error = %e, id = %id, "Jira mock: failed to delete webhook registration");
+                false
+            }),
+        Err(_) => false,
+    };
+
+    if deleted {
+        info!(site = %site, id = %id, "Jira mock: deleted webhook registration");
+        spawn_trigger_refresh(&state);
+    } else {
+        debug!(site = %site, id = %id, "Jira mock: webhook id not found (already deleted?)");
+    }
     StatusCode::NO_CONTENT
 }
 
-/// `GET /rest/api/2/myself` — n8n calls this to validate Jira credentials.
+/// `GET /rest/api/{2,3}/myself` — n8n calls this to validate Jira credentials.
 ///
-/// Returns a minimal mock user object.
-pub async fn get_myself() -> impl IntoResponse {
-    debug!("Jira mock: GET /rest/api/2/myself -> mock user");
+/// Returns a mock user carrying both the Cloud (`accountId`) and
+/// Server/Data Center (`name`, `key`) identifiers, since n8n's Cloud,
+/// Server, and Server PAT credentials all test against this endpoint.
+pub async fn get_myself(site: Option<Path<String>>, headers: HeaderMap) -> impl IntoResponse {
+    let site = normalize_site(site.as_deref().map(String::as_str));
+    let base_url = site_base_url(&headers, &site);
+    debug!(site = %site, "Jira mock: GET /rest/api/myself -> mock user");
     Json(serde_json::json!({
+        "self": format!("{}/rest/api/2/user?accountId=unihook-mock", base_url),
         "accountId": "unihook-mock",
+        "accountType": "atlassian",
+        "name": "unihook",
+        "key": "unihook",
         "emailAddress": "unihook@example.com",
         "displayName": "Unihook Mock Jira User",
-        "active": true
+        "active": true,
+        "timeZone": "UTC",
+        "locale": "en_US"
+    }))
+}
+
+/// `GET /rest/api/2/serverInfo` — n8n's Server/Data Center code paths call
+/// this to detect the Jira version and deployment type.
+///
+/// Reports a Data Center 9.x instance whose `baseUrl` is Unihook itself.
+pub async fn get_server_info(site: Option<Path<String>>, headers: HeaderMap) -> impl IntoResponse {
+    let site = normalize_site(site.as_deref().map(String::as_str));
+    let base_url = site_base_url(&headers, &site);
+    debug!(site = %site, "Jira mock: GET /rest/api/2/serverInfo");
+    Json(serde_json::json!({
+        "baseUrl": base_url,
+        "version": "9.12.0",
+        "versionNumbers": [9, 12, 0],
+        "deploymentType": "Server",
+        "buildNumber": 9_120_000,
+        "buildDate": "2023-12-05T00:00:00.000+0000",
+        "scmInfo": "unihook",
+        "serverTitle": "Unihook Jira Mock"
     }))
 }
 
@@ -103,6 +366,7 @@ mod tests {
     use crate::db::Database;
     use crate::n8n::N8nClient;
     use crate::router::{GitHubRouter, JiraRouter, SlackRouter, ZoomRouter};
+    use crate::zoom::ZoomReplayGuard;
     use axum::response::IntoResponse;
 
     /// Build a test `AppState` backed by an in-memory SQLite database.
@@ -136,11 +400,32 @@ mod tests {
             jira_router,
             github_router,
             zoom_router,
+            zoom_replay_guard: ZoomReplayGuard::new(config.zoom_replay_window_secs),
             config,
             db,
         })
     }
 
+    fn hook_path(site: Option<&str>, id: &str) -> WebhookPath {
+        WebhookPath {
+            site: site.map(str::to_string),
+            id: id.to_string(),
+        }
+    }
+
+    fn host_headers(host: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("host", host.parse().unwrap());
+        headers
+    }
+
+    async fn response_json(response: axum::response::Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
     // ── create_webhook handler tests ────────────────────────────────────
 
     #[tokio::test]
@@ -155,7 +440,7 @@ mod tests {
             "excludeBody": false
         });
 
-        let response = create_webhook(State(state), Json(body))
+        let response = create_webhook(State(state), None, HeaderMap::new(), Json(body))
             .await
             .into_response();
 
@@ -169,44 +454,412 @@ mod tests {
         // Minimal body — url, name, events all optional
         let body = serde_json::json!({});
 
-        let response = create_webhook(State(state), Json(body))
+        let response = create_webhook(State(state), None, HeaderMap::new(), Json(body))
             .await
             .into_response();
 
         assert_eq!(response.status(), StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn test_create_webhook_persists_registration() {
+        let state = test_state();
+
+        let body = serde_json::json!({
+            "name": "n8n-webhook",
+            "url": "http://n8n:5678/webhook/jira-wh-2/webhook",
+            "events": ["jira:issue_created", "comment_created"],
+            "filters": { "issue-related-events-section": "project = PROJ" },
+            "excludeBody": true
+        });
+
+        let response = create_webhook(
+            State(state.clone()),
+            None,
+            host_headers("unihook.internal:3000"),
+            Json(body),
+        )
+        .await
+        .into_response();
+        let json = response_json(response).await;
+
+        let rows = state.db.list_jira_webhooks("").unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            rows[0].events,
+            vec!["jira:issue_created", "comment_created"]
+        );
+        assert!(rows[0].exclude_body);
+        assert_eq!(
+            rows[0].filters["issue-related-events-section"],
+            "project = PROJ"
+        );
+        assert_eq!(
+            json["self"],
+            format!(
+                "http://unihook.internal:3000/rest/webhooks/1.0/webhook/{}",
+                rows[0].id
+            )
+        );
+        assert_eq!(json["excludeBody"], true);
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_registers_trigger() {
+        let state = test_state();
+
+        let body = serde_json::json!({
+            "url": "http://n8n:5678/webhook-test/jira-wh-3/webhook",
+            "events": ["jira:issue_updated"],
+            "filters": { "issue-related-events-section": "project = OPS" }
+        });
+        create_webhook(
+            State(state.clone()),
+            Some(Path("acme".to_string())),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        let rows = state.db.query_jira_triggers().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "jira-wh-3");
+        assert_eq!(rows[0].site, "acme");
+        assert_eq!(rows[0].projects, vec!["OPS"]);
+        // Registered through n8n's test URL: only test deliveries
+        assert!(!rows[0].workflow_active);
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_ids_are_unique() {
+        let state = test_state();
+
+        for wh in ["a", "b"] {
+            let body = serde_json::json!({
+                "url": format!("http://n8n:5678/webhook/{wh}/webhook"),
+                "events": ["jira:issue_created"]
+            });
+            create_webhook(State(state.clone()), None, HeaderMap::new(), Json(body)).await;
+        }
+
+        let rows = state.db.list_jira_webhooks("").unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_ne!(rows[0].id, rows[1].id);
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_self_honors_forwarded_proto() {
+        let state = test_state();
+        let mut headers = host_headers("unihook.example.com");
+        headers.insert("x-forwarded-proto", "https".parse().unwrap());
+
+        let body = serde_json::json!({ "url": "http://n8n:5678/webhook/c/webhook" });
+        let response = create_webhook(State(state), None, headers, Json(body))
+            .await
+            .into_response();
+        let json = response_json(response).await;
+
+        assert!(
+            json["self"]
+                .as_str()
+                .unwrap()
+                .starts_with("https://unihook.example.com/rest/webhooks/1.0/webhook/")
+        );
+    }
+
+    // ── list_webhooks / get_webhook handler tests ───────────────────────
+
+    #[tokio::test]
+    async fn test_list_webhooks_returns_registrations() {
+        let state = test_state();
+        let url = "http://n8n:5678/webhook/jira-wh-3/webhook";
+        let body = serde_json::json!({ "url": url, "events": ["jira:issue_updated"] });
+        create_webhook(State(state.clone()), None, HeaderMap::new(), Json(body)).await;
+
+        let response = list_webhooks(State(state), None, host_headers("unihook:3000"))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let json = response_json(response).await;
+
+        let hooks = json.as_array().unwrap();
+        assert_eq!(hooks.len(), 1);
+        assert_eq!(hooks[0]["url"], url);
+        assert_eq!(
+            hooks[0]["events"],
+            serde_json::json!(["jira:issue_updated"])
+        );
+        // n8n takes the ID from the last `self` path segment
+        let self_url = hooks[0]["self"].as_str().unwrap();
+        assert!(self_url.starts_with("http://unihook:3000/rest/webhooks/1.0/webhook/"));
+        assert!(self_url.rsplit('/').next().unwrap().parse::<i64>().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_webhook_by_id() {
+        let state = test_state();
+        let body = serde_json::json!({ "url": "http://n8n:5678/webhook/d/webhook" });
+        create_webhook(State(state.clone()), None, HeaderMap::new(), Json(body)).await;
+        let id = state.db.list_jira_webhooks("").unwrap()[0].id;
+
+        let response = get_webhook(
+            State(state.clone()),
+            Path(hook_path(None, &id.to_string())),
+            HeaderMap::new(),
+        )
+        .await
+        .into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = get_webhook(State(state), Path(hook_path(None, "999")), HeaderMap::new())
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    // ── per-site mock path tests ────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_site_registrations_are_scoped() {
+        let state = test_state();
+        let url = "http://n8n:5678/webhook/site-wh/webhook";
+        let body = serde_json::json!({ "url": url, "events": ["jira:issue_created"] });
+
+        let response = create_webhook(
+            State(state.clone()),
+            Some(Path("Acme".to_string())),
+            host_headers("unihook:3000"),
+            Json(body),
+        )
+        .await
+        .into_response();
+        let json = response_json(response).await;
+
+        let rows = state.db.list_jira_webhooks("acme").unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].webhook_id, "site-wh");
+        assert_eq!(
+            json["self"],
+            format!(
+                "http://unihook:3000/jira/acme/rest/webhooks/1.0/webhook/{}",
+                rows[0].id
+            )
+        );
+
+        // The default site's list doesn't include it
+        let response = list_webhooks(State(state.clone()), None, HeaderMap::new())
+            .await
+            .into_response();
+        assert_eq!(response_json(response).await, serde_json::json!([]));
+
+        let response = list_webhooks(
+            State(state),
+            Some(Path("acme".to_string())),
+            HeaderMap::new(),
+        )
+        .await
+        .into_response();
+        assert_eq!(response_json(response).await.as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_webhook_under_site_path() {
+        let state = test_state();
+        let body = serde_json::json!({ "url": "http://n8n:5678/webhook/f/webhook" });
+        create_webhook(
+            State(state.clone()),
+            Some(Path("dc".to_string())),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+        let id = state.db.list_jira_webhooks("dc").unwrap()[0].id;
+
+        let response = delete_webhook(
+            State(state.clone()),
+            Path(hook_path(Some("dc"), &id.to_string())),
+        )
+        .await
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(state.db.list_jira_webhooks("dc").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_other_sites_registration_is_not_found_by_id() {
+        let state = test_state();
+        let body = serde_json::json!({ "url": "http://n8n:5678/webhook/g/webhook" });
+        create_webhook(
+            State(state.clone()),
+            Some(Path("dc".to_string())),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+        let id = state.db.list_jira_webhooks("dc").unwrap()[0].id.to_string();
+
+        // Neither another site nor the default site can read or delete it
+        for site in [Some("acme"), None] {
+            let response = get_webhook(
+                State(state.clone()),
+                Path(hook_path(site, &id)),
+                HeaderMap::new(),
+            )
+            .await
+            .into_response();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+            let response = delete_webhook(State(state.clone()), Path(hook_path(site, &id)))
+                .await
+                .into_response();
+            assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        }
+        assert_eq!(state.db.list_jira_webhooks("dc").unwrap().len(), 1);
+        assert_eq!(state.db.count_jira_triggers().unwrap(), 1);
+    }
+
     // ── delete_webhook handler tests ────────────────────────────────────
 
     #[tokio::test]
     async fn test_delete_webhook_returns_204() {
-        let response = delete_webhook(Path("42".to_string())).await.into_response();
+        let state = test_state();
+        let response = delete_webhook(State(state), Path(hook_path(None, "42")))
+            .await
+            .into_response();
 
         assert_eq!(response.status(), StatusCode::NO_CONTENT);
     }
 
     #[tokio::test]
     async fn test_delete_webhook_nonexistent_returns_204() {
-        let response = delete_webhook(Path("nonexistent".to_string()))
+        let state = test_state();
+        let response = delete_webhook(State(state), Path(hook_path(None, "nonexistent")))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_delete_webhook_removes_registration() {
+        let state = test_state();
+        let body = serde_json::json!({ "url": "http://n8n:5678/webhook/e/webhook" });
+        create_webhook(State(state.clone()), None, HeaderMap::new(), Json(body)).await;
+        let id = state.db.list_jira_webhooks("").unwrap()[0].id;
+
+        let response = delete_webhook(State(state.clone()), Path(hook_path(None, &id.to_string())))
             .await
             .into_response();
 
         assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(state.db.list_jira_webhooks("").unwrap().is_empty());
     }
 
     // ── list_webhooks and get_myself handler tests ──────────────────────
 
     #[tokio::test]
     async fn test_list_webhooks_returns_200() {
-        let response = list_webhooks().await.into_response();
+        let state = test_state();
+        let response = list_webhooks(State(state), None, HeaderMap::new())
+            .await
+            .into_response();
 
         assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[tokio::test]
     async fn test_get_myself_returns_200() {
-        let response = get_myself().await.into_response();
+        let response = get_myself(None, HeaderMap::new()).await.into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_get_myself_has_cloud_and_server_identifiers() {
+        let response = get_myself(Some(Path("acme".to_string())), host_headers("unihook:3000"))
+            .await
+            .into_response();
+        let body = response_json(response).await;
+
+        assert_eq!(body["accountId"], "unihook-mock");
+        assert_eq!(body["name"], "unihook");
+        assert!(
+            body["self"]
+                .as_str()
+                .unwrap()
+                .starts_with("http://unihook:3000/jira/acme/rest/api/2/user")
+        );
+    }
 
+    #[tokio::test]
+    async fn test_get_server_info() {
+        let response = get_server_info(Some(Path("dc".to_string())), host_headers("unihook:3000"))
+            .await
+            .into_response();
         assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(body["baseUrl"], "http://unihook:3000/jira/dc");
+        assert_eq!(body["deploymentType"], "Server");
+        assert_eq!(body["versionNumbers"][0], 9);
+    }
+
+    // ── authentication tests ────────────────────────────────────────────
+
+    fn auth_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, value.parse().unwrap());
+        headers
+    }
+
+    fn basic(user: &str, password: &str) -> HeaderMap {
+        auth_headers(&format!(
+            "Basic {}",
+            BASE64.encode(format!("{user}:{password}"))
+        ))
+    }
+
+    #[test]
+    fn test_check_credentials_basic_auth() {
+        let user = Some("n8n@example.com");
+
+        assert!(check_credentials(
+            user,
+            "dud",
+            &basic("N8N@example.com", "dud")
+        ));
+        assert!(!check_credentials(
+            user,
+            "dud",
+            &basic("someone@example.com", "dud")
+        ));
+        assert!(!check_credentials(
+            user,
+            "dud",
+            &basic("n8n@example.com", "wrong")
+        ));
+        // Without a configured user, any login with the right token is accepted
+        assert!(check_credentials(None, "dud", &basic("anyone", "dud")));
+    }
+
+    #[test]
+    fn test_check_credentials_bearer_and_missing() {
+        assert!(check_credentials(
+            None,
+            "dud-pat",
+            &auth_headers("Bearer dud-pat")
+        ));
+        assert!(!check_credentials(
+            None,
+            "dud-pat",
+            &auth_headers("Bearer wrong")
+        ));
+        assert!(!check_credentials(
+            None,
+            "dud-pat",
+            &auth_headers("Basic !!!")
+        ));
+        assert!(!check_credentials(None, "dud-pat", &HeaderMap::new()));
     }
 }
@@ -1,14 +1,15 @@
 use axum::{
-    extract::State,
+    extract::{Path, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Json},
 };
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use crate::crypto::{compute_zoom_url_validation_token, verify_zoom_webhook_signature};
+use crate::crypto::{compute_zoom_url_validation_token, find_zoom_webhook_secret};
 use crate::zoom::{
-    UrlValidationResponse, ZoomWebhookPayload, extract_host_email, extract_plain_token,
+    UrlValidationResponse, ZoomReplayGuard, ZoomWebhookPayload, extract_account_id,
+    extract_plain_token,
 };
 
 use super::{AppState, extract_forwarded_headers};
@@ -16,8 +17,14 @@ use super::{AppState, extract_forwarded_headers};
 /// Headers to forward from Zoom to n8n webhooks
 const ZOOM_FORWARDED_HEADER_PREFIXES: &[&str] = &["x-zm-", "content-type"];
 
+/// Handle Zoom events on `/zoom/events` and `/zoom/{account_id}/events`.
+///
+/// The account comes from the path or, on `/zoom/events`, the payload's
+/// `account_id`; it selects the Secret Tokens the request is verified with
+/// and scopes routing to triggers for that account.
 pub async fn handle_zoom_event(
     State(state): State<Arc<AppState>>,
+    account: Option<Path<String>>,
     headers: HeaderMap,
     body: String,
 ) -> impl IntoResponse {
@@ -30,19 +37,22 @@ pub async fn handle_zoom_event(
         }
     };
 
-    let signature = header_value(&headers, "x-zm-signature");
-    let timestamp = header_value(&headers, "x-zm-request-timestamp");
-
-    let (Some(signature), Some(timestamp)) = (signature, timestamp) else {
-        warn!("Missing Zoom webhook signature headers");
-        return StatusCode::UNAUTHORIZED.into_response();
+    let routing_payload = serde_json::json!({
+        "payload": payload.payload,
+    });
+    let account_id = match resolve_zoom_account(
+        account.map(|Path(account)| account),
+        extract_account_id(&routing_payload),
+    ) {
+        Ok(account_id) => account_id,
+        Err(status) => return status.into_response(),
     };
 
-    let secret = &state.config.zoom_webhook_secret;
-    if !verify_zoom_webhook_signature(secret, body.as_bytes(), &timestamp, &signature) {
-        warn!("Invalid Zoom webhook signature");
-        return StatusCode::UNAUTHORIZED.into_response();
-    }
+    let secrets = state.config.zoom_secrets_for_account(account_id.as_deref());
+    let secret = match verify_zoom_request(&secrets, &state.zoom_replay_guard, &headers, &body) {
+        Ok(secret) => secret,
+        Err(status) => return status.into_response(),
+    };
 
     if payload.event == "endpoint.url_validation" {
         let plain_token = match extract_plain_token(&payload.payload) {
@@ -70,23 +80,83 @@ pub async fn handle_zoom_event(
         return StatusCode::OK.into_response();
     }
 
-    info!(event = %payload.event, "Received Zoom event");
+    info!(
+        event = %payload.event,
+        account_id = account_id.as_deref().unwrap_or_default(),
+        "Received Zoom event"
+    );
 
-    let host_email = extract_host_email(&serde_json::json!({
-        "payload": payload.payload,
-    }));
     let forwarded_headers = extract_forwarded_headers(&headers, ZOOM_FORWARDED_HEADER_PREFIXES);
     let router = state.zoom_router.clone();
     let event = payload.event.clone();
+    let secret = secret.to_string();
     tokio::spawn(async move {
         router
-            .route_event(&event, host_email.as_deref(), body, forwarded_headers)
+            .route_event(
+                &event,
+                account_id.as_deref(),
+                &routing_payload,
+                body,
+                forwarded_headers,
+                &secret,
+            )
             .await;
     });
 
     StatusCode::OK.into_response()
 }
 
+/// Determine the Zoom account of a request. An account in the path must
+/// agree with the payload's `account_id` when the payload has one.
+fn resolve_zoom_account(
+    path_account: Option<String>,
+    payload_account: Option<String>,
+) -> Result<Option<String>, StatusCode> {
+    match (path_account, payload_account) {
+        (Some(path), Some(payload)) if path != payload => {
+            warn!(
+                path_account_id = %path,
+                payload_account_id = %payload,
+                "Zoom payload account_id does not match the account path"
+            );
+            Err(StatusCode::UNAUTHORIZED)
+        }
+        (Some(path), _) => Ok(Some(path)),
+        (None, payload) => Ok(payload),
+    }
+}
+
+/// Verify a Zoom request's `x-zm-signature` against the configured Secret
+/// Tokens and reject replays (stale timestamps or signatures already seen).
+/// Returns the secret that signed the request.
+fn verify_zoom_request<'a>(
+    secrets: &'a [String],
+    replay_guard: &ZoomReplayGuard,
+    headers: &HeaderMap,
+    body: &str,
+) -> Result<&'a str, StatusCode> {
+    let signature = header_value(headers, "x-zm-signature");
+    let timestamp = header_value(headers, "x-zm-request-timestamp");
+
+    let (Some(signature), Some(timestamp)) = (signature, timestamp) else {
+        warn!("Missing Zoom webhook signature headers");
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    let Some(index) = find_zoom_webhook_secret(secrets, body.as_bytes(), &timestamp, &signature)
+    else {
+        warn!("Invalid Zoom webhook signature");
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+    debug!(secret_index = index, "Zoom webhook signature verified");
+
+    if let Err(e) = replay_guard.check(&timestamp, &signature) {
+        warn!(error = %e, timestamp = %timestamp, "Rejected Zoom webhook replay");
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(&secrets[index])
+}
+
 fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
     headers
         .get(name)
@@ -97,7 +167,101 @@ fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto::compute_zoom_webhook_signature;
     use axum::http::{HeaderName, HeaderValue};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn signed_headers(secret: &str, timestamp: u64, body: &str) -> HeaderMap {
+        let timestamp = timestamp.to_string();
+        let signature = compute_zoom_webhook_signature(secret, &timestamp, body.as_bytes());
+        let mut headers = HeaderMap::new();
+        headers.insert("x-zm-signature", signature.parse().unwrap());
+        headers.insert("x-zm-request-timestamp", timestamp.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_verify_accepts_any_configured_secret() {
+        let secrets = vec!["old-secret".to_string(), "new-secret".to_string()];
+        let guard = ZoomReplayGuard::new(300);
+        let body = r#"{"event":"meeting.started"}"#;
+
+        let headers = signed_headers("old-secret", now(), body);
+        assert_eq!(
+            verify_zoom_request(&secrets, &guard, &headers, body),
+            Ok("old-secret")
+        );
+        let headers = signed_headers("new-secret", now(), body);
+        assert_eq!(
+            verify_zoom_request(&secrets, &guard, &headers, body),
+            Ok("new-secret")
+        );
+        let headers = signed_headers("other-secret", now(), body);
+        assert_eq!(
+            verify_zoom_request(&secrets, &guard, &headers, body),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_stale_and_replayed_requests() {
+        let secrets = vec!["secret".to_string()];
+        let guard = ZoomReplayGuard::new(300);
+        let body = r#"{"event":"meeting.started"}"#;
+
+        let stale = signed_headers("secret", now() - 3600, body);
+        assert_eq!(
+            verify_zoom_request(&secrets, &guard, &stale, body),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+
+        let fresh = signed_headers("secret", now(), body);
+        assert!(verify_zoom_request(&secrets, &guard, &fresh, body).is_ok());
+        assert_eq!(
+            verify_zoom_request(&secrets, &guard, &fresh, body),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+
+    #[test]
+    fn test_resolve_zoom_account() {
+        assert_eq!(resolve_zoom_account(None, None), Ok(None));
+        assert_eq!(
+            resolve_zoom_account(None, Some("prod".to_string())),
+            Ok(Some("prod".to_string()))
+        );
+        // URL validation payloads carry no account_id
+        assert_eq!(
+            resolve_zoom_account(Some("sub1".to_string()), None),
+            Ok(Some("sub1".to_string()))
+        );
+        assert_eq!(
+            resolve_zoom_account(Some("sub1".to_string()), Some("sub1".to_string())),
+            Ok(Some("sub1".to_string()))
+        );
+        assert_eq!(
+            resolve_zoom_account(Some("sub1".to_string()), Some("prod".to_string())),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_missing_headers() {
+        let secrets = vec!["secret".to_string()];
+        let guard = ZoomReplayGuard::new(300);
+
+        assert_eq!(
+            verify_zoom_request(&secrets, &guard, &HeaderMap::new(), "{}"),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
 
     #[test]
     fn test_forwards_zoom_signature_header() {
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{Instant, interval_at};
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+use crate::db::{Database, WebhookSecretGcReport};
+
+/// Start the background task that garbage-collects orphaned webhook secrets.
+///
+/// Secrets are only removed by the provider mock when n8n deregisters a
+/// webhook, so secrets of deleted workflows and of hooks registered under an
+/// `unknown-*` fallback ID would otherwise accumulate. Every
+/// `WEBHOOK_SECRET_GC_INTERVAL_SECS`, secrets no trigger references are
+/// quarantined, and deleted once they have been orphaned for
+/// `WEBHOOK_SECRET_GC_GRACE_SECS`. The first pass runs one interval after
+/// startup, so the trigger refresh tasks have synced first.
+pub fn start_webhook_secret_gc_task(db: Arc<Database>, config: Arc<Config>) {
+    if config.webhook_secret_gc_interval_secs == 0 {
+        info!("WEBHOOK_SECRET_GC_INTERVAL_SECS=0: orphaned webhook secret cleanup disabled");
+        return;
+    }
+    let period = Duration::from_secs(config.webhook_secret_gc_interval_secs);
+    let grace_secs = config.webhook_secret_gc_grace_secs;
+
+    tokio::spawn(async move {
+        let mut ticker = interval_at(Instant::now() + period, period);
+        loop {
+            ticker.tick().await;
+            match db.collect_orphaned_webhook_secrets(grace_secs) {
+                Ok(report) => log_report(&report, grace_secs),
+                Err(e) => warn!(error = %e, "Failed to garbage-collect webhook secrets"),
+            }
+        }
+    });
+}
+
+/// Log what a garbage-collection pass changed.
+fn log_report(report: &WebhookSecretGcReport, grace_secs: u64) {
+    for webhook_id in &report.quarantined {
+        info!(
+            webhook_id = %webhook_id,
+            grace_secs,
+            "Quarantined orphaned webhook secret (no trigger references it)"
+        );
+    }
+    for webhook_id in &report.restored {
+        info!(webhook_id = %webhook_id, "Restored quarantined webhook secret");
+    }
+    for secret in &report.deleted {
+        info!(
+            webhook_id = %secret.webhook_id,
+            provider = %secret.provider,
+            created_at = ?secret.created_at,
+            orphaned_at = %secret.orphaned_at,
+            "Deleted orphaned webhook secret"
+        );
+    }
+
+    if report.quarantined.is_empty() && report.restored.is_empty() && report.deleted.is_empty() {
+        debug!("Webhook secret garbage collection found nothing to do");
+    } else {
+        info!(
+            quarantined = report.quarantined.len(),
+            restored = report.restored.len(),
+            deleted = report.deleted.len(),
+            "Webhook secret garbage collection finished"
+        );
+    }
+}
@@ -85,6 +85,9 @@ pub struct SlackEvent {
     /// Bot ID if the event was triggered by a bot
     pub bot_id: Option<String>,
 
+    /// Parent message timestamp for threaded messages (equals `ts` on the parent)
+    pub thread_ts: Option<String>,
+
     /// Capture any additional fields we don't explicitly handle
     #[serde(flatten)]
     pub extra: serde_json::Value,
@@ -118,6 +121,30 @@ impl SlackEvent {
             _ => &self.event_type,
         }
     }
+
+    /// The conversation type this event happened in (`channel`, `group`, `im`, `mpim`).
+    ///
+    /// Uses `channel_type` when Slack sends it (message events). Events such as
+    /// `app_mention` omit it, so direct messages are inferred from the `D`
+    /// channel ID prefix; other conversation types cannot be told apart by ID.
+    pub fn resolved_channel_type(&self) -> Option<&str> {
+        if let Some(channel_type) = self.channel_type.as_deref() {
+            return Some(channel_type);
+        }
+        match self.channel.as_deref() {
+            Some(ch) if ch.starts_with('D') => Some("im"),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this event is a reply inside a thread (not the parent message).
+    pub fn is_thread_reply(&self) -> bool {
+        match (self.thread_ts.as_deref(), self.ts.as_deref()) {
+            (Some(thread_ts), Some(ts)) => thread_ts != ts,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -237,6 +264,7 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "message");
@@ -255,6 +283,7 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "file_shared");
@@ -273,6 +302,7 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "reaction_added");
@@ -291,6 +321,7 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "app_mention");
@@ -309,6 +340,7 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "channel_created");
@@ -327,6 +359,7 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "user_created");
@@ -345,8 +378,71 @@ mod tests {
             file_id: None,
             channel_type: None,
             bot_id: None,
+            thread_ts: None,
             extra: serde_json::Value::Null,
         };
         assert_eq!(event.to_n8n_event_type(), "some_unknown_event");
     }
+
+    #[test]
+    fn test_thread_reply_parsing() {
+        let json = r#"{
+            "type": "message",
+            "channel": "C123456",
+            "channel_type": "channel",
+            "user": "U123456",
+            "text": "a reply",
+            "ts": "1234567890.000200",
+            "thread_ts": "1234567890.000100"
+        }"#;
+
+        let event: SlackEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.thread_ts.as_deref(), Some("1234567890.000100"));
+        assert!(event.is_thread_reply());
+    }
+
+    #[test]
+    fn test_thread_parent_is_not_reply() {
+        let json = r#"{
+            "type": "message",
+            "channel": "C123456",
+            "ts": "1234567890.000100",
+            "thread_ts": "1234567890.000100"
+        }"#;
+
+        let event: SlackEvent = serde_json::from_str(json).unwrap();
+        assert!(!event.is_thread_reply());
+    }
+
+    #[test]
+    fn test_top_level_message_is_not_reply() {
+        let json = r#"{"type": "message", "channel": "C123456", "ts": "1234567890.000100"}"#;
+
+        let event: SlackEvent = serde_json::from_str(json).unwrap();
+        assert!(!event.is_thread_reply());
+    }
+
+    #[test]
+    fn test_resolved_channel_type_prefers_payload_field() {
+        let json = r#"{"type": "message", "channel": "D123456", "channel_type": "mpim"}"#;
+
+        let event: SlackEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.resolved_channel_type(), Some("mpim"));
+    }
+
+    #[test]
+    fn test_resolved_channel_type_infers_dm_from_channel_id() {
+        let json = r#"{"type": "app_mention", "channel": "D123456"}"#;
+
+        let event: SlackEvent = serde_json::from_str(json).unwrap();
+        assert_eq!(event.resolved_channel_type(), Some("im"));
+    }
+
+    #[test]
+    fn test_resolved_channel_type_unknown_for_public_channel_id() {
+        let json = r#"{"type": "app_mention", "channel": "C123456"}"#;
+
+        let event: SlackEvent = serde_json::from_str(json).unwrap();
+        assert!(event.resolved_channel_type().is_none());
+    }
 }
@@ -1,4 +1,50 @@
 use crate::n8n::{Workflow, WorkflowNode};
+use tracing::warn;
+
+/// Slack conversation types accepted by the `unihook.channel_types` annotation.
+pub const SLACK_CHANNEL_TYPES: &[&str] = &["channel", "group", "im", "mpim"];
+
+/// Whether a trigger receives top-level messages, thread replies, or both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlackThreadFilter {
+    /// Top-level messages and thread replies (n8n's default behavior)
+    #[default]
+    Any,
+    /// Only messages that are not replies in a thread
+    TopLevel,
+    /// Only replies in a thread
+    Replies,
+}
+
+impl SlackThreadFilter {
+    /// Parse the `unihook.thread` annotation value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "any" => Some(Self::Any),
+            "top_level" => Some(Self::TopLevel),
+            "replies" => Some(Self::Replies),
+            _ => None,
+        }
+    }
+
+    /// Stable string form used in the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::TopLevel => "top_level",
+            Self::Replies => "replies",
+        }
+    }
+
+    /// Returns true if an event with the given reply status passes this filter.
+    pub fn matches(&self, is_thread_reply: bool) -> bool {
+        match self {
+            Self::Any => true,
+            Self::TopLevel => !is_thread_reply,
+            Self::Replies => is_thread_reply,
+        }
+    }
+}
 
 /// Extracted configuration from a Slack Trigger node
 #[derive(Debug, Clone)]
@@ -28,6 +74,15 @@ pub struct SlackTriggerConfig {
 
     /// Whether to watch the entire workspace
     pub watch_whole_workspace: bool,
+
+    /// Conversation types to accept (`channel`, `group`, `im`, `mpim`).
+    /// `None` means any type. Set via the `unihook.channel_types` node
+    /// annotation; if every listed value is unknown the filter is empty and
+    /// matches nothing.
+    pub channel_types: Option<Vec<String>>,
+
+    /// Top-level vs threaded message filter. Set via the `unihook.thread` node annotation.
+    pub thread_filter: SlackThreadFilter,
 }
 
 /// Parse Slack Trigger configuration from a workflow node
@@ -65,6 +120,21 @@ pub fn parse_slack_trigger(workflow: &Workflow, node: &WorkflowNode) -> Option<S
     // Require a webhook ID — it's the correlation key for the database
     let webhook_id = node.webhook_id.as_ref()?;
 
+    // Optional Unihook-only filters from the node's notes
+    let annotations = node.annotations();
+    let channel_types = parse_channel_types(&annotations.get_list("channel_types"), workflow);
+    let thread_filter = match annotations.get("thread") {
+        Some(value) => SlackThreadFilter::parse(value).unwrap_or_else(|| {
+            warn!(
+                workflow_id = %workflow.id,
+                value = %value,
+                "Ignoring unknown unihook.thread annotation (expected any, top_level, or replies)"
+            );
+            SlackThreadFilter::Any
+        }),
+        None => SlackThreadFilter::Any,
+    };
+
     Some(SlackTriggerConfig {
         webhook_id: webhook_id.clone(),
         workflow_id: workflow.id.clone(),
@@ -73,9 +143,45 @@ pub fn parse_slack_trigger(workflow: &Workflow, node: &WorkflowNode) -> Option<S
         event_type,
         channels,
         watch_whole_workspace,
+        channel_types,
+        thread_filter,
     })
 }
 
+/// Normalize `unihook.channel_types` values, dropping unknown conversation types.
+///
+/// Returns `None` when the annotation is absent. A configured list stays
+/// `Some` even if every value is unknown, so a typo such as `privte` narrows
+/// delivery to nothing instead of removing the filter.
+fn parse_channel_types(values: &[String], workflow: &Workflow) -> Option<Vec<String>> {
+    if values.is_empty() {
+        return None;
+    }
+    let channel_types: Vec<String> = values
+        .iter()
+        .map(|v| v.to_lowercase())
+        .filter(|v| {
+            let known = SLACK_CHANNEL_TYPES.contains(&v.as_str());
+            if !known {
+                warn!(
+                    workflow_id = %workflow.id,
+                    channel_type = %v,
+                    "Ignoring unknown Slack channel type in unihook.channel_types annotation"
+                );
+            }
+            known
+        })
+        .collect();
+    if channel_types.is_empty() {
+        warn!(
+            workflow_id = %workflow.id,
+            values = ?values,
+            "unihook.channel_types has no known Slack channel types; trigger will receive no events"
+        );
+    }
+    Some(channel_types)
+}
+
 /// Extract channel IDs from node parameters
 fn extract_channels(params: &serde_json::Value) -> Vec<String> {
     // channelId as resource locator object: {"__rl": true, "value": "C123", "mode": "id"}
@@ -118,6 +224,7 @@ mod tests {
             name: "Slack Trigger".to_string(),
             parameters: params,
             webhook_id: webhook_id.map(|s| s.to_string()),
+            notes: None,
         }
     }
 
@@ -190,6 +297,7 @@ mod tests {
             name: "HTTP Request".to_string(),
             parameters: json!({}),
             webhook_id: Some("webhook-123".to_string()),
+            notes: None,
         };
         let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
 
@@ -256,4 +364,82 @@ mod tests {
 
         assert!(channels.is_empty());
     }
+
+    // ==================== Annotation Tests ====================
+
+    #[test]
+    fn test_parse_defaults_have_no_extra_filters() {
+        let node = create_slack_trigger_node(Some("webhook-123"), json!({"trigger": ["message"]}));
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_slack_trigger(&workflow, &node).unwrap();
+
+        assert!(config.channel_types.is_none());
+        assert_eq!(config.thread_filter, SlackThreadFilter::Any);
+    }
+
+    #[test]
+    fn test_parse_channel_type_and_thread_annotations() {
+        let mut node =
+            create_slack_trigger_node(Some("webhook-123"), json!({"trigger": ["message"]}));
+        node.notes = Some(
+            "DM replies only\nunihook.channel_types: IM, mpim\nunihook.thread: replies".to_string(),
+        );
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_slack_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(
+            config.channel_types,
+            Some(vec!["im".to_string(), "mpim".to_string()])
+        );
+        assert_eq!(config.thread_filter, SlackThreadFilter::Replies);
+    }
+
+    #[test]
+    fn test_parse_unknown_annotation_values_are_ignored() {
+        let mut node =
+            create_slack_trigger_node(Some("webhook-123"), json!({"trigger": ["message"]}));
+        node.notes =
+            Some("unihook.channel_types: im, shared\nunihook.thread: sideways".to_string());
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_slack_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.channel_types, Some(vec!["im".to_string()]));
+        assert_eq!(config.thread_filter, SlackThreadFilter::Any);
+    }
+
+    #[test]
+    fn test_parse_all_unknown_channel_types_fails_closed() {
+        let mut node =
+            create_slack_trigger_node(Some("webhook-123"), json!({"trigger": ["message"]}));
+        node.notes = Some("unihook.channel_types: privte".to_string());
+        let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
+
+        let config = parse_slack_trigger(&workflow, &node).unwrap();
+
+        assert_eq!(config.channel_types, Some(vec![]));
+    }
+
+    #[test]
+    fn test_thread_filter_matches() {
+        assert!(SlackThreadFilter::Any.matches(true));
+        assert!(SlackThreadFilter::Any.matches(false));
+        assert!(SlackThreadFilter::TopLevel.matches(false));
+        assert!(!SlackThreadFilter::TopLevel.matches(true));
+        assert!(SlackThreadFilter::Replies.matches(true));
+        assert!(!SlackThreadFilter::Replies.matches(false));
+    }
+
+    #[test]
+    fn test_thread_filter_roundtrip() {
+        for filter in [
+            SlackThreadFilter::Any,
+            SlackThreadFilter::TopLevel,
+            SlackThreadFilter::Replies,
+        ] {
+            assert_eq!(SlackThreadFilter::parse(filter.as_str()), Some(filter));
+        }
+    }
 }
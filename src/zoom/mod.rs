@@ -1,5 +1,11 @@
 pub mod models;
+pub mod policy;
+pub mod redact;
+pub mod replay;
 pub mod triggers;
 
 pub use models::*;
+pub use policy::*;
+pub use redact::*;
+pub use replay::*;
 pub use triggers::*;
@@ -39,6 +39,64 @@ pub fn extract_host_email(payload: &serde_json::Value) -> Option<String> {
         .map(normalize_email)
 }
 
+/// Extract the Zoom account that sent an event (`payload.account_id`).
+pub fn extract_account_id(payload: &serde_json::Value) -> Option<String> {
+    payload
+        .get("payload")?
+        .get("account_id")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+}
+
+/// Extract the participant's email from participant events
+/// (`payload.object.participant.email`).
+pub fn extract_participant_email(payload: &serde_json::Value) -> Option<String> {
+    payload
+        .get("payload")?
+        .get("object")?
+        .get("participant")?
+        .get("email")
+        .and_then(|v| v.as_str())
+        .filter(|email| !email.trim().is_empty())
+        .map(normalize_email)
+}
+
+/// Extract a meeting's alternative hosts from `payload.object.settings`
+/// (or `payload.object`). Zoom sends them as one `;`- or `,`-separated
+/// string.
+pub fn extract_alternative_hosts(payload: &serde_json::Value) -> Vec<String> {
+    let Some(object) = payload.get("payload").and_then(|p| p.get("object")) else {
+        return Vec::new();
+    };
+    object
+        .get("settings")
+        .and_then(|s| s.get("alternative_hosts"))
+        .or_else(|| object.get("alternative_hosts"))
+        .and_then(|v| v.as_str())
+        .map(|hosts| {
+            hosts
+                .split([';', ','])
+                .map(normalize_email)
+                .filter(|email| !email.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Extract the meeting host's Zoom user ID (`payload.object.host_id`).
+pub fn extract_host_id(payload: &serde_json::Value) -> Option<String> {
+    payload
+        .get("payload")?
+        .get("object")?
+        .get("host_id")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+}
+
 fn normalize_email(email: &str) -> String {
     email.trim().to_string()
 }
@@ -112,4 +170,57 @@ mod tests {
         });
         assert!(extract_host_email(&payload).is_none());
     }
+
+    #[test]
+    fn test_extract_participant_email() {
+        let payload = json!({
+            "payload": {
+                "object": {
+                    "host_email": "host@example.com",
+                    "participant": { "user_name": "Pat", "email": " pat@example.com " }
+                }
+            }
+        });
+        assert_eq!(
+            extract_participant_email(&payload).as_deref(),
+            Some("pat@example.com")
+        );
+        assert!(extract_participant_email(&json!({"payload": {"object": {}}})).is_none());
+    }
+
+    #[test]
+    fn test_extract_alternative_hosts() {
+        let payload = json!({
+            "payload": {
+                "object": {
+                    "settings": { "alternative_hosts": "a@example.com; b@example.com,," }
+                }
+            }
+        });
+        assert_eq!(
+            extract_alternative_hosts(&payload),
+            vec!["a@example.com", "b@example.com"]
+        );
+        assert!(extract_alternative_hosts(&json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_extract_host_id() {
+        let payload = json!({ "payload": { "object": { "host_id": "uLoRgfbbTayCX6r2Q_qQsQ" } } });
+        assert_eq!(
+            extract_host_id(&payload).as_deref(),
+            Some("uLoRgfbbTayCX6r2Q_qQsQ")
+        );
+    }
+
+    #[test]
+    fn test_extract_account_id() {
+        let payload =
+            json!({ "payload": { "account_id": "D8cJuqWVQ623CI4Q8yQK0Q", "object": {} } });
+        assert_eq!(
+            extract_account_id(&payload).as_deref(),
+            Some("D8cJuqWVQ623CI4Q8yQK0Q")
+        );
+        assert!(extract_account_id(&json!({ "payload": { "plainToken": "x" } })).is_none());
+    }
 }
@@ -0,0 +1,151 @@
+/// Returns true if a Zoom event type matches a pattern from
+/// `ZOOM_ALLOWED_EVENTS` or `ZOOM_EVENT_TIERS`.
+///
+/// `*` matches any run of characters (including `.`), so `meeting.*` matches
+/// every meeting event and `*` matches everything. Other characters match
+/// literally.
+pub fn event_pattern_matches(pattern: &str, event: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == event;
+    };
+    let Some(mut remaining) = event.strip_prefix(prefix) else {
+        return false;
+    };
+    let mut parts = rest.split('*').peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            // Last literal part anchors at the end of the event
+            return remaining.len() >= part.len() && remaining.ends_with(part);
+        }
+        match remaining.find(part) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// How sensitive a Zoom event type is, from `ZOOM_EVENT_TIERS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZoomEventTier {
+    /// Delivered to the workflows of the event's host, plus privileged
+    /// workflows (the default).
+    #[default]
+    Host,
+    /// Delivered only to privileged workflows (`ZOOM_PRIVILEGED_USERS`,
+    /// `ZOOM_PRIVILEGED_WORKFLOW_IDS`), whoever hosted the meeting.
+    Privileged,
+}
+
+impl ZoomEventTier {
+    /// Parse a tier name. Unknown names fall back to the most restrictive
+    /// tier, so a typo never widens delivery.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "host" => Self::Host,
+            _ => Self::Privileged,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Host => "host",
+            Self::Privileged => "privileged",
+        }
+    }
+}
+
+/// An optional way of matching a Zoom event to workflow owners besides the
+/// meeting host's email, enabled per event type with `ZOOM_ROUTING_MODES`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoomRoutingMode {
+    /// `payload.object.participant.email`
+    Participant,
+    /// The meeting's alternative hosts (`settings.alternative_hosts`)
+    AlternativeHost,
+    /// `payload.object.host_id`, resolved to an email
+    HostId,
+}
+
+impl ZoomRoutingMode {
+    /// Parse a mode name; unknown names return `None`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "participant" => Some(Self::Participant),
+            "alternative_host" | "co_host" => Some(Self::AlternativeHost),
+            "host_id" => Some(Self::HostId),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Participant => "participant",
+            Self::AlternativeHost => "alternative_host",
+            Self::HostId => "host_id",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_pattern_exact() {
+        assert!(event_pattern_matches("meeting.started", "meeting.started"));
+        assert!(!event_pattern_matches("meeting.started", "meeting.ended"));
+    }
+
+    #[test]
+    fn test_event_pattern_globs() {
+        assert!(event_pattern_matches("*", "recording.completed"));
+        assert!(event_pattern_matches(
+            "meeting.*",
+            "meeting.participant_joined"
+        ));
+        assert!(!event_pattern_matches("meeting.*", "webinar.started"));
+        assert!(event_pattern_matches("*.started", "webinar.started"));
+        assert!(event_pattern_matches(
+            "meeting.*_joined",
+            "meeting.participant_joined"
+        ));
+        assert!(!event_pattern_matches(
+            "meeting.*_joined",
+            "meeting.participant_left"
+        ));
+        assert!(event_pattern_matches(
+            "recording.*.*",
+            "recording.transcript.completed"
+        ));
+        // The trailing literal can't overlap the prefix
+        assert!(!event_pattern_matches("meeting*meeting", "meeting"));
+    }
+
+    #[test]
+    fn test_tier_parse_fails_closed() {
+        assert_eq!(ZoomEventTier::parse("Host"), ZoomEventTier::Host);
+        assert_eq!(
+            ZoomEventTier::parse("privileged"),
+            ZoomEventTier::Privileged
+        );
+        assert_eq!(ZoomEventTier::parse("privilegd"), ZoomEventTier::Privileged);
+    }
+
+    #[test]
+    fn test_routing_mode_parse() {
+        assert_eq!(
+            ZoomRoutingMode::parse("Participant"),
+            Some(ZoomRoutingMode::Participant)
+        );
+        assert_eq!(
+            ZoomRoutingMode::parse("alternative-host"),
+            Some(ZoomRoutingMode::AlternativeHost)
+        );
+        assert_eq!(
+            ZoomRoutingMode::parse("host_id"),
+            Some(ZoomRoutingMode::HostId)
+        );
+        assert_eq!(ZoomRoutingMode::parse("everyone"), None);
+    }
+}
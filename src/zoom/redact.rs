@@ -0,0 +1,199 @@
+use serde_json::Value;
+
+/// Replacement value for masked fields.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// How `ZOOM_REDACT_FIELDS` removes a sensitive field from a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZoomRedactionMode {
+    /// Replace the field's value with `"[REDACTED]"`, keeping the key
+    /// (the default).
+    #[default]
+    Mask,
+    /// Remove the field entirely.
+    Remove,
+}
+
+impl ZoomRedactionMode {
+    /// Parse a mode name. Unknown names fall back to masking.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "remove" | "strip" => Self::Remove,
+            _ => Self::Mask,
+        }
+    }
+}
+
+/// Redact the fields at the given JSON pointers (RFC 6901) in place.
+///
+/// A `*` segment matches every element of an array or every member of an
+/// object, so `/payload/object/recording_files/*/download_url` covers each
+/// recording file. Pointers to fields that are not present are skipped.
+/// Returns the number of fields redacted.
+pub fn redact_json_pointers<'a>(
+    value: &mut Value,
+    pointers: impl IntoIterator<Item = &'a str>,
+    mode: ZoomRedactionMode,
+) -> usize {
+    pointers
+        .into_iter()
+        .map(|pointer| {
+            let Some(path) = pointer.strip_prefix('/') else {
+                return 0;
+            };
+            let segments: Vec<String> = path
+                .split('/')
+                .map(|s| s.replace("~1", "/").replace("~0", "~"))
+                .collect();
+            redact_at(value, &segments, mode)
+        })
+        .sum()
+}
+
+fn redact_at(value: &mut Value, segments: &[String], mode: ZoomRedactionMode) -> usize {
+    let Some((segment, rest)) = segments.split_first() else {
+        return 0;
+    };
+
+    if rest.is_empty() {
+        return match (value, mode) {
+            (Value::Object(map), ZoomRedactionMode::Mask) if segment == "*" => {
+                map.values_mut().map(mask).sum()
+            }
+            (Value::Object(map), ZoomRedactionMode::Remove) if segment == "*" => {
+                std::mem::take(map).len()
+            }
+            (Value::Object(map), ZoomRedactionMode::Mask) => map.get_mut(segment).map_or(0, mask),
+            (Value::Object(map), ZoomRedactionMode::Remove) => {
+                usize::from(map.remove(segment).is_some())
+            }
+            (Value::Array(items), ZoomRedactionMode::Mask) if segment == "*" => {
+                items.iter_mut().map(mask).sum()
+            }
+            (Value::Array(items), ZoomRedactionMode::Remove) if segment == "*" => {
+                std::mem::take(items).len()
+            }
+            (Value::Array(items), mode) => match segment.parse::<usize>() {
+                Ok(index) if index < items.len() => match mode {
+                    ZoomRedactionMode::Mask => mask(&mut items[index]),
+                    ZoomRedactionMode::Remove => {
+                        items.remove(index);
+                        1
+                    }
+                },
+                _ => 0,
+            },
+            _ => 0,
+        };
+    }
+
+    match value {
+        Value::Object(map) if segment == "*" => {
+            map.values_mut().map(|v| redact_at(v, rest, mode)).sum()
+        }
+        Value::Object(map) => map.get_mut(segment).map_or(0, |v| redact_at(v, rest, mode)),
+        Value::Array(items) if segment == "*" => {
+            items.iter_mut().map(|v| redact_at(v, rest, mode)).sum()
+        }
+        Value::Array(items) => segment
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| items.get_mut(index))
+            .map_or(0, |v| redact_at(v, rest, mode)),
+        _ => 0,
+    }
+}
+
+fn mask(value: &mut Value) -> usize {
+    *value = Value::String(REDACTED.to_string());
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn recording_completed() -> Value {
+        json!({
+            "event": "recording.completed",
+            "download_token": "abc.def",
+            "payload": {
+                "object": {
+                    "host_email": "host@example.com",
+                    "password": "secret",
+                    "recording_play_passcode": "passcode",
+                    "recording_files": [
+                        {"id": "f1", "download_url": "https://zoom.us/rec/1"},
+                        {"id": "f2", "download_url": "https://zoom.us/rec/2"}
+                    ]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_mask_keeps_keys() {
+        let mut payload = recording_completed();
+        let count = redact_json_pointers(
+            &mut payload,
+            [
+                "/download_token",
+                "/payload/object/password",
+                "/payload/object/missing",
+            ],
+            ZoomRedactionMode::Mask,
+        );
+
+        assert_eq!(count, 2);
+        assert_eq!(payload["download_token"], REDACTED);
+        assert_eq!(payload["payload"]["object"]["password"], REDACTED);
+        assert_eq!(
+            payload["payload"]["object"]["recording_play_passcode"],
+            "passcode"
+        );
+        assert!(payload["payload"]["object"].get("missing").is_none());
+    }
+
+    #[test]
+    fn test_remove_strips_fields_and_wildcards() {
+        let mut payload = recording_completed();
+        let count = redact_json_pointers(
+            &mut payload,
+            [
+                "/payload/object/recording_play_passcode",
+                "/payload/object/recording_files/*/download_url",
+            ],
+            ZoomRedactionMode::Remove,
+        );
+
+        assert_eq!(count, 3);
+        let object = &payload["payload"]["object"];
+        assert!(object.get("recording_play_passcode").is_none());
+        assert_eq!(object["recording_files"][1], json!({"id": "f2"}));
+        assert_eq!(object["host_email"], "host@example.com");
+    }
+
+    #[test]
+    fn test_pointer_escapes_and_indexes() {
+        let mut payload = json!({"a/b": {"list": ["x", "y"]}});
+        let count = redact_json_pointers(
+            &mut payload,
+            ["/a~1b/list/1", "/a~1b/list/5", "not-a-pointer"],
+            ZoomRedactionMode::Mask,
+        );
+
+        assert_eq!(count, 1);
+        assert_eq!(payload, json!({"a/b": {"list": ["x", REDACTED]}}));
+    }
+
+    #[test]
+    fn test_mode_parse() {
+        assert_eq!(
+            ZoomRedactionMode::parse("Remove"),
+            ZoomRedactionMode::Remove
+        );
+        assert_eq!(ZoomRedactionMode::parse("mask"), ZoomRedactionMode::Mask);
+        assert_eq!(ZoomRedactionMode::parse("other"), ZoomRedactionMode::Mask);
+    }
+}
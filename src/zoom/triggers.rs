@@ -26,6 +26,10 @@ pub struct ZoomTriggerConfig {
 
     /// n8n project type (`personal` or `team`)
     pub project_type: String,
+
+    /// Zoom account ID from the node's `unihook.account` annotation. An
+    /// account-scoped trigger only receives that account's events.
+    pub account_id: Option<String>,
 }
 
 /// n8n node type when installed as a community package (`nodes/node_modules/...`).
@@ -71,6 +75,7 @@ pub fn parse_zoom_trigger(
         owner_email: owner.owner_email.clone(),
         project_id: owner.project_id.clone(),
         project_type: owner.project_type.clone(),
+        account_id: node.annotations().get("account").map(str::to_string),
     })
 }
 
@@ -108,6 +113,7 @@ mod tests {
             name: "Zoom Trigger".to_string(),
             parameters: params,
             webhook_id: webhook_id.map(|s| s.to_string()),
+            notes: None,
         }
     }
 
@@ -177,6 +183,7 @@ mod tests {
             name: "HTTP Request".to_string(),
             parameters: json!({}),
             webhook_id: Some("webhook-123".to_string()),
+            notes: None,
         };
         let workflow = create_workflow("wf1", "Workflow", vec![node.clone()]);
 
@@ -194,4 +201,23 @@ mod tests {
 
         assert!(parse_zoom_trigger(&workflow, &node, &test_owner()).is_none());
     }
+
+    #[test]
+    fn test_parse_zoom_trigger_account_annotation() {
+        let mut node = create_zoom_trigger_node(
+            ZOOM_TRIGGER_NODE_TYPE,
+            Some("webhook-z9"),
+            json!({ "event": ["meeting.started"] }),
+        );
+        node.notes =
+            Some("Subsidiary meetings\nunihook.account: D8cJuqWVQ623CI4Q8yQK0Q".to_string());
+        let workflow = create_workflow("wf9", "Subsidiary", vec![node.clone()]);
+
+        let config = parse_zoom_trigger(&workflow, &node, &test_owner()).unwrap();
+        assert_eq!(config.account_id.as_deref(), Some("D8cJuqWVQ623CI4Q8yQK0Q"));
+
+        node.notes = None;
+        let config = parse_zoom_trigger(&workflow, &node, &test_owner()).unwrap();
+        assert!(config.account_id.is_none());
+    }
 }
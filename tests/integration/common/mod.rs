@@ -85,8 +85,9 @@ pub struct TestEnvironment {
     manage_docker: bool,
     /// Slack credential ID for attaching to Slack Trigger nodes
     slack_credential_id: Option<String>,
-    /// Jira credential ID for attaching to Jira Trigger nodes
-    jira_credential_id: Option<String>,
+    /// Jira credential IDs for attaching to Jira Trigger nodes, keyed by
+    /// credential type (Cloud, Server, Server PAT)
+    jira_credential_ids: Vec<(&'static str, String)>,
     /// GitHub credential ID for attaching to GitHub Trigger nodes
     github_credential_id: Option<String>,
 }
@@ -124,11 +125,19 @@ impl TestEnvironment {
             println!("Using Slack credential ID: {}", cred_id);
         }
 
-        // Get Jira credential ID from environment (created by test script)
-        let jira_credential_id = std::env::var("JIRA_CREDENTIAL_ID").ok();
-        if let Some(ref cred_id) = jira_credential_id {
-            println!("Using Jira credential ID: {}", cred_id);
-        }
+        // Get Jira credential IDs from environment (created by test script)
+        let jira_credential_ids: Vec<(&'static str, String)> = [
+            ("jiraSoftwareCloudApi", "JIRA_CREDENTIAL_ID"),
+            ("jiraSoftwareServerApi", "JIRA_SERVER_CREDENTIAL_ID"),
+            ("jiraSoftwareServerPatApi", "JIRA_SERVER_PAT_CREDENTIAL_ID"),
+        ]
+        .into_iter()
+        .filter_map(|(cred_type, var)| {
+            let cred_id = std::env::var(var).ok()?;
+            println!("Using {} credential ID: {}", cred_type, cred_id);
+            Some((cred_type, cred_id))
+        })
+        .collect();
 
         // Get GitHub credential ID from environment (created by test script)
         let github_credential_id = std::env::var("GITHUB_CREDENTIAL_ID").ok();
@@ -145,7 +154,7 @@ impl TestEnvironment {
             docker_config,
             manage_docker: should_manage,
             slack_credential_id,
-            jira_credential_id,
+            jira_credential_ids,
             github_credential_id,
         })
     }
@@ -172,10 +181,10 @@ impl TestEnvironment {
                 })?;
         }
 
-        // If we have a Jira credential, attach it to the workflow's Jira Trigger nodes
-        if let Some(ref cred_id) = self.jira_credential_id {
+        // Attach each available Jira credential to the Jira Trigger nodes of its type
+        for (cred_type, cred_id) in &self.jira_credential_ids {
             self.n8n_client
-                .attach_jira_credential(&workflow.id, cred_id)
+                .attach_jira_credential(&workflow.id, cred_type, cred_id)
                 .await
                 .map_err(|e| {
                     TestEnvError::N8nError(format!("Failed to attach Jira credential: {}", e))
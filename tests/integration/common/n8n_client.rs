@@ -114,11 +114,14 @@ impl N8nTestClient {
         Ok(())
     }
 
-    /// Attach Jira Software Cloud API credential to all Jira Trigger nodes in a workflow
+    /// Attach a Jira credential to the Jira Trigger nodes in a workflow that use
+    /// its credential type (`jiraSoftwareCloudApi`, `jiraSoftwareServerApi`, or
+    /// `jiraSoftwareServerPatApi`, selected by the node's `jiraVersion`).
     /// This is required for n8n to properly register the webhook for the trigger
     pub async fn attach_jira_credential(
         &self,
         workflow_id: &str,
+        credential_type: &str,
         credential_id: &str,
     ) -> Result<(), N8nTestError> {
         // First, get the current workflow
@@ -135,12 +138,14 @@ impl N8nTestClient {
         let mut updated = false;
 
         for node in &mut nodes {
-            if node.get("type").and_then(|t| t.as_str()) == Some("n8n-nodes-base.jiraTrigger") {
+            if node.get("type").and_then(|t| t.as_str()) == Some("n8n-nodes-base.jiraTrigger")
+                && jira_credential_type(node) == credential_type
+            {
                 // Add credentials to this node
                 let credentials = serde_json::json!({
-                    "jiraSoftwareCloudApi": {
+                    credential_type: {
                         "id": credential_id,
-                        "name": "Test Jira Software Cloud API"
+                        "name": format!("Test {}", credential_type)
                     }
                 });
                 node.as_object_mut()
@@ -530,3 +535,17 @@ impl std::fmt::Display for N8nTestError {
 }
 
 impl std::error::Error for N8nTestError {}
+
+/// The credential type a Jira Trigger node uses, based on its `jiraVersion`
+/// parameter (n8n defaults to Jira Cloud).
+fn jira_credential_type(node: &serde_json::Value) -> &'static str {
+    match node
+        .get("parameters")
+        .and_then(|p| p.get("jiraVersion"))
+        .and_then(|v| v.as_str())
+    {
+        Some("server") => "jiraSoftwareServerApi",
+        Some("serverPat") => "jiraSoftwareServerPatApi",
+        _ => "jiraSoftwareCloudApi",
+    }
+}
@@ -321,6 +321,103 @@ async fn test_jira_body_forwarded_to_workflow() {
         .expect("Failed to cleanup workflow");
 }
 
+// ==================== Credential Type Tests ====================
+
+/// Activate a Jira Trigger workflow for one credential type and verify that
+/// activation succeeded against the provider mock and events reach it.
+async fn assert_jira_credential_type_routes(workflow_name: &str, issue_key: &str) {
+    let env = TestEnvironment::new(false)
+        .await
+        .expect("Failed to create test environment");
+
+    let workflow = load_workflow(workflow_name);
+    let created = env
+        .setup_workflow(&workflow)
+        .await
+        .expect("Failed to setup workflow");
+
+    assert!(
+        created.active,
+        "Expected {} to activate against the Jira mock",
+        workflow_name
+    );
+
+    let initial_count = get_execution_count(&env, &created.id).await;
+
+    let payload = create_jira_issue_created_payload("PROJ", issue_key);
+    let response = env
+        .send_jira_event(&payload)
+        .await
+        .expect("Failed to send event");
+    assert!(response.status().is_success());
+
+    let execution_occurred = wait_for_execution(&env, &created.id, initial_count + 1).await;
+    assert!(
+        execution_occurred,
+        "Expected {} workflow execution to be triggered",
+        workflow_name
+    );
+
+    env.cleanup_workflow(&created.id)
+        .await
+        .expect("Failed to cleanup workflow");
+}
+
+#[tokio::test]
+async fn test_jira_cloud_credential_activates_and_routes() {
+    assert_jira_credential_type_routes("jira_issue_trigger", "PROJ-201").await;
+}
+
+#[tokio::test]
+async fn test_jira_server_credential_activates_and_routes() {
+    assert_jira_credential_type_routes("jira_server_issue_trigger", "PROJ-202").await;
+}
+
+#[tokio::test]
+async fn test_jira_server_pat_credential_activates_and_routes() {
+    assert_jira_credential_type_routes("jira_server_pat_issue_trigger", "PROJ-203").await;
+}
+
+#[tokio::test]
+async fn test_jira_mock_serves_server_and_v3_endpoints() {
+    let env = TestEnvironment::new(false)
+        .await
+        .expect("Failed to create test environment");
+
+    for path in [
+        "/rest/api/2/myself",
+        "/rest/api/3/myself",
+        "/rest/api/2/serverInfo",
+        "/rest/jira-webhook/1.0/webhooks",
+        "/jira/acme/rest/api/2/serverInfo",
+    ] {
+        let response = env
+            .http_client
+            .get(format!("{}{}", UNIHOOK_URL, path))
+            .send()
+            .await
+            .expect("Failed to call Jira mock");
+        assert!(
+            response.status().is_success(),
+            "Expected {} to succeed, got: {}",
+            path,
+            response.status()
+        );
+    }
+
+    let server_info: serde_json::Value = env
+        .http_client
+        .get(format!("{}/rest/api/2/serverInfo", UNIHOOK_URL))
+        .send()
+        .await
+        .expect("Failed to call serverInfo")
+        .json()
+        .await
+        .expect("serverInfo is not JSON");
+    assert_eq!(server_info["deploymentType"], "Server");
+    assert!(server_info["versionNumbers"].is_array());
+}
+
 // ==================== Health Check Integration ====================
 
 #[tokio::test]
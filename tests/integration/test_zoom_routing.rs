@@ -7,7 +7,15 @@ use crate::common::{
     create_zoom_recording_completed_payload, create_zoom_url_validation_payload,
     create_zoom_user_updated_payload, get_execution_count, load_workflow, wait_for_execution,
 };
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn unix_timestamp() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        .to_string()
+}
 
 #[tokio::test]
 async fn test_zoom_url_validation_returns_encrypted_token() {
@@ -18,15 +26,15 @@ async fn test_zoom_url_validation_returns_encrypted_token() {
     let plain_token = "test-plain-token-12345";
     let payload = create_zoom_url_validation_payload(plain_token);
     let body = serde_json::to_string(&payload).unwrap();
-    let timestamp = "1739923528";
-    let signature = compute_zoom_signature(TEST_ZOOM_WEBHOOK_SECRET, timestamp, &body);
+    let timestamp = unix_timestamp();
+    let signature = compute_zoom_signature(TEST_ZOOM_WEBHOOK_SECRET, &timestamp, &body);
 
     let response = env
         .http_client
         .post(format!("{}/zoom/events", UNIHOOK_URL))
         .header("content-type", "application/json")
         .header("x-zm-signature", signature)
-        .header("x-zm-request-timestamp", timestamp)
+        .header("x-zm-request-timestamp", &timestamp)
         .body(body)
         .send()
         .await
@@ -80,6 +88,75 @@ async fn test_zoom_invalid_signature_rejected() {
         .expect("Failed to cleanup workflow");
 }
 
+#[tokio::test]
+async fn test_zoom_account_path_validation_and_mismatch() {
+    let env = TestEnvironment::new(false)
+        .await
+        .expect("Failed to create test environment");
+
+    let send = |body: String| {
+        let timestamp = unix_timestamp();
+        let signature = compute_zoom_signature(TEST_ZOOM_WEBHOOK_SECRET, &timestamp, &body);
+        env.http_client
+            .post(format!("{}/zoom/subsidiary-account/events", UNIHOOK_URL))
+            .header("content-type", "application/json")
+            .header("x-zm-signature", signature)
+            .header("x-zm-request-timestamp", timestamp)
+            .body(body)
+            .send()
+    };
+
+    // URL validation has no account_id; the path account is used
+    let validation = create_zoom_url_validation_payload("account-token");
+    let response = send(serde_json::to_string(&validation).unwrap())
+        .await
+        .expect("Failed to send URL validation request");
+    assert!(response.status().is_success());
+
+    // A payload from another account can't be posted to this account's path
+    let mut payload = create_zoom_meeting_started_payload();
+    payload["payload"]["account_id"] = serde_json::json!("other-account");
+    let response = send(serde_json::to_string(&payload).unwrap())
+        .await
+        .expect("Failed to send event");
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_zoom_stale_and_replayed_requests_rejected() {
+    let env = TestEnvironment::new(false)
+        .await
+        .expect("Failed to create test environment");
+
+    let payload = create_zoom_url_validation_payload("replay-token");
+    let body = serde_json::to_string(&payload).unwrap();
+    let send = |timestamp: String| {
+        let signature = compute_zoom_signature(TEST_ZOOM_WEBHOOK_SECRET, &timestamp, &body);
+        env.http_client
+            .post(format!("{}/zoom/events", UNIHOOK_URL))
+            .header("content-type", "application/json")
+            .header("x-zm-signature", signature)
+            .header("x-zm-request-timestamp", timestamp)
+            .body(body.clone())
+            .send()
+    };
+
+    // Validly signed, but outside the replay window
+    let stale = send("1739923528".to_string())
+        .await
+        .expect("Failed to send stale request");
+    assert_eq!(stale.status(), 401);
+
+    // The same fresh request is accepted once
+    let timestamp = unix_timestamp();
+    let first = send(timestamp.clone())
+        .await
+        .expect("Failed to send request");
+    assert!(first.status().is_success());
+    let replay = send(timestamp).await.expect("Failed to send replay");
+    assert_eq!(replay.status(), 401);
+}
+
 #[tokio::test]
 async fn test_zoom_meeting_started_triggers_workflow_execution() {
     let env = TestEnvironment::new(false)